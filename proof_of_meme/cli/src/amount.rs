@@ -0,0 +1,49 @@
+//! Exact decimal <-> base unit conversion for SOL and meme token amounts
+
+use anyhow::{bail, Context, Result};
+
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Parse "1.25" into base units with `decimals` places, rejecting excess precision
+pub fn parse_amount(value: &str, decimals: u8) -> Result<u64> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        bail!("invalid amount: {value:?}");
+    }
+    if fraction.len() > decimals as usize {
+        bail!("{value} has more than {decimals} decimal places");
+    }
+
+    let scale = 10u64.pow(decimals as u32);
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().with_context(|| format!("invalid amount: {value:?}"))? };
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        let padded = format!("{fraction:0<width$}", width = decimals as usize);
+        padded.parse().with_context(|| format!("invalid amount: {value:?}"))?
+    };
+
+    whole
+        .checked_mul(scale)
+        .and_then(|units| units.checked_add(fraction))
+        .with_context(|| format!("amount too large: {value}"))
+}
+
+/// Format base units with `decimals` places, trimming trailing zeros
+pub fn format_amount(units: u64, decimals: u8) -> String {
+    let scale = 10u64.pow(decimals as u32);
+    let fraction = units % scale;
+    if fraction == 0 {
+        return (units / scale).to_string();
+    }
+    let fraction = format!("{fraction:0>width$}", width = decimals as usize);
+    format!("{}.{}", units / scale, fraction.trim_end_matches('0'))
+}
+
+pub fn parse_sol(value: &str) -> Result<u64> {
+    parse_amount(value, SOL_DECIMALS)
+}
+
+pub fn format_sol(lamports: u64) -> String {
+    format_amount(lamports, SOL_DECIMALS)
+}
@@ -0,0 +1,572 @@
+//! `pom`: operate the Proof of Meme program from the command line
+
+mod amount;
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use proof_of_meme_client::accounts::MEME_STATUS_OFFSET;
+use proof_of_meme_client::constants::*;
+use proof_of_meme_client::instructions as ix;
+use proof_of_meme_client::pda::*;
+use proof_of_meme_client::quote::{self, Quote};
+use proof_of_meme_client::raydium::{RaydiumPoolAccounts, AMM_CONFIG, CREATE_POOL_FEE_RECEIVER};
+use proof_of_meme_client::state::{
+    Backing, BondingCurve, CurveBreakpoint, CurveKind, CurveParams, Meme, MemeStatus, MigrationTarget, PlatformConfig,
+    TokenProgram,
+};
+use proof_of_meme_client::PROGRAM_ID;
+use serde::Deserialize;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+use amount::{format_amount, format_sol, parse_amount, parse_sol};
+
+#[derive(Parser)]
+#[command(name = "pom", version, about = "Proof of Meme command-line tool")]
+struct Cli {
+    /// RPC URL or moniker (localhost, devnet, testnet, mainnet-beta)
+    #[arg(short = 'u', long, global = true, env = "POM_RPC_URL", default_value = "localhost")]
+    url: String,
+
+    /// Keypair that signs and pays for transactions
+    #[arg(short = 'k', long, global = true, env = "POM_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the platform config; the signer becomes the authority
+    InitPlatform {
+        /// Meme submission fee in SOL
+        #[arg(long, default_value = "0.1")]
+        submission_fee: String,
+        #[arg(long, default_value_t = DEFAULT_PLATFORM_FEE_BPS)]
+        platform_fee_bps: u16,
+        #[arg(long, default_value_t = DEFAULT_GENESIS_FEE_BPS)]
+        genesis_fee_bps: u16,
+        #[arg(long, default_value_t = DEFAULT_BURN_FEE_BPS)]
+        burn_fee_bps: u16,
+        /// Seconds after launch before genesis tokens start unlocking
+        #[arg(long, default_value_t = 0)]
+        vesting_cliff: i64,
+        /// Seconds over which genesis tokens unlock linearly
+        #[arg(long, default_value_t = 0)]
+        vesting_duration: i64,
+    },
+    /// Grow a platform config created before the config fields were added to the current layout
+    MigratePlatform,
+    /// Submit a meme described by a JSON file
+    Submit { file: PathBuf },
+    /// Change the uri and/or description of your meme while it is still proving
+    UpdateMetadata {
+        meme: String,
+        #[arg(long)]
+        uri: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Back a meme that is still proving
+    Back {
+        /// Meme index or address
+        meme: String,
+        /// Amount in SOL
+        amount: String,
+    },
+    /// Reduce or cancel backing while the meme is still proving
+    Unback {
+        meme: String,
+        /// Amount in SOL (default: all of it)
+        amount: Option<String>,
+    },
+    /// Withdraw backing from a failed or cancelled meme
+    Withdraw { meme: String },
+    /// Reclaim the pro-rata excess of an oversubscribed launch
+    Refund { meme: String },
+    /// List memes with progress toward their goals
+    List {
+        #[arg(long, value_enum)]
+        status: Option<StatusFilter>,
+    },
+    /// Quote a bonding curve trade without sending it
+    Quote {
+        #[command(subcommand)]
+        side: QuoteSide,
+    },
+    /// Buy tokens on the bonding curve
+    Buy {
+        meme: String,
+        /// SOL to spend, fee included
+        amount: String,
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u64,
+    },
+    /// Sell tokens on the bonding curve
+    Sell {
+        meme: String,
+        /// Tokens to sell
+        amount: String,
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u64,
+    },
+    /// Claim accumulated genesis trading fees
+    ClaimFees { meme: String },
+    /// Launch a meme whose proving period ended with its goal met
+    Finalize { meme: String },
+    /// Fail a meme whose proving period ended short of its goal
+    MarkFailed { meme: String },
+    /// Cancel your own meme while it is still proving
+    Cancel { meme: String },
+    /// Migrate a completed curve to the venue picked at submission (Raydium CPMM or the native pool)
+    Migrate {
+        meme: String,
+        #[arg(long, default_value_t = AMM_CONFIG)]
+        amm_config: Pubkey,
+        #[arg(long, default_value_t = CREATE_POOL_FEE_RECEIVER)]
+        create_pool_fee: Pubkey,
+    },
+}
+
+#[derive(Subcommand)]
+enum QuoteSide {
+    /// Tokens received for an amount of SOL
+    Buy { meme: String, amount: String },
+    /// SOL received for an amount of tokens
+    Sell { meme: String, amount: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum StatusFilter {
+    Proving,
+    Launched,
+    Failed,
+    Migrated,
+    Cancelled,
+}
+
+impl From<StatusFilter> for MemeStatus {
+    fn from(status: StatusFilter) -> Self {
+        match status {
+            StatusFilter::Proving => MemeStatus::Proving,
+            StatusFilter::Launched => MemeStatus::Launched,
+            StatusFilter::Failed => MemeStatus::Failed,
+            StatusFilter::Migrated => MemeStatus::Migrated,
+            StatusFilter::Cancelled => MemeStatus::Cancelled,
+        }
+    }
+}
+
+/// Contents of the file passed to `pom submit`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MemeSubmission {
+    name: String,
+    symbol: String,
+    uri: String,
+    description: String,
+    /// Goal in SOL
+    sol_goal: serde_json::Number,
+    min_backers: u32,
+    duration_seconds: i64,
+    /// Launch as soon as the goal is met, after the platform launch window
+    #[serde(default)]
+    launch_on_goal: bool,
+    /// Most SOL the launch accepts; backing beyond it is refunded pro rata
+    sol_cap: Option<serde_json::Number>,
+    /// Create the mint under Token-2022 with native metadata instead of SPL Token + Metaplex
+    #[serde(default)]
+    token_2022: bool,
+    /// Virtual SOL reserves at launch; by default the curve opens at the genesis price
+    initial_virtual_sol: Option<serde_json::Number>,
+    /// SOL raised on the curve beyond the backing before it completes
+    completion_sol: Option<serde_json::Number>,
+    /// Share of the supply for genesis backers, in basis points
+    #[serde(default)]
+    genesis_allocation_bps: u16,
+    /// Shape of the bonding curve; constant product when absent
+    #[serde(default)]
+    curve: CurveShape,
+    /// Migrate into the program's native pool instead of Raydium, if the platform allows it
+    #[serde(default)]
+    native_pool: bool,
+}
+
+/// `curve` in a meme file, e.g. `{ "kind": "linear", "end_price_bps": 50000 }`
+#[derive(Deserialize, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum CurveShape {
+    #[default]
+    ConstantProduct,
+    /// Price at the end of the supply as a multiple of the opening price, in basis points
+    Linear { end_price_bps: u32 },
+    /// Growth over each slice of the supply, in basis points
+    Exponential { growth_bps: u16 },
+    /// Breakpoints in order, the last at 10000 supply_bps
+    PiecewiseLinear { breakpoints: Vec<CurveBreakpointEntry> },
+}
+
+#[derive(Deserialize)]
+struct CurveBreakpointEntry {
+    supply_bps: u16,
+    price_bps: u32,
+}
+
+impl TryFrom<CurveShape> for CurveKind {
+    type Error = anyhow::Error;
+
+    fn try_from(shape: CurveShape) -> Result<Self> {
+        Ok(match shape {
+            CurveShape::ConstantProduct => CurveKind::ConstantProduct,
+            CurveShape::Linear { end_price_bps } => CurveKind::Linear { end_price_bps },
+            CurveShape::Exponential { growth_bps } => CurveKind::Exponential { growth_bps },
+            CurveShape::PiecewiseLinear { breakpoints: entries } => {
+                if entries.len() > MAX_CURVE_BREAKPOINTS {
+                    return Err(anyhow!("at most {MAX_CURVE_BREAKPOINTS} curve breakpoints"));
+                }
+                let mut breakpoints = [CurveBreakpoint::default(); MAX_CURVE_BREAKPOINTS];
+                for (breakpoint, entry) in breakpoints.iter_mut().zip(&entries) {
+                    *breakpoint = CurveBreakpoint { supply_bps: entry.supply_bps, price_bps: entry.price_bps };
+                }
+                CurveKind::PiecewiseLinear { breakpoints, count: entries.len() as u8 }
+            }
+        })
+    }
+}
+
+struct Ctx {
+    rpc: RpcClient,
+    payer: Keypair,
+}
+
+impl Ctx {
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&self.payer.pubkey()), &[&self.payer], blockhash);
+        let signature = self.rpc.send_and_confirm_transaction_with_spinner(&tx)?;
+        println!("Signature: {signature}");
+        Ok(signature)
+    }
+
+    fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let data = self.rpc.get_account_data(address).with_context(|| format!("failed to fetch {address}"))?;
+        T::try_deserialize(&mut data.as_slice()).map_err(|err| anyhow!("failed to decode {address}: {err}"))
+    }
+
+    fn platform(&self) -> Result<PlatformConfig> {
+        self.account(&find_platform_address().0)
+    }
+
+    /// Accepts a meme index or address
+    fn meme(&self, meme: &str) -> Result<(Pubkey, Meme)> {
+        let address = match meme.parse::<u64>() {
+            Ok(index) => find_meme_address(index).0,
+            Err(_) => meme.parse().with_context(|| format!("not a meme index or address: {meme}"))?,
+        };
+        Ok((address, self.account(&address)?))
+    }
+
+    fn curve(&self, meme: &Pubkey) -> Result<BondingCurve> {
+        self.account(&find_curve_address(meme).0)
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let ctx = Ctx {
+        rpc: RpcClient::new_with_commitment(rpc_url(&cli.url), CommitmentConfig::confirmed()),
+        payer: load_keypair(&cli.keypair)?,
+    };
+
+    match cli.command {
+        Command::InitPlatform {
+            submission_fee,
+            platform_fee_bps,
+            genesis_fee_bps,
+            burn_fee_bps,
+            vesting_cliff,
+            vesting_duration,
+        } => {
+            ctx.send(&[ix::initialize_platform(
+                &ctx.payer.pubkey(),
+                parse_sol(&submission_fee)?,
+                platform_fee_bps,
+                genesis_fee_bps,
+                burn_fee_bps,
+                vesting_cliff,
+                vesting_duration,
+            )])?;
+            println!("Platform: {}", find_platform_address().0);
+        }
+        Command::MigratePlatform => {
+            ctx.send(&[ix::migrate_platform_config(&ctx.payer.pubkey())])?;
+        }
+        Command::Submit { file } => submit(&ctx, &file)?,
+        Command::UpdateMetadata { meme, uri, description } => {
+            let (address, meme) = ctx.meme(&meme)?;
+            let uri = uri.unwrap_or_else(|| meme.get_uri());
+            let description = description.unwrap_or_else(|| meme.get_description());
+            ctx.send(&[ix::update_meme_metadata(&ctx.payer.pubkey(), &address, uri, description)])?;
+        }
+        Command::Back { meme, amount } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::back_meme(&ctx.payer.pubkey(), &meme, parse_sol(&amount)?)])?;
+        }
+        Command::Unback { meme, amount } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            let backer = ctx.payer.pubkey();
+            let amount = match amount {
+                Some(amount) => parse_sol(&amount)?,
+                None => ctx.account::<Backing>(&find_backing_address(&meme, &backer).0)?.amount,
+            };
+            let platform = ctx.platform()?;
+            let penalty = platform.unback_penalty(amount).ok_or_else(|| anyhow!("amount too large"))?;
+            if penalty > 0 {
+                println!("Exit penalty: {} SOL", format_sol(penalty));
+            }
+            ctx.send(&[ix::unback_meme(&backer, &meme, &platform.fee_recipient, amount)])?;
+        }
+        Command::Withdraw { meme } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::withdraw_backing(&ctx.payer.pubkey(), &meme)])?;
+        }
+        Command::Refund { meme } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::claim_backing_refund(&ctx.payer.pubkey(), &meme)])?;
+        }
+        Command::List { status } => list(&ctx, status.map(MemeStatus::from))?,
+        Command::Quote { side } => match side {
+            QuoteSide::Buy { meme, amount } => {
+                let (meme, _) = ctx.meme(&meme)?;
+                print_buy_quote(&quote_buy(&ctx, &meme, &amount)?);
+            }
+            QuoteSide::Sell { meme, amount } => {
+                let (meme, _) = ctx.meme(&meme)?;
+                print_sell_quote(&quote_sell(&ctx, &meme, &amount)?);
+            }
+        },
+        Command::Buy { meme, amount, slippage_bps } => {
+            let (meme, state) = ctx.meme(&meme)?;
+            let token_program = state.token_program.id();
+            let quote = quote_buy(&ctx, &meme, &amount)?;
+            print_buy_quote(&quote);
+
+            let buyer = ctx.payer.pubkey();
+            let fee_recipient = ctx.platform()?.fee_recipient;
+            ctx.send(&[
+                create_associated_token_account_idempotent(&buyer, &buyer, &state.mint, &token_program),
+                ix::buy_tokens(&buyer, &meme, &token_program, &fee_recipient, quote.amount_in, quote::min_amount_out(quote.amount_out, slippage_bps)),
+            ])?;
+        }
+        Command::Sell { meme, amount, slippage_bps } => {
+            let (meme, state) = ctx.meme(&meme)?;
+            let quote = quote_sell(&ctx, &meme, &amount)?;
+            print_sell_quote(&quote);
+
+            let fee_recipient = ctx.platform()?.fee_recipient;
+            ctx.send(&[ix::sell_tokens(
+                &ctx.payer.pubkey(),
+                &meme,
+                &state.token_program.id(),
+                &fee_recipient,
+                quote.amount_in,
+                quote::min_amount_out(quote.amount_out, slippage_bps),
+            )])?;
+        }
+        Command::ClaimFees { meme } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::claim_genesis_fees(&ctx.payer.pubkey(), &meme)])?;
+        }
+        Command::Finalize { meme } => {
+            let (meme, state) = ctx.meme(&meme)?;
+            ctx.send(&[
+                ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+                ix::finalize_proving(&ctx.payer.pubkey(), &meme, &state.token_program.id()),
+            ])?;
+        }
+        Command::MarkFailed { meme } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::mark_meme_failed(&ctx.payer.pubkey(), &meme)])?;
+        }
+        Command::Cancel { meme } => {
+            let (meme, _) = ctx.meme(&meme)?;
+            ctx.send(&[ix::cancel_meme(&ctx.payer.pubkey(), &meme)])?;
+        }
+        Command::Migrate { meme, amm_config, create_pool_fee } => {
+            let (meme, state) = ctx.meme(&meme)?;
+            let fee_recipient = ctx.platform()?.fee_recipient;
+            let token_program = state.token_program.id();
+            match state.curve_params.migration_target {
+                MigrationTarget::Raydium => {
+                    let curve_vault = find_curve_vault_address(&meme).0;
+                    let pool = RaydiumPoolAccounts::derive(&state.mint, &curve_vault, &amm_config, &create_pool_fee);
+                    ctx.send(&[
+                        ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+                        ix::migrate_to_raydium(&ctx.payer.pubkey(), &meme, &token_program, &fee_recipient, &pool),
+                    ])?;
+                    println!("Raydium pool: {}", pool.pool_state);
+                }
+                MigrationTarget::NativePool => {
+                    ctx.send(&[
+                        ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+                        ix::migrate_to_pool(&ctx.payer.pubkey(), &meme, &token_program, &fee_recipient),
+                    ])?;
+                    println!("Native pool: {}", find_pool_address(&meme).0);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(ctx: &Ctx, file: &Path) -> Result<()> {
+    let json = std::fs::read_to_string(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let submission: MemeSubmission = serde_json::from_str(&json).with_context(|| format!("invalid meme file {}", file.display()))?;
+
+    let platform = ctx.platform()?;
+    let index = platform.total_memes_submitted;
+    let args = ix::SubmitMeme {
+        name: submission.name,
+        symbol: submission.symbol,
+        uri: submission.uri,
+        description: submission.description,
+        sol_goal: parse_sol(&submission.sol_goal.to_string())?,
+        min_backers: submission.min_backers,
+        duration_seconds: submission.duration_seconds,
+        launch_on_goal: submission.launch_on_goal,
+        sol_cap: optional_sol(submission.sol_cap)?,
+        token_program: if submission.token_2022 { TokenProgram::Token2022 } else { TokenProgram::Spl },
+        // Unset parameters take the platform defaults
+        curve_params: CurveParams {
+            initial_virtual_sol: optional_sol(submission.initial_virtual_sol)?,
+            completion_sol: optional_sol(submission.completion_sol)?,
+            genesis_allocation_bps: submission.genesis_allocation_bps,
+            kind: submission.curve.try_into()?,
+            migration_target: if submission.native_pool { MigrationTarget::NativePool } else { MigrationTarget::Raydium },
+        },
+    };
+
+    ctx.send(&[ix::submit_meme(&ctx.payer.pubkey(), index, &platform.fee_recipient, args)])?;
+    println!("Meme #{index}: {}", find_meme_address(index).0);
+    Ok(())
+}
+
+/// Lamports for an optional SOL amount in a meme file (0 when absent)
+fn optional_sol(sol: Option<serde_json::Number>) -> Result<u64> {
+    sol.map(|sol| parse_sol(&sol.to_string())).transpose().map(|lamports| lamports.unwrap_or(0))
+}
+
+fn list(ctx: &Ctx, status: Option<MemeStatus>) -> Result<()> {
+    let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Meme::DISCRIMINATOR.to_vec()))];
+    if let Some(status) = status {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(MEME_STATUS_OFFSET, vec![status as u8])));
+    }
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    let mut memes: Vec<(Pubkey, Meme)> = ctx
+        .rpc
+        .get_program_accounts_with_config(&PROGRAM_ID, config)?
+        .into_iter()
+        .filter_map(|(address, account)| Some((address, Meme::try_deserialize(&mut account.data.as_slice()).ok()?)))
+        .collect();
+    memes.sort_by_key(|(_, meme)| meme.index);
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    println!(
+        "{:>5}  {:<44}  {:<10}  {:<9}  {:>22}  {:>9}  PROVING",
+        "INDEX", "ADDRESS", "SYMBOL", "STATUS", "BACKED / GOAL (SOL)", "BACKERS"
+    );
+    for (address, meme) in &memes {
+        let progress = meme.sol_backed as u128 * 100 / meme.sol_goal.max(1) as u128;
+        let proving = match meme.status {
+            MemeStatus::Proving if meme.proving_ends_at > now => format_duration(meme.proving_ends_at - now),
+            MemeStatus::Proving => "ended".to_string(),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:>5}  {:<44}  {:<10}  {:<9}  {:>22}  {:>9}  {}{}",
+            meme.index,
+            address.to_string(),
+            meme.get_symbol(),
+            format!("{:?}", meme.status),
+            format!("{} / {} ({progress}%)", format_sol(meme.sol_backed), format_sol(meme.sol_goal)),
+            format!("{}/{}", meme.backer_count, meme.min_backers),
+            proving,
+            if meme.frozen { " [frozen]" } else { "" },
+        );
+    }
+    println!("{} meme(s)", memes.len());
+    Ok(())
+}
+
+fn quote_buy(ctx: &Ctx, meme: &Pubkey, amount: &str) -> Result<Quote> {
+    let sol_amount = parse_sol(amount)?;
+    quote::quote_buy(&ctx.curve(meme)?, &ctx.platform()?, sol_amount).context("buy amount is too small or overflows the curve")
+}
+
+fn quote_sell(ctx: &Ctx, meme: &Pubkey, amount: &str) -> Result<Quote> {
+    let token_amount = parse_amount(amount, TOKEN_DECIMALS)?;
+    quote::quote_sell(&ctx.curve(meme)?, &ctx.platform()?, token_amount).context("sell amount is too small or overflows the curve")
+}
+
+fn print_buy_quote(quote: &Quote) {
+    println!("Spend:       {} SOL", format_sol(quote.amount_in));
+    println!("Receive:     {} tokens", format_amount(quote.amount_out, TOKEN_DECIMALS));
+    println!("Trading fee: {} SOL", format_sol(quote.trading_fee));
+}
+
+fn print_sell_quote(quote: &Quote) {
+    println!("Sell:        {} tokens", format_amount(quote.amount_in, TOKEN_DECIMALS));
+    println!("Receive:     {} SOL", format_sol(quote.amount_out));
+    println!("Trading fee: {} SOL", format_sol(quote.trading_fee));
+}
+
+fn format_duration(seconds: i64) -> String {
+    let (hours, minutes) = (seconds / 3600, seconds % 3600 / 60);
+    if hours > 0 {
+        format!("{hours}h {minutes}m left")
+    } else {
+        format!("{minutes}m left")
+    }
+}
+
+fn rpc_url(url: &str) -> String {
+    match url {
+        "localhost" | "l" => "http://127.0.0.1:8899",
+        "devnet" | "d" => "https://api.devnet.solana.com",
+        "testnet" | "t" => "https://api.testnet.solana.com",
+        "mainnet-beta" | "m" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn load_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => Path::new(&std::env::var("HOME").context("HOME is not set")?).join(rest),
+        None => PathBuf::from(path),
+    };
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {}: {err}", path.display()))
+}
@@ -0,0 +1,110 @@
+//! Decoders for raw account data, checking the Anchor discriminator
+
+use anchor_lang::{AccountDeserialize, Result};
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use proof_of_meme::constants::{MAX_DESCRIPTION_LENGTH, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, TOTAL_SUPPLY};
+use proof_of_meme::state::{Backing, BondingCurve, GenesisPool, Meme, PlatformConfig, Pool};
+
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_platform(data: &[u8]) -> Result<PlatformConfig> {
+    decode(data)
+}
+
+pub fn decode_meme(data: &[u8]) -> Result<Meme> {
+    decode(data)
+}
+
+pub fn decode_backing(data: &[u8]) -> Result<Backing> {
+    decode(data)
+}
+
+pub fn decode_curve(data: &[u8]) -> Result<BondingCurve> {
+    decode(data)
+}
+
+pub fn decode_genesis_pool(data: &[u8]) -> Result<GenesisPool> {
+    decode(data)
+}
+
+pub fn decode_pool(data: &[u8]) -> Result<Pool> {
+    decode(data)
+}
+
+/// Decodes SPL Token and Token-2022 mints alike
+pub fn decode_mint(data: &[u8]) -> Result<Mint> {
+    Ok(StateWithExtensions::<Mint>::unpack(data)?.base)
+}
+
+/// Whether a meme mint's supply is final: full supply minted, no mint or freeze authority left
+pub fn is_fixed_supply(mint: &Mint) -> bool {
+    mint.is_initialized
+        && mint.supply == TOTAL_SUPPLY
+        && mint.mint_authority.is_none()
+        && mint.freeze_authority.is_none()
+}
+
+/// Offset of `Meme::status`, for `getProgramAccounts` memcmp filters
+pub const MEME_STATUS_OFFSET: usize = 8 + // discriminator
+    32 + // creator
+    32 + // mint
+    MAX_NAME_LENGTH + 1 + // name + length
+    MAX_SYMBOL_LENGTH + 1 + // symbol + length
+    MAX_URI_LENGTH + 1 + // uri + length
+    MAX_DESCRIPTION_LENGTH + 2 + // description + length
+    8 + // sol_goal
+    8 + // sol_backed
+    4 + // min_backers
+    4 + // backer_count
+    8; // proving_ends_at
+
+/// Offset of `BondingCurve::status`, for `getProgramAccounts` memcmp filters
+pub const CURVE_STATUS_OFFSET: usize = 8 + // discriminator
+    32 + // meme
+    32 + // mint
+    8 * 10; // reserves, volume and fee counters
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use proof_of_meme::state::{CurveStatus, MemeStatus};
+
+    fn account_bytes<T: Discriminator>(size: usize, offset: usize, value: u8) -> Vec<u8> {
+        let mut data = vec![0; size];
+        data[..8].copy_from_slice(T::DISCRIMINATOR);
+        data[offset] = value;
+        data
+    }
+
+    #[test]
+    fn status_offsets_match_layout() {
+        let data = account_bytes::<Meme>(Meme::SIZE, MEME_STATUS_OFFSET, MemeStatus::Failed as u8);
+        assert_eq!(decode_meme(&data).unwrap().status, MemeStatus::Failed);
+
+        let data = account_bytes::<BondingCurve>(BondingCurve::SIZE, CURVE_STATUS_OFFSET, CurveStatus::Complete as u8);
+        assert_eq!(decode_curve(&data).unwrap().status, CurveStatus::Complete);
+    }
+
+    #[test]
+    fn fixed_supply_needs_full_supply_and_no_authorities() {
+        use anchor_lang::solana_program::program_option::COption;
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_lang::solana_program::pubkey::Pubkey;
+
+        let launched = Mint { supply: TOTAL_SUPPLY, is_initialized: true, ..Mint::default() };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(launched, &mut data).unwrap();
+        assert!(is_fixed_supply(&decode_mint(&data).unwrap()));
+
+        let mintable = Mint { mint_authority: COption::Some(Pubkey::new_unique()), ..launched };
+        assert!(!is_fixed_supply(&mintable));
+        let freezable = Mint { freeze_authority: COption::Some(Pubkey::new_unique()), ..launched };
+        assert!(!is_fixed_supply(&freezable));
+        let unminted = Mint { supply: 0, ..launched };
+        assert!(!is_fixed_supply(&unminted));
+    }
+}
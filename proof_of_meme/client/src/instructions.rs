@@ -0,0 +1,677 @@
+//! Instruction builders. Every PDA is derived from the meme address; callers
+//! supply signers, the meme and, where fees are paid, the platform fee recipient.
+//! Instructions that move a meme's tokens also take its token program
+//! (`Meme::token_program`), which decides the token account addresses.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address, get_associated_token_address_with_program_id};
+use anchor_spl::metadata;
+use anchor_spl::token::{self, spl_token};
+use proof_of_meme::raydium::RAYDIUM_CPMM_PROGRAM_ID;
+use proof_of_meme::state::{CurveBounds, SwapDirection};
+use proof_of_meme::{accounts, instruction, ID};
+
+use crate::pda::*;
+use crate::raydium::RaydiumPoolAccounts;
+
+/// Arguments for `submit_meme`
+pub use proof_of_meme::instruction::SubmitMeme;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn platform() -> Pubkey {
+    find_platform_address().0
+}
+
+fn event_authority() -> Pubkey {
+    find_event_authority_address().0
+}
+
+// Platform administration
+
+pub fn initialize_platform(
+    authority: &Pubkey,
+    submission_fee: u64,
+    platform_fee_bps: u16,
+    genesis_fee_bps: u16,
+    burn_fee_bps: u16,
+    vesting_cliff_seconds: i64,
+    vesting_duration_seconds: i64,
+) -> Instruction {
+    build(
+        accounts::InitializePlatform {
+            authority: *authority,
+            platform: platform(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializePlatform {
+            submission_fee,
+            platform_fee_bps,
+            genesis_fee_bps,
+            burn_fee_bps,
+            vesting_cliff_seconds,
+            vesting_duration_seconds,
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_platform_config(
+    authority: &Pubkey,
+    submission_fee: u64,
+    platform_fee_bps: u16,
+    genesis_fee_bps: u16,
+    burn_fee_bps: u16,
+    crank_reward: u64,
+    launch_window_seconds: i64,
+    unback_penalty_bps: u16,
+    config_timelock_seconds: i64,
+    curve_bounds: CurveBounds,
+) -> Instruction {
+    build(
+        accounts::UpdatePlatformConfig {
+            authority: *authority,
+            platform: platform(),
+        },
+        instruction::UpdatePlatformConfig {
+            submission_fee,
+            platform_fee_bps,
+            genesis_fee_bps,
+            burn_fee_bps,
+            crank_reward,
+            launch_window_seconds,
+            unback_penalty_bps,
+            config_timelock_seconds,
+            curve_bounds,
+        },
+    )
+}
+
+pub fn apply_platform_config(payer: &Pubkey) -> Instruction {
+    build(
+        accounts::ApplyPlatformConfig {
+            payer: *payer,
+            platform: platform(),
+        },
+        instruction::ApplyPlatformConfig {},
+    )
+}
+
+pub fn cancel_platform_config_update(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelPlatformConfigUpdate {
+            authority: *authority,
+            platform: platform(),
+        },
+        instruction::CancelPlatformConfigUpdate {},
+    )
+}
+
+pub fn propose_authority(authority: &Pubkey, new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            authority: *authority,
+            platform: platform(),
+        },
+        instruction::ProposeAuthority { new_authority: *new_authority },
+    )
+}
+
+pub fn accept_authority(new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            new_authority: *new_authority,
+            platform: platform(),
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+pub fn set_fee_recipient(authority: &Pubkey, fee_recipient: &Pubkey) -> Instruction {
+    build(
+        accounts::SetFeeRecipient {
+            authority: *authority,
+            platform: platform(),
+            fee_recipient: *fee_recipient,
+        },
+        instruction::SetFeeRecipient {},
+    )
+}
+
+pub fn migrate_platform_config(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::MigratePlatformConfig {
+            authority: *authority,
+            platform: platform(),
+            system_program: system_program::ID,
+        },
+        instruction::MigratePlatformConfig {},
+    )
+}
+
+pub fn set_platform_paused(authority: &Pubkey, paused: u8) -> Instruction {
+    build(
+        accounts::SetPlatformPaused {
+            authority: *authority,
+            platform: platform(),
+        },
+        instruction::SetPlatformPaused { paused },
+    )
+}
+
+pub fn set_meme_frozen(authority: &Pubkey, meme: &Pubkey, frozen: bool) -> Instruction {
+    build(
+        accounts::SetMemeFrozen {
+            authority: *authority,
+            platform: platform(),
+            meme: *meme,
+        },
+        instruction::SetMemeFrozen { frozen },
+    )
+}
+
+// Proving
+
+/// `index` must be the platform's current `total_memes`
+pub fn submit_meme(creator: &Pubkey, index: u64, fee_recipient: &Pubkey, args: SubmitMeme) -> Instruction {
+    let meme = find_meme_address(index).0;
+    build(
+        accounts::SubmitMeme {
+            creator: *creator,
+            platform: platform(),
+            meme,
+            vault: find_vault_address(&meme).0,
+            fee_recipient: *fee_recipient,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn update_meme_metadata(creator: &Pubkey, meme: &Pubkey, uri: String, description: String) -> Instruction {
+    build(
+        accounts::UpdateMemeMetadata {
+            creator: *creator,
+            platform: platform(),
+            meme: *meme,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::UpdateMemeMetadata { uri, description },
+    )
+}
+
+pub fn back_meme(backer: &Pubkey, meme: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::BackMeme {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            vault: find_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::BackMeme { amount },
+    )
+}
+
+pub fn unback_meme(backer: &Pubkey, meme: &Pubkey, fee_recipient: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::UnbackMeme {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            vault: find_vault_address(meme).0,
+            fee_recipient: *fee_recipient,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::UnbackMeme { amount },
+    )
+}
+
+pub fn withdraw_backing(backer: &Pubkey, meme: &Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawBacking {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            vault: find_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::WithdrawBacking {},
+    )
+}
+
+pub fn claim_backing_refund(backer: &Pubkey, meme: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimBackingRefund {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            vault: find_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::ClaimBackingRefund {},
+    )
+}
+
+pub fn finalize_proving(finalizer: &Pubkey, meme: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    let genesis_pool = find_genesis_pool_address(meme).0;
+    build(
+        accounts::FinalizeProving {
+            finalizer: *finalizer,
+            platform: platform(),
+            meme: *meme,
+            vault: find_vault_address(meme).0,
+            mint,
+            curve,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            genesis_pool,
+            genesis_token_account: get_associated_token_address_with_program_id(&genesis_pool, &mint, token_program),
+            curve_vault: find_curve_vault_address(meme).0,
+            metadata: find_metadata_address(&mint).0,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            token_metadata_program: metadata::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::FinalizeProving {},
+    )
+}
+
+pub fn mark_meme_failed(finalizer: &Pubkey, meme: &Pubkey) -> Instruction {
+    build(
+        accounts::MarkMemeFailed {
+            finalizer: *finalizer,
+            platform: platform(),
+            meme: *meme,
+            vault: find_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::MarkMemeFailed {},
+    )
+}
+
+pub fn cancel_meme(creator: &Pubkey, meme: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelMeme {
+            creator: *creator,
+            platform: platform(),
+            meme: *meme,
+            vault: find_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::CancelMeme {},
+    )
+}
+
+// Bonding curve
+
+/// The buyer's token account must already exist
+pub fn buy_tokens(
+    buyer: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    fee_recipient: &Pubkey,
+    sol_amount: u64,
+    min_tokens_out: u64,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    build(
+        accounts::BuyTokens {
+            buyer: *buyer,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            curve,
+            genesis_pool: find_genesis_pool_address(meme).0,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            buyer_token_account: get_associated_token_address_with_program_id(buyer, &mint, token_program),
+            curve_vault: find_curve_vault_address(meme).0,
+            fee_recipient: *fee_recipient,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::BuyTokens { sol_amount, min_tokens_out },
+    )
+}
+
+pub fn sell_tokens(
+    seller: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    fee_recipient: &Pubkey,
+    token_amount: u64,
+    min_sol_out: u64,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    build(
+        accounts::SellTokens {
+            seller: *seller,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            curve,
+            genesis_pool: find_genesis_pool_address(meme).0,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            seller_token_account: get_associated_token_address_with_program_id(seller, &mint, token_program),
+            curve_vault: find_curve_vault_address(meme).0,
+            fee_recipient: *fee_recipient,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::SellTokens { token_amount, min_sol_out },
+    )
+}
+
+pub fn execute_buyback_burn(payer: &Pubkey, meme: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    build(
+        accounts::ExecuteBuybackBurn {
+            payer: *payer,
+            platform: platform(),
+            meme: *meme,
+            curve,
+            mint,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            curve_vault: find_curve_vault_address(meme).0,
+            token_program: *token_program,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::ExecuteBuybackBurn {},
+    )
+}
+
+// Genesis claims
+
+pub fn claim_genesis_fees(backer: &Pubkey, meme: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimGenesisFees {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            curve: find_curve_address(meme).0,
+            genesis_pool: find_genesis_pool_address(meme).0,
+            curve_vault: find_curve_vault_address(meme).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::ClaimGenesisFees {},
+    )
+}
+
+pub fn claim_genesis_tokens(backer: &Pubkey, meme: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let genesis_pool = find_genesis_pool_address(meme).0;
+    build(
+        accounts::ClaimGenesisTokens {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            backing: find_backing_address(meme, backer).0,
+            genesis_pool,
+            mint,
+            genesis_token_account: get_associated_token_address_with_program_id(&genesis_pool, &mint, token_program),
+            backer_token_account: get_associated_token_address_with_program_id(backer, &mint, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimGenesisTokens {},
+    )
+}
+
+pub fn claim_vested(backer: &Pubkey, meme: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let genesis_pool = find_genesis_pool_address(meme).0;
+    build(
+        accounts::ClaimVested {
+            backer: *backer,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            backing: find_backing_address(meme, backer).0,
+            genesis_pool,
+            genesis_token_account: get_associated_token_address_with_program_id(&genesis_pool, &mint, token_program),
+            backer_token_account: get_associated_token_address_with_program_id(backer, &mint, token_program),
+            token_program: *token_program,
+        },
+        instruction::ClaimVested {},
+    )
+}
+
+// Migration
+
+/// `pool` is usually `RaydiumPoolAccounts::derive_default(mint, curve_vault)`
+pub fn migrate_to_raydium(
+    migrator: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    fee_recipient: &Pubkey,
+    pool: &RaydiumPoolAccounts,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    let curve_vault = find_curve_vault_address(meme).0;
+    let wsol_mint = spl_token::native_mint::ID;
+    build(
+        accounts::MigrateToRaydium {
+            migrator: *migrator,
+            platform: platform(),
+            meme: *meme,
+            curve,
+            fee_recipient: *fee_recipient,
+            curve_vault,
+            mint,
+            wsol_mint,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            vault_token_account: get_associated_token_address_with_program_id(&curve_vault, &mint, token_program),
+            vault_wsol_account: get_associated_token_address(&curve_vault, &wsol_mint),
+            cpmm_program: RAYDIUM_CPMM_PROGRAM_ID,
+            amm_config: pool.amm_config,
+            cpmm_authority: pool.authority,
+            pool_state: pool.pool_state,
+            lp_mint: pool.lp_mint,
+            vault_lp_account: pool.vault_lp_account,
+            pool_token_vault: pool.pool_token_vault,
+            pool_wsol_vault: pool.pool_wsol_vault,
+            create_pool_fee: pool.create_pool_fee,
+            observation_state: pool.observation_state,
+            token_program: token::ID,
+            meme_token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::MigrateToRaydium {},
+    )
+}
+
+pub fn migrate_to_pool(migrator: &Pubkey, meme: &Pubkey, token_program: &Pubkey, fee_recipient: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let curve = find_curve_address(meme).0;
+    let pool = find_pool_address(meme).0;
+    build(
+        accounts::MigrateToPool {
+            migrator: *migrator,
+            platform: platform(),
+            meme: *meme,
+            curve,
+            fee_recipient: *fee_recipient,
+            curve_vault: find_curve_vault_address(meme).0,
+            mint,
+            curve_token_account: get_associated_token_address_with_program_id(&curve, &mint, token_program),
+            pool,
+            pool_token_account: get_associated_token_address_with_program_id(&pool, &mint, token_program),
+            pool_vault: find_pool_vault_address(meme).0,
+            lp_mint: find_lp_mint_address(meme).0,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::MigrateToPool {},
+    )
+}
+
+// Native pool
+
+pub fn add_liquidity(
+    provider: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    sol_amount: u64,
+    max_token_amount: u64,
+    min_lp_out: u64,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let pool = find_pool_address(meme).0;
+    let lp_mint = find_lp_mint_address(meme).0;
+    build(
+        accounts::AddLiquidity {
+            provider: *provider,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            pool,
+            lp_mint,
+            pool_token_account: get_associated_token_address_with_program_id(&pool, &mint, token_program),
+            pool_vault: find_pool_vault_address(meme).0,
+            provider_token_account: get_associated_token_address_with_program_id(provider, &mint, token_program),
+            provider_lp_account: get_associated_token_address_with_program_id(provider, &lp_mint, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::AddLiquidity { sol_amount, max_token_amount, min_lp_out },
+    )
+}
+
+pub fn remove_liquidity(
+    provider: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    lp_amount: u64,
+    min_sol_out: u64,
+    min_tokens_out: u64,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let pool = find_pool_address(meme).0;
+    let lp_mint = find_lp_mint_address(meme).0;
+    build(
+        accounts::RemoveLiquidity {
+            provider: *provider,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            pool,
+            lp_mint,
+            pool_token_account: get_associated_token_address_with_program_id(&pool, &mint, token_program),
+            pool_vault: find_pool_vault_address(meme).0,
+            provider_token_account: get_associated_token_address_with_program_id(provider, &mint, token_program),
+            provider_lp_account: get_associated_token_address_with_program_id(provider, &lp_mint, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::RemoveLiquidity { lp_amount, min_sol_out, min_tokens_out },
+    )
+}
+
+pub fn swap(
+    trader: &Pubkey,
+    meme: &Pubkey,
+    token_program: &Pubkey,
+    fee_recipient: &Pubkey,
+    amount_in: u64,
+    min_amount_out: u64,
+    direction: SwapDirection,
+) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let pool = find_pool_address(meme).0;
+    build(
+        accounts::Swap {
+            trader: *trader,
+            platform: platform(),
+            meme: *meme,
+            mint,
+            curve: find_curve_address(meme).0,
+            genesis_pool: find_genesis_pool_address(meme).0,
+            pool,
+            pool_token_account: get_associated_token_address_with_program_id(&pool, &mint, token_program),
+            trader_token_account: get_associated_token_address_with_program_id(trader, &mint, token_program),
+            pool_vault: find_pool_vault_address(meme).0,
+            curve_vault: find_curve_vault_address(meme).0,
+            fee_recipient: *fee_recipient,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::Swap { amount_in, min_amount_out, direction },
+    )
+}
+
+pub fn execute_pool_buyback_burn(payer: &Pubkey, meme: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let mint = find_mint_address(meme).0;
+    let pool = find_pool_address(meme).0;
+    build(
+        accounts::ExecutePoolBuybackBurn {
+            payer: *payer,
+            platform: platform(),
+            meme: *meme,
+            pool,
+            mint,
+            pool_token_account: get_associated_token_address_with_program_id(&pool, &mint, token_program),
+            pool_vault: find_pool_vault_address(meme).0,
+            token_program: *token_program,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        instruction::ExecutePoolBuybackBurn {},
+    )
+}
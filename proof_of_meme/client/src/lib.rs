@@ -0,0 +1,18 @@
+//! Rust client for the Proof of Meme program
+//!
+//! - [`pda`]: addresses for every program-derived account
+//! - [`instructions`]: typed builders for every program instruction
+//! - [`accounts`]: decoders for program account data
+//! - [`quote`]: off-chain trade quotes using the on-chain math
+//! - [`raydium`]: Raydium CPMM accounts needed by `migrate_to_raydium`
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+pub mod quote;
+pub mod raydium;
+
+pub use proof_of_meme::constants;
+pub use proof_of_meme::errors::ProofOfMemeError;
+pub use proof_of_meme::state;
+pub use proof_of_meme::ID as PROGRAM_ID;
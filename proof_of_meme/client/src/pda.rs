@@ -0,0 +1,67 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::metadata;
+use proof_of_meme::constants::*;
+use proof_of_meme::ID;
+
+pub fn find_platform_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_SEED], &ID)
+}
+
+pub fn find_meme_address(index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MEME_SEED, &index.to_le_bytes()], &ID)
+}
+
+pub fn find_backing_address(meme: &Pubkey, backer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BACKING_SEED, meme.as_ref(), backer.as_ref()], &ID)
+}
+
+/// Proving vault holding backing SOL until launch or refund
+pub fn find_vault_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, meme.as_ref()], &ID)
+}
+
+pub fn find_mint_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_SEED, meme.as_ref()], &ID)
+}
+
+pub fn find_curve_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CURVE_SEED, meme.as_ref()], &ID)
+}
+
+/// Curve SOL vault; also creates the Raydium pool on migration
+pub fn find_curve_vault_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CURVE_VAULT_SEED, meme.as_ref()], &ID)
+}
+
+/// Metaplex metadata for a launched meme's mint, owned by the token metadata program
+pub fn find_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[METADATA_SEED, metadata::ID.as_ref(), mint.as_ref()], &metadata::ID)
+}
+
+pub fn find_genesis_pool_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GENESIS_POOL_SEED, meme.as_ref()], &ID)
+}
+
+/// Native constant-product pool created by `migrate_to_pool`
+pub fn find_pool_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_SEED, meme.as_ref()], &ID)
+}
+
+pub fn find_pool_vault_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_VAULT_SEED, meme.as_ref()], &ID)
+}
+
+pub fn find_lp_mint_address(meme: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LP_MINT_SEED, meme.as_ref()], &ID)
+}
+
+/// Signer for self-CPI event logging (`emit_cpi!`)
+pub fn find_event_authority_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID)
+}
+
+/// Token account holding the meme tokens of `owner`
+pub fn meme_token_address(meme: &Pubkey, owner: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, &find_mint_address(meme).0)
+}
@@ -0,0 +1,81 @@
+//! Off-chain trade quotes. Pricing goes through the same `BondingCurve` and
+//! `Pool` methods the handlers call, and fees through `split_trading_fee`, so a
+//! quote matches what the program will execute against the same account state.
+
+use proof_of_meme::constants::{BPS_DENOMINATOR, TRADING_FEE_BPS};
+use proof_of_meme::state::{BondingCurve, PlatformConfig, Pool, SwapDirection};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Quote {
+    pub amount_in: u64,
+    /// Tokens for a buy, SOL after fees for a sell
+    pub amount_out: u64,
+    pub trading_fee: u64,
+    pub genesis_fee: u64,
+    pub platform_fee: u64,
+    pub burn_fee: u64,
+}
+
+/// Buy on the bonding curve with `sol_amount` lamports, fee included
+pub fn quote_buy(curve: &BondingCurve, platform: &PlatformConfig, sol_amount: u64) -> Option<Quote> {
+    let trading_fee = trading_fee(sol_amount)?;
+    let tokens_out = curve.calculate_buy_tokens(sol_amount.checked_sub(trading_fee)?)?;
+    if tokens_out == 0 {
+        return None;
+    }
+    fees_quote(platform, sol_amount, tokens_out, trading_fee)
+}
+
+/// Sell `token_amount` tokens on the bonding curve; the fee comes out of the SOL proceeds
+pub fn quote_sell(curve: &BondingCurve, platform: &PlatformConfig, token_amount: u64) -> Option<Quote> {
+    let sol_out_gross = curve.calculate_sell_sol(token_amount)?;
+    if sol_out_gross == 0 {
+        return None;
+    }
+    let trading_fee = trading_fee(sol_out_gross)?;
+    fees_quote(platform, token_amount, sol_out_gross.checked_sub(trading_fee)?, trading_fee)
+}
+
+/// Swap against the native pool; the fee is always taken on the SOL side
+pub fn quote_swap(pool: &Pool, platform: &PlatformConfig, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+    match direction {
+        SwapDirection::SolToToken => {
+            let trading_fee = trading_fee(amount_in)?;
+            let tokens_out = pool.calculate_buy_tokens(amount_in.checked_sub(trading_fee)?)?;
+            if tokens_out == 0 || tokens_out >= pool.token_reserves {
+                return None;
+            }
+            fees_quote(platform, amount_in, tokens_out, trading_fee)
+        }
+        SwapDirection::TokenToSol => {
+            let sol_out_gross = pool.calculate_sell_sol(amount_in)?;
+            if sol_out_gross == 0 || sol_out_gross >= pool.sol_reserves {
+                return None;
+            }
+            let trading_fee = trading_fee(sol_out_gross)?;
+            fees_quote(platform, amount_in, sol_out_gross.checked_sub(trading_fee)?, trading_fee)
+        }
+    }
+}
+
+/// Minimum acceptable output for a quote with `slippage_bps` tolerance
+pub fn min_amount_out(amount_out: u64, slippage_bps: u64) -> u64 {
+    let keep_bps = BPS_DENOMINATOR.saturating_sub(slippage_bps);
+    (amount_out as u128 * keep_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+fn trading_fee(sol_amount: u64) -> Option<u64> {
+    sol_amount.checked_mul(TRADING_FEE_BPS)?.checked_div(BPS_DENOMINATOR)
+}
+
+fn fees_quote(platform: &PlatformConfig, amount_in: u64, amount_out: u64, trading_fee: u64) -> Option<Quote> {
+    let (genesis_fee, platform_fee, burn_fee) = platform.split_trading_fee(trading_fee)?;
+    Some(Quote {
+        amount_in,
+        amount_out,
+        trading_fee,
+        genesis_fee,
+        platform_fee,
+        burn_fee,
+    })
+}
@@ -0,0 +1,66 @@
+//! Raydium CPMM addresses for `migrate_to_raydium`
+
+use anchor_lang::prelude::{pubkey, Pubkey};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use proof_of_meme::raydium::RAYDIUM_CPMM_PROGRAM_ID;
+
+/// CPMM AMM config at index 0 (0.25% fee tier)
+pub const AMM_CONFIG: Pubkey = pubkey!("D4FPEruKEHrG5TenZ2mpDGEfu1iUvTiqBxvpU8HLBvC2");
+/// CPMM pool creation fee receiver
+pub const CREATE_POOL_FEE_RECEIVER: Pubkey = pubkey!("DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8");
+
+// CPMM seeds
+const AUTH_SEED: &[u8] = b"vault_and_lp_mint_auth_seed";
+const POOL_SEED: &[u8] = b"pool";
+const POOL_LP_MINT_SEED: &[u8] = b"pool_lp_mint";
+const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+const OBSERVATION_SEED: &[u8] = b"observation";
+
+/// Pool accounts CPMM creates when the curve vault migrates a meme
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaydiumPoolAccounts {
+    pub amm_config: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    /// Curve vault's LP token account; the LP minted into it is burned
+    pub vault_lp_account: Pubkey,
+    pub pool_token_vault: Pubkey,
+    pub pool_wsol_vault: Pubkey,
+    pub create_pool_fee: Pubkey,
+    pub observation_state: Pubkey,
+}
+
+impl RaydiumPoolAccounts {
+    /// Derive the pool for `mint` paired with wSOL under `amm_config`
+    pub fn derive(mint: &Pubkey, curve_vault: &Pubkey, amm_config: &Pubkey, create_pool_fee: &Pubkey) -> Self {
+        let wsol = spl_token::native_mint::ID;
+        // CPMM orders the pair by mint address
+        let (token_0, token_1) = if *mint < wsol { (*mint, wsol) } else { (wsol, *mint) };
+
+        let pool_state = find(&[POOL_SEED, amm_config.as_ref(), token_0.as_ref(), token_1.as_ref()]);
+        let lp_mint = find(&[POOL_LP_MINT_SEED, pool_state.as_ref()]);
+
+        Self {
+            amm_config: *amm_config,
+            authority: find(&[AUTH_SEED]),
+            pool_state,
+            lp_mint,
+            vault_lp_account: get_associated_token_address(curve_vault, &lp_mint),
+            pool_token_vault: find(&[POOL_VAULT_SEED, pool_state.as_ref(), mint.as_ref()]),
+            pool_wsol_vault: find(&[POOL_VAULT_SEED, pool_state.as_ref(), wsol.as_ref()]),
+            create_pool_fee: *create_pool_fee,
+            observation_state: find(&[OBSERVATION_SEED, pool_state.as_ref()]),
+        }
+    }
+
+    /// Mainnet pool using the default AMM config and fee receiver
+    pub fn derive_default(mint: &Pubkey, curve_vault: &Pubkey) -> Self {
+        Self::derive(mint, curve_vault, &AMM_CONFIG, &CREATE_POOL_FEE_RECEIVER)
+    }
+}
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &RAYDIUM_CPMM_PROGRAM_ID).0
+}
@@ -0,0 +1,226 @@
+//! `pom-keeper`: finds memes whose proving period ended and curves that completed,
+//! and submits the lifecycle transition for each, collecting the crank reward
+
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use proof_of_meme_client::accounts::{CURVE_STATUS_OFFSET, MEME_STATUS_OFFSET};
+use proof_of_meme_client::constants::{PAUSE_BACKING, PAUSE_MIGRATION};
+use proof_of_meme_client::instructions as ix;
+use proof_of_meme_client::pda::{find_curve_vault_address, find_platform_address};
+use proof_of_meme_client::raydium::{RaydiumPoolAccounts, AMM_CONFIG, CREATE_POOL_FEE_RECEIVER};
+use proof_of_meme_client::state::{BondingCurve, CurveStatus, Meme, MemeStatus, MigrationTarget, PlatformConfig};
+use proof_of_meme_client::PROGRAM_ID;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use solana_sdk::account::from_account;
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::sysvar;
+use solana_sdk::transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "pom-keeper", version, about = "Crank Proof of Meme lifecycle transitions")]
+struct Cli {
+    /// RPC URL or moniker (localhost, devnet, testnet, mainnet-beta)
+    #[arg(short = 'u', long, env = "POM_RPC_URL", default_value = "localhost")]
+    url: String,
+
+    /// Keypair that signs, pays rent for launches and receives crank rewards
+    #[arg(short = 'k', long, env = "POM_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Seconds between scans
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+
+    /// Scan once and exit
+    #[arg(long)]
+    once: bool,
+
+    #[arg(long, default_value_t = AMM_CONFIG)]
+    amm_config: Pubkey,
+
+    #[arg(long, default_value_t = CREATE_POOL_FEE_RECEIVER)]
+    create_pool_fee: Pubkey,
+}
+
+struct Keeper {
+    rpc: RpcClient,
+    payer: Keypair,
+    amm_config: Pubkey,
+    create_pool_fee: Pubkey,
+}
+
+impl Keeper {
+    /// One pass over every pending transition; a failed transaction is logged and skipped
+    fn scan(&self) -> Result<()> {
+        let platform: PlatformConfig = self.account(&find_platform_address().0)?;
+        // Proving deadlines are checked against the cluster clock, not the local one
+        let now = self.cluster_time()?;
+
+        if platform.is_paused(PAUSE_BACKING) {
+            println!("Backing is paused, skipping proving memes");
+        } else {
+            for (address, meme) in self.accounts_with_status::<Meme>(MEME_STATUS_OFFSET, MemeStatus::Proving as u8)? {
+                if meme.frozen || !meme.can_finalize(now) {
+                    continue;
+                }
+                let (action, instructions) = if meme.goal_reached() {
+                    // Launch creates five accounts and the token metadata
+                    let instructions = vec![
+                        ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+                        ix::finalize_proving(&self.payer.pubkey(), &address, &meme.token_program.id()),
+                    ];
+                    ("finalize", instructions)
+                } else {
+                    ("mark failed", vec![ix::mark_meme_failed(&self.payer.pubkey(), &address)])
+                };
+                self.crank(meme.index, action, &instructions);
+            }
+        }
+
+        if platform.is_paused(PAUSE_MIGRATION) {
+            println!("Migration is paused, skipping completed curves");
+        } else {
+            for (_, curve) in self.accounts_with_status::<BondingCurve>(CURVE_STATUS_OFFSET, CurveStatus::Complete as u8)? {
+                let meme: Meme = match self.account(&curve.meme) {
+                    Ok(meme) => meme,
+                    Err(err) => {
+                        eprintln!("{err:#}");
+                        continue;
+                    }
+                };
+                if meme.frozen || meme.status != MemeStatus::Launched {
+                    continue;
+                }
+                let instructions = self.migrate_instructions(&curve, &meme, &platform.fee_recipient);
+                self.crank(meme.index, "migrate", &instructions);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn migrate_instructions(&self, curve: &BondingCurve, meme: &Meme, fee_recipient: &Pubkey) -> Vec<Instruction> {
+        let migrator = self.payer.pubkey();
+        let token_program = meme.token_program.id();
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(400_000)];
+        // Migration refuses to run while burn fees (e.g. from the completing buy) are unspent
+        if curve.pending_burn_fees() > 0 {
+            instructions.push(ix::execute_buyback_burn(&migrator, &curve.meme, &token_program));
+        }
+        // Each meme migrates to the venue its creator picked at submission
+        instructions.push(match meme.curve_params.migration_target {
+            MigrationTarget::Raydium => {
+                let curve_vault = find_curve_vault_address(&curve.meme).0;
+                let pool = RaydiumPoolAccounts::derive(&curve.mint, &curve_vault, &self.amm_config, &self.create_pool_fee);
+                ix::migrate_to_raydium(&migrator, &curve.meme, &token_program, fee_recipient, &pool)
+            }
+            MigrationTarget::NativePool => ix::migrate_to_pool(&migrator, &curve.meme, &token_program, fee_recipient),
+        });
+        instructions
+    }
+
+    /// Another keeper may win the race for the same transition, so failures are not fatal
+    fn crank(&self, index: u64, action: &str, instructions: &[Instruction]) {
+        match self.send(instructions) {
+            Ok(signature) => println!("Meme #{index}: {action} {signature}"),
+            Err(err) => eprintln!("Meme #{index}: {action} failed: {err:#}"),
+        }
+    }
+
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&self.payer.pubkey()), &[&self.payer], blockhash);
+        Ok(self.rpc.send_and_confirm_transaction(&tx)?)
+    }
+
+    fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let data = self.rpc.get_account_data(address).with_context(|| format!("failed to fetch {address}"))?;
+        T::try_deserialize(&mut data.as_slice()).map_err(|err| anyhow!("failed to decode {address}: {err}"))
+    }
+
+    /// Program accounts of type `T` whose status byte at `offset` equals `status`
+    fn accounts_with_status<T: AccountDeserialize + Discriminator>(&self, offset: usize, status: u8) -> Result<Vec<(Pubkey, T)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, T::DISCRIMINATOR.to_vec())),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, vec![status])),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let mut accounts: Vec<(Pubkey, T)> = self
+            .rpc
+            .get_program_accounts_with_config(&PROGRAM_ID, config)?
+            .into_iter()
+            .filter_map(|(address, account)| Some((address, T::try_deserialize(&mut account.data.as_slice()).ok()?)))
+            .collect();
+        accounts.sort_by_key(|(address, _)| *address);
+        Ok(accounts)
+    }
+
+    fn cluster_time(&self) -> Result<i64> {
+        let account = self.rpc.get_account(&sysvar::clock::ID)?;
+        let clock: Clock = from_account(&account).context("failed to decode clock sysvar")?;
+        Ok(clock.unix_timestamp)
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let keeper = Keeper {
+        rpc: RpcClient::new_with_commitment(rpc_url(&cli.url), CommitmentConfig::confirmed()),
+        payer: load_keypair(&cli.keypair)?,
+        amm_config: cli.amm_config,
+        create_pool_fee: cli.create_pool_fee,
+    };
+    println!("Keeper {} watching {}", keeper.payer.pubkey(), keeper.rpc.url());
+
+    loop {
+        if let Err(err) = keeper.scan() {
+            if cli.once {
+                return Err(err);
+            }
+            eprintln!("Scan failed: {err:#}");
+        }
+        if cli.once {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(cli.interval));
+    }
+}
+
+fn rpc_url(url: &str) -> String {
+    match url {
+        "localhost" | "l" => "http://127.0.0.1:8899",
+        "devnet" | "d" => "https://api.devnet.solana.com",
+        "testnet" | "t" => "https://api.testnet.solana.com",
+        "mainnet-beta" | "m" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn load_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => Path::new(&std::env::var("HOME").context("HOME is not set")?).join(rest),
+        None => PathBuf::from(path),
+    };
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {}: {err}", path.display()))
+}
@@ -10,9 +10,15 @@ pub const MAX_BACKING_PERCENTAGE_BPS: u64 = 1_000; // 10% max per wallet
 // Time constraints
 pub const MIN_PROVING_DURATION: i64 = 24 * 60 * 60; // 24 hours
 pub const MAX_PROVING_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const MIN_LAUNCH_WINDOW: i64 = 10 * 60; // 10 minutes before a launch_on_goal meme can launch
+pub const DEFAULT_LAUNCH_WINDOW: i64 = 60 * 60; // 1 hour
 
 // Backing constraints
 pub const MIN_BACKING_AMOUNT: u64 = 500_000_000; // 0.5 SOL minimum for fee eligibility
+pub const MAX_UNBACK_PENALTY_BPS: u16 = 1_000; // 10% max exit penalty for unbacking during proving
+
+// Creator edits to uri/description lock once backing reaches this share of the goal
+pub const METADATA_LOCK_BPS: u64 = 2_500; // 25%
 
 // Token supply
 pub const TOKEN_DECIMALS: u8 = 6;
@@ -21,9 +27,33 @@ pub const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000; // 1 billion with 6 decimal
 // Bonding curve
 pub const CURVE_COMPLETION_SOL: u64 = 85_000_000_000; // ~85 SOL to complete curve (like pump.fun)
 pub const GENESIS_ALLOCATION_BPS: u64 = 2_000; // 20% of supply to genesis backers
+pub const MAX_GENESIS_ALLOCATION_BPS: u64 = 5_000; // creators can give backers at most half the supply
+pub const MAX_CURVE_BREAKPOINTS: usize = 8; // piecewise-linear curves
+pub const EXPONENTIAL_CURVE_STEPS: usize = 16; // exponential curves compound over this many equal slices of the supply
+pub const MAX_CURVE_PRICE_MULTIPLE_BPS: u32 = 10_000_000; // non-constant-product curves end at most 1000x the opening price
+pub const CURVE_PRICE_SCALE: u128 = 1_000_000_000_000; // their prices are lamports per token base unit * 1e12
+
+// Platform config
+pub const MAX_CONFIG_TIMELOCK: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Emergency pause flags (PlatformConfig.paused bitmask)
+pub const PAUSE_SUBMISSIONS: u8 = 1 << 0; // submit_meme, update_meme_metadata
+pub const PAUSE_BACKING: u8 = 1 << 1; // back, unback, withdraw, refund, finalize, mark failed, cancel
+pub const PAUSE_TRADING: u8 = 1 << 2; // curve buy/sell, pool swap and liquidity
+pub const PAUSE_CLAIMS: u8 = 1 << 3; // genesis fees and tokens
+pub const PAUSE_MIGRATION: u8 = 1 << 4; // migrate to Raydium or native pool
+pub const PAUSE_ALL: u8 = PAUSE_SUBMISSIONS | PAUSE_BACKING | PAUSE_TRADING | PAUSE_CLAIMS | PAUSE_MIGRATION;
+
+// Genesis vesting
+pub const MAX_VESTING_DURATION: i64 = 365 * 24 * 60 * 60; // 1 year
+
+// Crank rewards, escrowed from the submission fee or taken from the migration fee
+pub const MIN_CRANK_REWARD: u64 = 1_000_000; // 0.001 SOL, keeps the escrowing vault rent-exempt
+pub const MAX_CRANK_REWARD: u64 = 100_000_000; // 0.1 SOL
 
 // Migration
 pub const MIGRATION_FEE: u64 = 1_500_000_000; // 1.5 SOL
+pub const RAYDIUM_POOL_RENT: u64 = 100_000_000; // 0.1 SOL (rent for the pool accounts CPMM creates; the create fee comes from the AMM config)
 
 // Default fee splits (can be configured)
 pub const DEFAULT_PLATFORM_FEE_BPS: u16 = 2_000; // 20% of trading fee
@@ -38,6 +68,12 @@ pub const BACKING_SEED: &[u8] = b"backing";
 pub const CURVE_SEED: &[u8] = b"curve";
 pub const GENESIS_POOL_SEED: &[u8] = b"genesis_pool";
 pub const VAULT_SEED: &[u8] = b"vault";
+pub const POOL_SEED: &[u8] = b"pool";
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const CURVE_VAULT_SEED: &[u8] = b"curve_vault";
+pub const MINT_SEED: &[u8] = b"mint";
+pub const METADATA_SEED: &[u8] = b"metadata"; // Metaplex token metadata PDA prefix
 
 // String length limits
 pub const MAX_NAME_LENGTH: usize = 32;
@@ -21,6 +21,16 @@ pub enum ProofOfMemeError {
     DurationTooShort,
     #[msg("Duration above maximum (7 days)")]
     DurationTooLong,
+    #[msg("SOL cap below goal")]
+    InvalidSolCap,
+    #[msg("Curve parameters outside platform bounds")]
+    InvalidCurveParams,
+    #[msg("Invalid curve shape")]
+    InvalidCurveShape,
+    #[msg("Curve shape sells out before the curve completes")]
+    CurveSellsOut,
+    #[msg("Metadata is locked once backing reaches the lock threshold")]
+    MetadataLocked,
 
     // Backing errors
     #[msg("Backing amount below minimum (0.5 SOL for fee eligibility)")]
@@ -37,6 +47,14 @@ pub enum ProofOfMemeError {
     AlreadyFailed,
     #[msg("No backing found for this wallet")]
     NoBackingFound,
+    #[msg("No oversubscription refund available")]
+    NoRefundAvailable,
+    #[msg("Oversubscription refund already claimed")]
+    RefundAlreadyClaimed,
+    #[msg("Unbacking exceeds current backing")]
+    UnbackExceedsBacking,
+    #[msg("Only the creator can change a meme")]
+    NotMemeCreator,
 
     // Goal errors
     #[msg("SOL goal not reached")]
@@ -61,12 +79,36 @@ pub enum ProofOfMemeError {
     NoFeesToClaim,
     #[msg("Not a genesis backer")]
     NotGenesisBacker,
+    #[msg("No burn fees to spend")]
+    NoBurnFees,
+
+    // Genesis token errors
+    #[msg("Genesis tokens already claimed")]
+    GenesisTokensAlreadyClaimed,
+    #[msg("No genesis tokens to claim")]
+    NoTokensToClaim,
+    #[msg("Genesis tokens not yet allocated - claim genesis tokens first")]
+    GenesisTokensNotAllocated,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
 
     // Migration errors
     #[msg("Curve not complete - cannot migrate yet")]
     CurveNotComplete,
     #[msg("Already migrated")]
     AlreadyMigrated,
+    #[msg("Burn fees still pending - execute the buyback before migrating")]
+    BurnFeesPending,
+    #[msg("Meme migrates to a different venue")]
+    WrongMigrationTarget,
+    #[msg("Invalid Raydium account")]
+    InvalidRaydiumAccount,
+
+    // Pool errors
+    #[msg("Invalid pool account")]
+    InvalidPoolAccount,
+    #[msg("Insufficient pool liquidity")]
+    InsufficientLiquidity,
 
     // Math errors
     #[msg("Math overflow")]
@@ -74,9 +116,37 @@ pub enum ProofOfMemeError {
     #[msg("Invalid fee configuration")]
     InvalidFeeConfig,
 
+    // Config errors
+    #[msg("Invalid config timelock")]
+    InvalidConfigTimelock,
+    #[msg("No pending config update")]
+    NoPendingConfig,
+    #[msg("Config timelock has not elapsed")]
+    ConfigTimelockActive,
+    #[msg("Invalid crank reward")]
+    InvalidCrankReward,
+    #[msg("Invalid launch window")]
+    InvalidLaunchWindow,
+    #[msg("Invalid unback penalty")]
+    InvalidUnbackPenalty,
+    #[msg("Invalid curve parameter bounds")]
+    InvalidCurveBounds,
+    #[msg("Platform config is already on the current layout")]
+    PlatformConfigCurrent,
+
+    // Pause errors
+    #[msg("Action is paused")]
+    Paused,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
     // Security errors
     #[msg("Invalid platform authority")]
     InvalidPlatformAuthority,
+    #[msg("Invalid platform fee recipient")]
+    InvalidFeeRecipient,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
     #[msg("Amount must be greater than zero")]
     ZeroAmount,
     #[msg("Insufficient vault balance")]
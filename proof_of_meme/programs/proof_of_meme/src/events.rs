@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::state::{CurveParams, TokenProgram};
+
+/// A meme entered the Proving Grounds
+#[event]
+pub struct MemeSubmitted {
+    pub meme: Pubkey,
+    pub creator: Pubkey,
+    pub index: u64,
+    pub sol_goal: u64,
+    pub min_backers: u32,
+    pub proving_ends_at: i64,
+    pub launch_on_goal: bool,
+    /// 0 = uncapped
+    pub sol_cap: u64,
+    pub token_program: TokenProgram,
+    pub curve_params: CurveParams,
+    pub timestamp: i64,
+}
+
+/// The creator changed the pitch during proving
+#[event]
+pub struct MemeMetadataUpdated {
+    pub meme: Pubkey,
+    pub uri: String,
+    pub description: String,
+    pub metadata_updates: u16,
+    pub timestamp: i64,
+}
+
+/// A backer committed SOL during proving
+#[event]
+pub struct MemeBacked {
+    pub meme: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    /// Backer's running total for this meme
+    pub backer_total: u64,
+    pub sol_backed: u64,
+    pub backer_count: u32,
+    pub timestamp: i64,
+}
+
+/// A backer pulled some or all of their SOL out during proving
+#[event]
+pub struct MemeUnbacked {
+    pub meme: Pubkey,
+    pub backer: Pubkey,
+    /// Backing removed, including the penalty
+    pub amount: u64,
+    /// Kept by the platform
+    pub penalty: u64,
+    /// Backer's remaining total for this meme (0 = fully withdrawn)
+    pub backer_total: u64,
+    pub sol_backed: u64,
+    pub backer_count: u32,
+    pub timestamp: i64,
+}
+
+/// Proving succeeded, token minted and curve opened
+#[event]
+pub struct MemeLaunched {
+    pub meme: Pubkey,
+    pub mint: Pubkey,
+    /// Program that owns the mint
+    pub token_program: Pubkey,
+    pub curve: Pubkey,
+    pub sol_backed: u64,
+    /// Backing above the cap, refundable pro rata
+    pub sol_oversubscribed: u64,
+    pub backer_count: u32,
+    pub genesis_tokens: u64,
+    pub curve_tokens: u64,
+    pub timestamp: i64,
+}
+
+/// Proving ended without reaching the goal
+#[event]
+pub struct MemeFailed {
+    pub meme: Pubkey,
+    pub sol_backed: u64,
+    pub backer_count: u32,
+    pub timestamp: i64,
+}
+
+/// The creator withdrew a meme during proving
+#[event]
+pub struct MemeCancelled {
+    pub meme: Pubkey,
+    pub creator: Pubkey,
+    pub sol_backed: u64,
+    pub backer_count: u32,
+    /// Escrowed crank reward returned to the creator
+    pub crank_reward_returned: u64,
+    pub timestamp: i64,
+}
+
+/// A backer reclaimed their share of an oversubscribed launch
+#[event]
+pub struct BackingRefunded {
+    pub meme: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// A permissionless caller was paid for executing a lifecycle transition
+#[event]
+pub struct CrankRewardPaid {
+    pub meme: Pubkey,
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// A backer was refunded from a failed meme
+#[event]
+pub struct BackingWithdrawn {
+    pub meme: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// A trade against the bonding curve or native pool.
+/// Reserves are the venue's real reserves, whatever the curve shape prices off.
+#[event]
+pub struct TradeExecuted {
+    pub meme: Pubkey,
+    /// Trader wallet (the curve itself for buyback-and-burn)
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    /// Gross SOL side of the trade, before fees
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub trading_fee: u64,
+    pub pre_sol_reserves: u64,
+    pub pre_token_reserves: u64,
+    pub post_sol_reserves: u64,
+    pub post_token_reserves: u64,
+    /// Price after the trade, in lamports per whole token
+    pub post_price: u64,
+    pub timestamp: i64,
+}
+
+/// A genesis backer claimed their share of trading fees
+#[event]
+pub struct GenesisFeesClaimed {
+    pub meme: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    /// Backer's running total of claimed fees
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+/// The bonding curve hit its completion threshold
+#[event]
+pub struct CurveCompleted {
+    pub meme: Pubkey,
+    pub curve: Pubkey,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+/// Curve liquidity moved to an AMM pool
+#[event]
+pub struct Migrated {
+    pub meme: Pubkey,
+    /// Raydium CPMM pool state or native pool account
+    pub pool: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub migration_fee: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::{PlatformConfig, Meme, MemeStatus, Pool};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotComplete,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Box<Account<'info, Meme>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, meme.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.meme == meme.key() @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        address = pool.lp_mint @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool's token reserves
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's SOL vault
+    #[account(
+        mut,
+        seeds = [POOL_VAULT_SEED, meme.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub pool_vault: SystemAccount<'info>,
+
+    /// Provider's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's LP token account
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_liquidity(
+    ctx: Context<AddLiquidity>,
+    sol_amount: u64,
+    max_token_amount: u64,
+    min_lp_out: u64,
+) -> Result<()> {
+    // Validate input amount
+    require!(sol_amount > 0, ProofOfMemeError::ZeroAmount);
+
+    // Deposit at the current reserve ratio
+    let (token_amount, lp_out) = ctx.accounts.pool.calculate_deposit(sol_amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    require!(lp_out > 0, ProofOfMemeError::InvalidTokenAmount);
+    require!(token_amount <= max_token_amount, ProofOfMemeError::SlippageExceeded);
+    require!(lp_out >= min_lp_out, ProofOfMemeError::SlippageExceeded);
+
+    // Transfer SOL to pool vault
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+            },
+        ),
+        sol_amount,
+    )?;
+
+    // Transfer tokens to pool
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.provider_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        token_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Mint LP tokens to provider
+    let meme_key = ctx.accounts.meme.key();
+    let pool_seeds = &[
+        POOL_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        lp_out,
+    )?;
+
+    // Update pool state
+    let pool = &mut ctx.accounts.pool;
+    pool.sol_reserves = pool.sol_reserves.checked_add(sol_amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.token_reserves = pool.token_reserves.checked_add(token_amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.lp_supply = pool.lp_supply.checked_add(lp_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    msg!("Added {} lamports and {} tokens for {} LP", sol_amount, token_amount, lp_out);
+
+    Ok(())
+}
@@ -1,19 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{Meme, MemeStatus, Backing};
+use crate::state::{PlatformConfig, Meme, MemeStatus, Backing};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::MemeBacked;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BackMeme<'info> {
     #[account(mut)]
     pub backer: Signer<'info>,
 
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
     #[account(
         mut,
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -102,11 +112,27 @@ pub fn back_meme(ctx: Context<BackMeme>, amount: u64) -> Result<()> {
     meme.sol_backed = meme.sol_backed.checked_add(amount)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    // Lock the pitch once enough backers have bought into it
+    let lock_threshold = meme.metadata_lock_threshold().ok_or(ProofOfMemeError::MathOverflow)?;
+    if meme.sol_backed >= lock_threshold {
+        meme.metadata_locked = true;
+    }
+
     // Track if creator is backing their own meme (transparency)
     if ctx.accounts.backer.key() == meme.creator {
         meme.creator_backing = new_total;
     }
 
+    emit_cpi!(MemeBacked {
+        meme: meme.key(),
+        backer: ctx.accounts.backer.key(),
+        amount,
+        backer_total: new_total,
+        sol_backed: meme.sol_backed,
+        backer_count: meme.backer_count,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Backed meme with {} lamports (total backing: {})", amount, new_total);
     msg!("Progress: {}/{} SOL, {}/{} backers",
         meme.sol_backed / 1_000_000_000,
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, GenesisPool};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::{TradeExecuted, CurveCompleted};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BuyTokens<'info> {
     #[account(mut)]
@@ -12,7 +14,8 @@ pub struct BuyTokens<'info> {
 
     #[account(
         seeds = [PLATFORM_SEED],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -20,7 +23,8 @@ pub struct BuyTokens<'info> {
         // Validate meme has correct seed derivation
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive
+        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -43,26 +47,31 @@ pub struct BuyTokens<'info> {
     )]
     pub genesis_pool: Account<'info, GenesisPool>,
 
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     /// Curve's token account
     #[account(
         mut,
-        associated_token::mint = meme.mint,
-        associated_token::authority = curve
+        associated_token::mint = mint,
+        associated_token::authority = curve,
+        associated_token::token_program = token_program
     )]
-    pub curve_token_account: Account<'info, TokenAccount>,
+    pub curve_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Buyer's token account
     #[account(
         mut,
-        associated_token::mint = meme.mint,
-        associated_token::authority = buyer
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Curve's SOL vault
     #[account(
         mut,
-        seeds = [b"curve_vault", meme.key().as_ref()],
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
         bump = curve.vault_bump
     )]
     /// CHECK: PDA vault validated by seeds
@@ -71,12 +80,12 @@ pub struct BuyTokens<'info> {
     /// Platform fee recipient - MUST match platform config
     #[account(
         mut,
-        constraint = platform_authority.key() == platform.authority @ ProofOfMemeError::InvalidPlatformAuthority
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
     )]
     /// CHECK: Validated against platform config
-    pub platform_authority: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -108,21 +117,7 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64)
     require!(tokens_out <= ctx.accounts.curve.real_token_reserves, ProofOfMemeError::InsufficientTokens);
 
     // Calculate fee distribution
-    let genesis_fee = trading_fee
-        .checked_mul(platform.genesis_fee_bps as u64)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-
-    let platform_fee = trading_fee
-        .checked_mul(platform.platform_fee_bps as u64)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-
-    let burn_fee = trading_fee.checked_sub(genesis_fee)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_sub(platform_fee)
+    let (genesis_fee, platform_fee, burn_fee) = platform.split_trading_fee(trading_fee)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
     // Transfer SOL from buyer to curve vault (minus platform fee)
@@ -147,7 +142,7 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64)
                 ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
                     from: ctx.accounts.buyer.to_account_info(),
-                    to: ctx.accounts.platform_authority.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
                 },
             ),
             platform_fee,
@@ -165,17 +160,19 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64)
     ];
     let signer_seeds = &[&curve_seeds[..]];
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
+            TransferChecked {
                 from: ctx.accounts.curve_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.buyer_token_account.to_account_info(),
                 authority: ctx.accounts.curve.to_account_info(),
             },
             signer_seeds,
         ),
         tokens_out,
+        ctx.accounts.mint.decimals,
     )?;
 
     // Now take mutable references for state updates
@@ -183,6 +180,8 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64)
     let genesis_pool = &mut ctx.accounts.genesis_pool;
 
     // Update curve state
+    let pre_sol_reserves = curve.real_sol_reserves;
+    let pre_token_reserves = curve.real_token_reserves;
     curve.apply_buy(sol_after_fee, tokens_out);
     curve.genesis_fees_accumulated = curve.genesis_fees_accumulated
         .checked_add(genesis_fee)
@@ -199,9 +198,32 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64)
         .checked_add(genesis_fee)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    let clock = Clock::get()?;
+    emit_cpi!(TradeExecuted {
+        meme: meme_key,
+        trader: ctx.accounts.buyer.key(),
+        is_buy: true,
+        sol_amount,
+        token_amount: tokens_out,
+        trading_fee,
+        pre_sol_reserves,
+        pre_token_reserves,
+        post_sol_reserves: curve.real_sol_reserves,
+        post_token_reserves: curve.real_token_reserves,
+        post_price: curve.get_current_price().ok_or(ProofOfMemeError::MathOverflow)?,
+        timestamp: clock.unix_timestamp,
+    });
+
     // Check if curve is complete
     if curve.is_complete() {
         curve.status = CurveStatus::Complete;
+        emit_cpi!(CurveCompleted {
+            meme: meme_key,
+            curve: curve.key(),
+            real_sol_reserves: curve.real_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            timestamp: clock.unix_timestamp,
+        });
         msg!("Bonding curve complete! Ready for Raydium migration.");
     }
 
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, Meme, MemeStatus};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::MemeCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMeme<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.creator == creator.key() @ ProofOfMemeError::NotMemeCreator,
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Account<'info, Meme>,
+
+    /// The vault holding backing SOL and the escrowed crank reward
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, meme.key().as_ref()],
+        bump = meme.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_meme(ctx: Context<CancelMeme>) -> Result<()> {
+    let clock = Clock::get()?;
+    let meme = &mut ctx.accounts.meme;
+
+    // Once the meme can be finalized its outcome belongs to the backers, not the creator
+    require!(!meme.can_finalize(clock.unix_timestamp), ProofOfMemeError::ProvingEnded);
+
+    meme.status = MemeStatus::Cancelled;
+
+    // The submission fee is forfeited; the escrowed crank reward goes back since no crank is needed
+    let crank_reward = meme.crank_reward;
+    if crank_reward > 0 {
+        let meme_key = meme.key();
+        let vault_seeds = &[
+            VAULT_SEED,
+            meme_key.as_ref(),
+            &[meme.vault_bump],
+        ];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                &[&vault_seeds[..]],
+            ),
+            crank_reward,
+        )?;
+        meme.crank_reward = 0;
+    }
+
+    emit_cpi!(MemeCancelled {
+        meme: meme.key(),
+        creator: meme.creator,
+        sol_backed: meme.sol_backed,
+        backer_count: meme.backer_count,
+        crank_reward_returned: crank_reward,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Meme {} cancelled by creator - backers can withdraw.", meme.get_name());
+
+    Ok(())
+}
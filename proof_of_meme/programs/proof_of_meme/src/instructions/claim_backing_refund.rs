@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, Meme, MemeStatus, Backing};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::BackingRefunded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBackingRefund<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        // Refunds stay claimable after migration
+        constraint = meme.status == MemeStatus::Launched || meme.status == MemeStatus::Migrated @ ProofOfMemeError::ProvingStillActive,
+        constraint = meme.sol_oversubscribed > 0 @ ProofOfMemeError::NoRefundAvailable,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Account<'info, Meme>,
+
+    #[account(
+        mut,
+        seeds = [BACKING_SEED, meme.key().as_ref(), backer.key().as_ref()],
+        bump = backing.bump,
+        constraint = backing.backer == backer.key() @ ProofOfMemeError::NoBackingFound,
+        constraint = backing.meme == meme.key() @ ProofOfMemeError::AccountMismatch,
+        constraint = backing.refunded == 0 @ ProofOfMemeError::RefundAlreadyClaimed
+    )]
+    pub backing: Account<'info, Backing>,
+
+    /// The proving vault, which kept the oversubscription at launch
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, meme.key().as_ref()],
+        bump = meme.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_backing_refund(ctx: Context<ClaimBackingRefund>) -> Result<()> {
+    let meme = &ctx.accounts.meme;
+    let refund = meme.oversubscription_refund(ctx.accounts.backing.amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    require!(refund > 0, ProofOfMemeError::NoRefundAvailable);
+
+    let vault_balance = ctx.accounts.vault.lamports();
+    require!(refund <= vault_balance, ProofOfMemeError::InsufficientVaultBalance);
+
+    let meme_key = meme.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        meme_key.as_ref(),
+        &[meme.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.backer.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        refund,
+    )?;
+
+    // amount is left as backed: genesis shares are computed on full amounts
+    let backing = &mut ctx.accounts.backing;
+    backing.refunded = refund;
+
+    emit_cpi!(BackingRefunded {
+        meme: meme_key,
+        backer: ctx.accounts.backer.key(),
+        amount: refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Refunded {} lamports of oversubscribed backing", refund);
+
+    Ok(())
+}
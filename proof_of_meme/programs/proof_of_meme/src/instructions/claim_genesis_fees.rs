@@ -1,18 +1,29 @@
 use anchor_lang::prelude::*;
-use crate::state::{Meme, MemeStatus, Backing, BondingCurve, GenesisPool};
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, Meme, MemeStatus, Backing, BondingCurve, GenesisPool};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::GenesisFeesClaimed;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimGenesisFees<'info> {
     #[account(mut)]
     pub backer: Signer<'info>,
 
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_CLAIMS) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
     #[account(
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
         // Can claim while launched OR after migration (claim remaining)
-        constraint = meme.status == MemeStatus::Launched || meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotActive
+        constraint = meme.status == MemeStatus::Launched || meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -45,7 +56,7 @@ pub struct ClaimGenesisFees<'info> {
     /// Curve's SOL vault (where genesis fees accumulate)
     #[account(
         mut,
-        seeds = [b"curve_vault", meme.key().as_ref()],
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
         bump = curve.vault_bump
     )]
     /// CHECK: PDA vault validated by seeds
@@ -70,14 +81,24 @@ pub fn claim_genesis_fees(ctx: Context<ClaimGenesisFees>) -> Result<()> {
     require!(claimable <= vault_balance, ProofOfMemeError::InsufficientVaultBalance);
 
     // Transfer fees from curve vault to backer
-    **ctx.accounts.curve_vault.try_borrow_mut_lamports()? = ctx.accounts.curve_vault
-        .lamports()
-        .checked_sub(claimable)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-    **ctx.accounts.backer.try_borrow_mut_lamports()? = ctx.accounts.backer
-        .lamports()
-        .checked_add(claimable)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
+    let meme_key = ctx.accounts.meme.key();
+    let curve_vault_seeds = &[
+        CURVE_VAULT_SEED,
+        meme_key.as_ref(),
+        &[curve.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.backer.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        claimable,
+    )?;
 
     // Update tracking
     backing.fees_claimed = backing.fees_claimed
@@ -92,6 +113,14 @@ pub fn claim_genesis_fees(ctx: Context<ClaimGenesisFees>) -> Result<()> {
         .checked_add(claimable)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    emit_cpi!(GenesisFeesClaimed {
+        meme: ctx.accounts.meme.key(),
+        backer: ctx.accounts.backer.key(),
+        amount: claimable,
+        total_claimed: backing.fees_claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Claimed {} lamports in genesis fees", claimable);
     msg!("Total fees claimed by this backer: {} lamports", backing.fees_claimed);
 
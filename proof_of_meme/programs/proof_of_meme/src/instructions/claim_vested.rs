@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{PlatformConfig, Meme, MemeStatus, Backing, GenesisPool};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_CLAIMS) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        // Can claim while launched OR after migration
+        constraint = meme.status == MemeStatus::Launched || meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Account<'info, Meme>,
+
+    #[account(
+        mut,
+        seeds = [BACKING_SEED, meme.key().as_ref(), backer.key().as_ref()],
+        bump = backing.bump,
+        constraint = backing.backer == backer.key() @ ProofOfMemeError::NotGenesisBacker,
+        constraint = backing.meme == meme.key() @ ProofOfMemeError::AccountMismatch,
+        constraint = !backing.withdrawn @ ProofOfMemeError::BackingAlreadyWithdrawn,
+        constraint = backing.tokens_received > 0 @ ProofOfMemeError::GenesisTokensNotAllocated
+    )]
+    pub backing: Account<'info, Backing>,
+
+    #[account(
+        mut,
+        seeds = [GENESIS_POOL_SEED, meme.key().as_ref()],
+        bump = genesis_pool.bump,
+        constraint = genesis_pool.meme == meme.key() @ ProofOfMemeError::AccountMismatch
+    )]
+    pub genesis_pool: Account<'info, GenesisPool>,
+
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Genesis pool's token account holding the genesis allocation
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = genesis_pool,
+        associated_token::token_program = token_program
+    )]
+    pub genesis_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Backer's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = backer,
+        associated_token::token_program = token_program
+    )]
+    pub backer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let clock = Clock::get()?;
+    let backing = &ctx.accounts.backing;
+    let genesis_pool = &ctx.accounts.genesis_pool;
+
+    // Only the portion unlocked so far, minus what was already released
+    let vested = genesis_pool.calculate_vested(backing.tokens_received, clock.unix_timestamp)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    let claimable = vested.saturating_sub(backing.tokens_claimed);
+
+    require!(claimable > 0, ProofOfMemeError::NoTokensToClaim);
+
+    // CRITICAL: Verify escrow has enough tokens
+    require!(
+        claimable <= ctx.accounts.genesis_token_account.amount,
+        ProofOfMemeError::InsufficientTokens
+    );
+
+    // Transfer unlocked tokens from genesis escrow to backer
+    let meme_key = ctx.accounts.meme.key();
+    let pool_seeds = &[
+        GENESIS_POOL_SEED,
+        meme_key.as_ref(),
+        &[genesis_pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.genesis_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.backer_token_account.to_account_info(),
+                authority: ctx.accounts.genesis_pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        claimable,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Update tracking
+    let backing = &mut ctx.accounts.backing;
+    let genesis_pool = &mut ctx.accounts.genesis_pool;
+
+    backing.tokens_claimed = backing.tokens_claimed
+        .checked_add(claimable)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    genesis_pool.tokens_distributed = genesis_pool.tokens_distributed
+        .checked_add(claimable)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    msg!("Claimed {} vested genesis tokens", claimable);
+    msg!("Released {}/{} genesis tokens", backing.tokens_claimed, backing.tokens_received);
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::{TradeExecuted, CurveCompleted};
+
+// Permissionless - anyone can crank the buyback once burn fees have accrued. It stays open
+// after completion so the fees from the completing buy are spent before migration.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteBuybackBurn<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Account<'info, Meme>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_SEED, meme.key().as_ref()],
+        bump = curve.bump,
+        constraint = curve.meme == meme.key() @ ProofOfMemeError::InvalidCurveAccount,
+        constraint = curve.status != CurveStatus::Migrated @ ProofOfMemeError::AlreadyMigrated
+    )]
+    pub curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        address = meme.mint @ ProofOfMemeError::AccountMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Curve's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = curve,
+        associated_token::token_program = token_program
+    )]
+    pub curve_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Curve's SOL vault, already holding the burn fees
+    #[account(
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
+        bump = curve.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub curve_vault: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn execute_buyback_burn(ctx: Context<ExecuteBuybackBurn>) -> Result<()> {
+    let curve = &ctx.accounts.curve;
+
+    let sol_in = curve.pending_burn_fees();
+    require!(sol_in > 0, ProofOfMemeError::NoBurnFees);
+
+    // Burn fees sit in the vault outside real_sol_reserves; make sure they are really there
+    let required_balance = curve.real_sol_reserves
+        .checked_add(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    require!(
+        ctx.accounts.curve_vault.lamports() >= required_balance,
+        ProofOfMemeError::InsufficientVaultBalance
+    );
+
+    // Buy at the current curve price (no trading fee on the protocol's own buy)
+    let tokens_out = curve.calculate_buy_tokens(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    require!(tokens_out > 0, ProofOfMemeError::InvalidTokenAmount);
+    require!(tokens_out <= ctx.accounts.curve_token_account.amount, ProofOfMemeError::InsufficientTokens);
+
+    // Burn the bought tokens straight out of the curve's account
+    let meme_key = ctx.accounts.meme.key();
+    let curve_seeds = &[
+        CURVE_SEED,
+        meme_key.as_ref(),
+        &[curve.bump],
+    ];
+    let signer_seeds = &[&curve_seeds[..]];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.curve_token_account.to_account_info(),
+                authority: ctx.accounts.curve.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_out,
+    )?;
+
+    // Lamports never move - the burn fees simply become curve reserves
+    let curve = &mut ctx.accounts.curve;
+    let pre_sol_reserves = curve.real_sol_reserves;
+    let pre_token_reserves = curve.real_token_reserves;
+    curve.apply_buy(sol_in, tokens_out);
+    curve.burn_fees_spent = curve.burn_fees_spent
+        .checked_add(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    curve.tokens_burned = curve.tokens_burned
+        .checked_add(tokens_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    let clock = Clock::get()?;
+    emit_cpi!(TradeExecuted {
+        meme: meme_key,
+        trader: curve.key(),
+        is_buy: true,
+        sol_amount: sol_in,
+        token_amount: tokens_out,
+        trading_fee: 0,
+        pre_sol_reserves,
+        pre_token_reserves,
+        post_sol_reserves: curve.real_sol_reserves,
+        post_token_reserves: curve.real_token_reserves,
+        post_price: curve.get_current_price().ok_or(ProofOfMemeError::MathOverflow)?,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Check if curve is complete
+    if curve.status == CurveStatus::Active && curve.is_complete() {
+        curve.status = CurveStatus::Complete;
+        emit_cpi!(CurveCompleted {
+            meme: meme_key,
+            curve: curve.key(),
+            real_sol_reserves: curve.real_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("Bonding curve complete! Ready for Raydium migration.");
+    }
+
+    msg!("Bought back and burned {} tokens for {} lamports", tokens_out, sol_in);
+    msg!("Total burned: {} tokens for {} lamports", curve.tokens_burned, curve.burn_fees_spent);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+use crate::state::{PlatformConfig, Meme, MemeStatus, Pool};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::TradeExecuted;
+
+// Permissionless - the native pool's counterpart to execute_buyback_burn
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecutePoolBuybackBurn<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotComplete,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Box<Account<'info, Meme>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, meme.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.meme == meme.key() @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        address = meme.mint @ ProofOfMemeError::AccountMismatch
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool's token reserves
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's SOL vault, already holding the burn fees
+    #[account(
+        seeds = [POOL_VAULT_SEED, meme.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub pool_vault: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn execute_pool_buyback_burn(ctx: Context<ExecutePoolBuybackBurn>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let sol_in = pool.pending_burn_fees();
+    require!(sol_in > 0, ProofOfMemeError::NoBurnFees);
+
+    // Burn fees sit in the vault outside sol_reserves; make sure they are really there
+    let required_balance = pool.sol_reserves
+        .checked_add(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    require!(
+        ctx.accounts.pool_vault.lamports() >= required_balance,
+        ProofOfMemeError::InsufficientVaultBalance
+    );
+
+    // Buy at the pool price (no trading fee on the protocol's own buy)
+    let tokens_out = pool.calculate_buy_tokens(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    require!(tokens_out > 0, ProofOfMemeError::InvalidTokenAmount);
+    require!(tokens_out < pool.token_reserves, ProofOfMemeError::InsufficientLiquidity);
+
+    // Burn the bought tokens straight out of the pool's account
+    let meme_key = ctx.accounts.meme.key();
+    let pool_seeds = &[
+        POOL_SEED,
+        meme_key.as_ref(),
+        &[pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_out,
+    )?;
+
+    // Lamports never move - the burn fees simply become pool reserves
+    let pool = &mut ctx.accounts.pool;
+    let pre_sol_reserves = pool.sol_reserves;
+    let pre_token_reserves = pool.token_reserves;
+    pool.sol_reserves = pool.sol_reserves
+        .checked_add(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.token_reserves = pool.token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.burn_fees_spent = pool.burn_fees_spent
+        .checked_add(sol_in)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.tokens_burned = pool.tokens_burned
+        .checked_add(tokens_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    emit_cpi!(TradeExecuted {
+        meme: meme_key,
+        trader: pool.key(),
+        is_buy: true,
+        sol_amount: sol_in,
+        token_amount: tokens_out,
+        trading_fee: 0,
+        pre_sol_reserves,
+        pre_token_reserves,
+        post_sol_reserves: pool.sol_reserves,
+        post_token_reserves: pool.token_reserves,
+        post_price: pool.get_current_price().ok_or(ProofOfMemeError::MathOverflow)?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Bought back and burned {} tokens for {} lamports from pool", tokens_out, sol_in);
+    msg!("Total burned: {} tokens for {} lamports", pool.tokens_burned, pool.burn_fees_spent);
+
+    Ok(())
+}
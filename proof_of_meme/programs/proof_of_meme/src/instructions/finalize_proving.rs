@@ -1,10 +1,22 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
-use anchor_spl::associated_token::AssociatedToken;
-use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, GenesisPool};
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::metadata::{self, CreateMetadataAccountsV3, Metadata};
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{
+    self, InitializeMint2, MetadataPointerInitialize, MintTo, SetAuthority, TokenInterface,
+    TokenMetadataInitialize, TokenMetadataUpdateAuthority,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, GenesisPool, TokenProgram};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::{CrankRewardPaid, MemeLaunched, MemeFailed};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FinalizeProving<'info> {
     #[account(mut)]
@@ -13,7 +25,8 @@ pub struct FinalizeProving<'info> {
     #[account(
         mut,
         seeds = [PLATFORM_SEED],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -21,7 +34,8 @@ pub struct FinalizeProving<'info> {
         mut,
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -34,16 +48,14 @@ pub struct FinalizeProving<'info> {
     /// CHECK: PDA vault
     pub vault: SystemAccount<'info>,
 
-    /// Token mint - created on successful launch
+    /// Token mint - created on successful launch under the meme's token program
     #[account(
-        init,
-        payer = finalizer,
-        mint::decimals = TOKEN_DECIMALS,
-        mint::authority = curve,
-        seeds = [b"mint", meme.key().as_ref()],
+        mut,
+        seeds = [MINT_SEED, meme.key().as_ref()],
         bump
     )]
-    pub mint: Account<'info, Mint>,
+    /// CHECK: Created in the handler; Token-2022 extensions must be set up before the mint is initialized
+    pub mint: UncheckedAccount<'info>,
 
     /// Bonding curve account - created on successful launch
     #[account(
@@ -56,13 +68,9 @@ pub struct FinalizeProving<'info> {
     pub curve: Account<'info, BondingCurve>,
 
     /// Curve's token account to hold tokens for sale
-    #[account(
-        init,
-        payer = finalizer,
-        associated_token::mint = mint,
-        associated_token::authority = curve
-    )]
-    pub curve_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    /// CHECK: Created in the handler by the associated token program, which checks the address
+    pub curve_token_account: UncheckedAccount<'info>,
 
     /// Genesis pool for fee tracking
     #[account(
@@ -74,17 +82,35 @@ pub struct FinalizeProving<'info> {
     )]
     pub genesis_pool: Account<'info, GenesisPool>,
 
+    /// Genesis pool's token account - escrows the genesis allocation until backers claim
+    #[account(mut)]
+    /// CHECK: Created in the handler by the associated token program, which checks the address
+    pub genesis_token_account: UncheckedAccount<'info>,
+
     /// Curve's SOL vault
     #[account(
         mut,
-        seeds = [b"curve_vault", meme.key().as_ref()],
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
         bump
     )]
     /// CHECK: PDA vault for curve
     pub curve_vault: SystemAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    /// Metaplex metadata for the mint - created on successful SPL Token launches;
+    /// Token-2022 mints carry their metadata themselves
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    /// CHECK: Created and validated by the token metadata program
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(address = meme.token_program.id() @ ProofOfMemeError::AccountMismatch)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -94,13 +120,11 @@ pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
     let meme = &mut ctx.accounts.meme;
     let platform = &mut ctx.accounts.platform;
 
-    // Check if proving period has ended
-    require!(clock.unix_timestamp >= meme.proving_ends_at, ProofOfMemeError::ProvingStillActive);
+    // Check if proving period has ended (or a launch_on_goal meme is past its launch window)
+    require!(meme.can_finalize(clock.unix_timestamp), ProofOfMemeError::ProvingStillActive);
 
     // Check if goal was reached
-    let goal_reached = meme.sol_backed >= meme.sol_goal && meme.backer_count >= meme.min_backers;
-
-    require!(goal_reached, ProofOfMemeError::GoalNotReached);
+    require!(meme.goal_reached(), ProofOfMemeError::GoalNotReached);
 
     // LAUNCH THE TOKEN
     msg!("Goal reached! Launching {}...", meme.get_name());
@@ -113,10 +137,13 @@ pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
     meme.status = MemeStatus::Launched;
     meme.launched_at = clock.unix_timestamp;
 
+    // Memes submitted before curve parameters existed recorded none, so fill in the defaults
+    let curve_params = meme.curve_params.with_defaults();
+
     // Calculate token distribution
-    // 20% to genesis backers, 80% to bonding curve
+    // Genesis allocation to genesis backers, the rest to the bonding curve
     let genesis_tokens = TOTAL_SUPPLY
-        .checked_mul(GENESIS_ALLOCATION_BPS)
+        .checked_mul(curve_params.genesis_allocation_bps as u64)
         .ok_or(ProofOfMemeError::MathOverflow)?
         .checked_div(BPS_DENOMINATOR)
         .ok_or(ProofOfMemeError::MathOverflow)?;
@@ -124,14 +151,121 @@ pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
     let curve_tokens = TOTAL_SUPPLY.checked_sub(genesis_tokens)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    // Past the cap, only the cap goes into the curve; the excess is refunded pro rata
+    meme.sol_oversubscribed = if meme.sol_cap > 0 {
+        meme.sol_backed.saturating_sub(meme.sol_cap)
+    } else {
+        0
+    };
+    let sol_accepted = meme.sol_accepted();
+
+    // Unless the creator picked the virtual SOL, the opening price matches what genesis
+    // backers paid per token: virtual_sol / virtual_tokens == sol_accepted / genesis_tokens
+    let virtual_sol_reserves = if curve_params.initial_virtual_sol > 0 {
+        curve_params.initial_virtual_sol
+    } else {
+        let virtual_sol_reserves = (sol_accepted as u128)
+            .checked_mul(curve_tokens as u128)
+            .ok_or(ProofOfMemeError::MathOverflow)?
+            .checked_div(genesis_tokens as u128)
+            .ok_or(ProofOfMemeError::MathOverflow)?;
+        u64::try_from(virtual_sol_reserves)
+            .map_err(|_| ProofOfMemeError::MathOverflow)?
+    };
+
+    // Non-constant-product shapes open at the same price, then follow their schedule
+    let opening_price = (virtual_sol_reserves as u128)
+        .checked_mul(CURVE_PRICE_SCALE)
+        .ok_or(ProofOfMemeError::MathOverflow)?
+        .checked_div(curve_tokens as u128)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    let opening_price = u64::try_from(opening_price)
+        .map_err(|_| ProofOfMemeError::MathOverflow)?;
+
+    // Backing SOL already sits in the curve, so completion is measured on top of it
+    let completion_threshold = sol_accepted
+        .checked_add(curve_params.completion_sol)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    let meme_key = meme.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        meme_key.as_ref(),
+        &[meme.vault_bump],
+    ];
+
+    // Pay the escrowed crank reward before the backing moves to the curve
+    let crank_reward = meme.crank_reward;
+    if crank_reward > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.finalizer.to_account_info(),
+                },
+                &[&vault_seeds[..]],
+            ),
+            crank_reward,
+        )?;
+        meme.crank_reward = 0;
+
+        emit_cpi!(CrankRewardPaid {
+            meme: meme_key,
+            cranker: ctx.accounts.finalizer.key(),
+            amount: crank_reward,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // The oversubscription stays in the proving vault for refunds. The finalizer funds its
+    // rent exemption so rounding dust left by the last refund can't strand it.
+    let rent_reserve = if meme.sol_oversubscribed > 0 {
+        let rent_reserve = ctx.accounts.rent.minimum_balance(0);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.finalizer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            rent_reserve,
+        )?;
+        rent_reserve
+    } else {
+        0
+    };
+
+    // Sweep the accepted backing into the curve vault
+    let vault_balance = ctx.accounts.vault.lamports();
+    let vault_required = meme.sol_backed
+        .checked_add(rent_reserve)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    require!(vault_balance >= vault_required, ProofOfMemeError::InsufficientVaultBalance);
+
+    let sweep_amount = vault_balance - rent_reserve - meme.sol_oversubscribed;
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.curve_vault.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        sweep_amount,
+    )?;
+
     // Initialize bonding curve
     curve.meme = meme.key();
     curve.mint = ctx.accounts.mint.key();
     // Virtual reserves set up for pump.fun-style curve
-    // Starting at low price, increasing as tokens are bought
-    curve.virtual_sol_reserves = 30_000_000_000; // 30 SOL virtual
+    // Starting at the genesis price, increasing as tokens are bought
+    curve.virtual_sol_reserves = virtual_sol_reserves;
     curve.virtual_token_reserves = curve_tokens;
-    curve.real_sol_reserves = 0;
+    curve.real_sol_reserves = sol_accepted;
     curve.real_token_reserves = curve_tokens;
     curve.tokens_sold = 0;
     curve.total_volume = 0;
@@ -139,29 +273,122 @@ pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
     curve.genesis_fees_distributed = 0;
     curve.platform_fees_accumulated = 0;
     curve.burn_fees_accumulated = 0;
+    curve.burn_fees_spent = 0;
+    curve.tokens_burned = 0;
     curve.status = CurveStatus::Active;
-    curve.completion_threshold = CURVE_COMPLETION_SOL;
+    curve.completion_threshold = completion_threshold;
     curve.bump = ctx.bumps.curve;
     curve.vault_bump = ctx.bumps.curve_vault;
+    curve.migration_pool = Pubkey::default();
+    curve.opening_price = opening_price;
+    curve.curve_supply = curve_tokens;
+    curve.kind = curve_params.kind;
 
     // Initialize genesis pool
     genesis_pool.meme = meme.key();
+    // Shares use each backing's full amount: scaling every backing by the same
+    // pro-rata ratio leaves them unchanged
     genesis_pool.total_qualified_backing = meme.sol_backed;
     genesis_pool.total_fees = 0;
     genesis_pool.total_claimed = 0;
     genesis_pool.qualified_backer_count = meme.backer_count;
     genesis_pool.bump = ctx.bumps.genesis_pool;
+    genesis_pool.total_tokens = genesis_tokens;
+    genesis_pool.tokens_distributed = 0;
+    // Snapshot the platform's default vesting schedule, unlocking from launch
+    genesis_pool.vesting_start = clock.unix_timestamp;
+    genesis_pool.vesting_cliff_seconds = platform.vesting_cliff_seconds;
+    genesis_pool.vesting_duration_seconds = platform.vesting_duration_seconds;
 
-    // Mint tokens to curve's token account
-    let meme_key = meme.key();
     let curve_seeds = &[
         CURVE_SEED,
         meme_key.as_ref(),
         &[curve.bump],
     ];
     let signer_seeds = &[&curve_seeds[..]];
+    let mint_seeds = &[
+        MINT_SEED,
+        meme_key.as_ref(),
+        &[ctx.bumps.mint],
+    ];
+
+    // Create the mint under the meme's token program. Token-2022 mints point their metadata
+    // at themselves; the mint is funded up front for the metadata written after minting.
+    let is_token_2022 = meme.token_program == TokenProgram::Token2022;
+    let (mint_space, metadata_space) = if is_token_2022 {
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(curve.key()),
+            mint: ctx.accounts.mint.key(),
+            name: meme.get_name(),
+            symbol: meme.get_symbol(),
+            uri: meme.get_uri(),
+            additional_metadata: vec![],
+        };
+        (
+            token_interface::find_mint_account_size(Some(&vec![ExtensionType::MetadataPointer]))?,
+            token_metadata.tlv_size_of()?,
+        )
+    } else {
+        (token_interface::find_mint_account_size(None)?, 0)
+    };
+
+    create_mint_account(
+        &ctx.accounts.finalizer,
+        &ctx.accounts.mint,
+        &ctx.accounts.system_program,
+        ctx.accounts.token_program.key,
+        ctx.accounts.rent.minimum_balance(mint_space + metadata_space),
+        mint_space,
+        &[&mint_seeds[..]],
+    )?;
+
+    if is_token_2022 {
+        // No pointer authority: the metadata can never be pointed elsewhere
+        token_interface::metadata_pointer_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            None,
+            Some(ctx.accounts.mint.key()),
+        )?;
+    }
+
+    // No freeze authority, so holders can never be frozen
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        TOKEN_DECIMALS,
+        &curve.key(),
+        None,
+    )?;
+
+    for (token_account, authority) in [
+        (&ctx.accounts.curve_token_account, curve.to_account_info()),
+        (&ctx.accounts.genesis_token_account, genesis_pool.to_account_info()),
+    ] {
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.finalizer.to_account_info(),
+                associated_token: token_account.to_account_info(),
+                authority,
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+    }
 
-    token::mint_to(
+    // Mint tokens to curve's token account
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
@@ -174,28 +401,218 @@ pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
         curve_tokens,
     )?;
 
+    // Mint genesis allocation into escrow for backers to claim
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.genesis_token_account.to_account_info(),
+                authority: curve.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        genesis_tokens,
+    )?;
+
+    // On-chain name, symbol and uri from the meme; immutable, so the pitch backers launched
+    // is the one traders see
+    if is_token_2022 {
+        token_interface::token_metadata_initialize(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    update_authority: curve.to_account_info(),
+                    mint_authority: curve.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            meme.get_name(),
+            meme.get_symbol(),
+            meme.get_uri(),
+        )?;
+        token_interface::token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    current_authority: curve.to_account_info(),
+                    new_authority: curve.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+    } else {
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    mint_authority: curve.to_account_info(),
+                    payer: ctx.accounts.finalizer.to_account_info(),
+                    update_authority: curve.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name: meme.get_name(),
+                symbol: meme.get_symbol(),
+                uri: meme.get_uri(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            false, // is_mutable
+            true,  // update_authority_is_signer
+            None,  // collection_details
+        )?;
+    }
+
+    // Full supply is out: revoke the mint authority so nothing can ever mint more
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: curve.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
     // Update platform stats
     platform.total_memes_launched = platform.total_memes_launched.checked_add(1)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    emit_cpi!(MemeLaunched {
+        meme: meme.key(),
+        mint: meme.mint,
+        token_program: ctx.accounts.token_program.key(),
+        curve: curve.key(),
+        sol_backed: meme.sol_backed,
+        sol_oversubscribed: meme.sol_oversubscribed,
+        backer_count: meme.backer_count,
+        genesis_tokens,
+        curve_tokens,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Token launched! {} tokens in curve, {} reserved for genesis backers",
         curve_tokens, genesis_tokens);
+    msg!("Moved {} lamports of backing into the curve vault", sweep_amount);
+    if meme.sol_oversubscribed > 0 {
+        msg!("Oversubscribed by {} lamports, refundable pro rata", meme.sol_oversubscribed);
+    }
 
     Ok(())
 }
 
+/// Create the mint PDA owned by `owner`. Like Anchor's `init`, this also works when someone
+/// has already sent lamports to the address, so a launch can't be blocked that way.
+fn create_mint_account<'info>(
+    payer: &Signer<'info>,
+    mint: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    owner: &Pubkey,
+    lamports: u64,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let current_lamports = mint.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: mint.to_account_info(),
+                },
+            ),
+            lamports - current_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
 // Separate instruction for marking failed memes
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MarkMemeFailed<'info> {
+    #[account(mut)]
     pub finalizer: Signer<'info>,
 
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
     #[account(
         mut,
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
+
+    /// The vault holding backing SOL and the escrowed crank reward
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, meme.key().as_ref()],
+        bump = meme.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn mark_meme_failed(ctx: Context<MarkMemeFailed>) -> Result<()> {
@@ -206,11 +623,48 @@ pub fn mark_meme_failed(ctx: Context<MarkMemeFailed>) -> Result<()> {
     require!(clock.unix_timestamp >= meme.proving_ends_at, ProofOfMemeError::ProvingStillActive);
 
     // Check if goal was NOT reached
-    let goal_reached = meme.sol_backed >= meme.sol_goal && meme.backer_count >= meme.min_backers;
-    require!(!goal_reached, ProofOfMemeError::GoalNotReached);
+    require!(!meme.goal_reached(), ProofOfMemeError::GoalNotReached);
 
     msg!("Goal not reached. Marking as failed - backers can withdraw.");
     meme.status = MemeStatus::Failed;
 
+    // Pay the escrowed crank reward; only backing is left in the vault for refunds
+    let crank_reward = meme.crank_reward;
+    if crank_reward > 0 {
+        let meme_key = meme.key();
+        let vault_seeds = &[
+            VAULT_SEED,
+            meme_key.as_ref(),
+            &[meme.vault_bump],
+        ];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.finalizer.to_account_info(),
+                },
+                &[&vault_seeds[..]],
+            ),
+            crank_reward,
+        )?;
+        meme.crank_reward = 0;
+
+        emit_cpi!(CrankRewardPaid {
+            meme: meme_key,
+            cranker: ctx.accounts.finalizer.key(),
+            amount: crank_reward,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit_cpi!(MemeFailed {
+        meme: meme.key(),
+        sol_backed: meme.sol_backed,
+        backer_count: meme.backer_count,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::PlatformConfig;
+use crate::state::{PlatformConfig, CurveBounds};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
 
@@ -26,14 +26,27 @@ pub fn initialize_platform(
     platform_fee_bps: u16,
     genesis_fee_bps: u16,
     burn_fee_bps: u16,
+    vesting_cliff_seconds: i64,
+    vesting_duration_seconds: i64,
 ) -> Result<()> {
     // Validate fee configuration (must sum to 10000 bps = 100%)
-    let total_bps = platform_fee_bps as u32 + genesis_fee_bps as u32 + burn_fee_bps as u32;
-    require!(total_bps == 10_000, ProofOfMemeError::InvalidFeeConfig);
+    require!(
+        PlatformConfig::is_valid_fee_split(platform_fee_bps, genesis_fee_bps, burn_fee_bps),
+        ProofOfMemeError::InvalidFeeConfig
+    );
+
+    // Validate genesis vesting schedule
+    require!(
+        PlatformConfig::is_valid_vesting_schedule(vesting_cliff_seconds, vesting_duration_seconds),
+        ProofOfMemeError::InvalidVestingSchedule
+    );
 
     let platform = &mut ctx.accounts.platform;
 
     platform.authority = ctx.accounts.authority.key();
+    platform.pending_authority = Pubkey::default();
+    platform.fee_recipient = ctx.accounts.authority.key();
+    platform.paused = 0;
     platform.submission_fee = submission_fee;
     platform.platform_fee_bps = platform_fee_bps;
     platform.genesis_fee_bps = genesis_fee_bps;
@@ -42,10 +55,22 @@ pub fn initialize_platform(
     platform.total_memes_launched = 0;
     platform.total_platform_fees = 0;
     platform.bump = ctx.bumps.platform;
+    platform.vesting_cliff_seconds = vesting_cliff_seconds;
+    platform.vesting_duration_seconds = vesting_duration_seconds;
+    // Crank rewards start disabled; enable through update_platform_config
+    platform.crank_reward = 0;
+    platform.launch_window_seconds = DEFAULT_LAUNCH_WINDOW;
+    platform.unback_penalty_bps = 0;
+    // Creators get the default curve until the authority widens the bounds
+    platform.curve_bounds = CurveBounds::DEFAULT;
+    platform.config_timelock_seconds = 0;
+    platform.pending_config_activates_at = 0;
 
     msg!("Platform initialized with submission fee: {} lamports", submission_fee);
     msg!("Fee split - Platform: {}bps, Genesis: {}bps, Burn: {}bps",
         platform_fee_bps, genesis_fee_bps, burn_fee_bps);
+    msg!("Genesis vesting - Cliff: {}s, Duration: {}s",
+        vesting_cliff_seconds, vesting_duration_seconds);
 
     Ok(())
 }
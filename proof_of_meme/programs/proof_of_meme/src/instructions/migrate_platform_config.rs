@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, CurveBounds};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+/// Platform configs created before the vesting, timelock, authority, pause, crank,
+/// launch window, unback and curve bound fields were appended are `LEGACY_SIZE` bytes,
+/// too short for `Account<PlatformConfig>` to deserialize. Every instruction reading
+/// the platform fails on them until the authority runs this once.
+#[derive(Accounts)]
+pub struct MigratePlatformConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump,
+        owner = crate::ID
+    )]
+    /// CHECK: Legacy layout; discriminator, size and authority checked in the handler
+    pub platform: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+    let platform = ctx.accounts.platform.to_account_info();
+    let authority = ctx.accounts.authority.key();
+
+    {
+        let data = platform.try_borrow_data()?;
+        require!(data.len() == PlatformConfig::LEGACY_SIZE, ProofOfMemeError::PlatformConfigCurrent);
+        require!(data[..8] == *PlatformConfig::DISCRIMINATOR, ProofOfMemeError::AccountMismatch);
+        // The authority is the first field in both layouts
+        require!(data[8..40] == authority.to_bytes(), ProofOfMemeError::Unauthorized);
+    }
+
+    // Authority covers the rent for the added bytes
+    let rent_due = Rent::get()?
+        .minimum_balance(PlatformConfig::SIZE)
+        .saturating_sub(platform.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: platform.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    platform.resize(PlatformConfig::SIZE)?;
+
+    // The legacy fields are a prefix of the current layout and everything after them is
+    // zeroed, so the config deserializes with the added fields at zero. Set the ones
+    // initialize_platform does not leave at zero.
+    let mut config = PlatformConfig::try_deserialize(&mut &platform.try_borrow_data()?[..])?;
+    config.fee_recipient = authority;
+    config.launch_window_seconds = DEFAULT_LAUNCH_WINDOW;
+    config.curve_bounds = CurveBounds::DEFAULT;
+    config.try_serialize(&mut &mut platform.try_borrow_mut_data()?[..])?;
+
+    msg!("Platform config migrated to {} bytes", PlatformConfig::SIZE);
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, MigrationTarget, Pool};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::{CrankRewardPaid, Migrated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateToPool<'info> {
+    /// Anyone can trigger migration once curve is complete
+    #[account(mut)]
+    pub migrator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_MIGRATION) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive,
+        constraint = meme.curve_params.migration_target == MigrationTarget::NativePool @ ProofOfMemeError::WrongMigrationTarget,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Box<Account<'info, Meme>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_SEED, meme.key().as_ref()],
+        bump = curve.bump,
+        constraint = curve.meme == meme.key() @ ProofOfMemeError::InvalidCurveAccount,
+        constraint = curve.status == CurveStatus::Complete @ ProofOfMemeError::CurveNotComplete,
+        constraint = curve.pending_burn_fees() == 0 @ ProofOfMemeError::BurnFeesPending
+    )]
+    pub curve: Box<Account<'info, BondingCurve>>,
+
+    /// Platform fee recipient receives migration fee - MUST match platform config
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
+    )]
+    /// CHECK: Validated against platform config
+    pub fee_recipient: SystemAccount<'info>,
+
+    /// Curve's SOL vault
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
+        bump = curve.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub curve_vault: SystemAccount<'info>,
+
+    #[account(
+        address = meme.mint @ ProofOfMemeError::AccountMismatch
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Curve's token account holding unsold tokens
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = curve,
+        associated_token::token_program = token_program
+    )]
+    pub curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Native pool - created on migration
+    #[account(
+        init,
+        payer = migrator,
+        space = Pool::SIZE,
+        seeds = [POOL_SEED, meme.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool's token reserves
+    #[account(
+        init,
+        payer = migrator,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's SOL vault
+    #[account(
+        mut,
+        seeds = [POOL_VAULT_SEED, meme.key().as_ref()],
+        bump
+    )]
+    /// CHECK: PDA vault for pool
+    pub pool_vault: SystemAccount<'info>,
+
+    /// Pool LP mint, under the same token program as the meme
+    #[account(
+        init,
+        payer = migrator,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = pool,
+        mint::token_program = token_program,
+        seeds = [LP_MINT_SEED, meme.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn migrate_to_pool(ctx: Context<MigrateToPool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let meme_key = ctx.accounts.meme.key();
+    let real_sol_reserves = ctx.accounts.curve.real_sol_reserves;
+
+    // Curve SOL pays the migration fee; the rest seeds the pool
+    let pool_sol = real_sol_reserves
+        .checked_sub(MIGRATION_FEE)
+        .ok_or(ProofOfMemeError::InsufficientVaultBalance)?;
+    let pool_tokens = ctx.accounts.curve_token_account.amount;
+
+    require!(pool_sol > 0, ProofOfMemeError::InsufficientVaultBalance);
+    require!(pool_tokens > 0, ProofOfMemeError::InsufficientTokens);
+
+    // Verify vault holds the curve reserves (unclaimed genesis fees stay behind)
+    let vault_balance = ctx.accounts.curve_vault.lamports();
+    require!(vault_balance >= real_sol_reserves, ProofOfMemeError::InsufficientVaultBalance);
+
+    let initial_lp = Pool::calculate_initial_lp(pool_sol, pool_tokens)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    let curve_vault_seeds = &[
+        CURVE_VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.curve.vault_bump],
+    ];
+    let curve_seeds = &[
+        CURVE_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.curve.bump],
+    ];
+
+    // Take migration fee from curve vault; the migrator's crank reward comes out of it
+    let crank_reward = ctx.accounts.platform.crank_reward.min(MIGRATION_FEE);
+    let platform_fee = MIGRATION_FEE
+        .checked_sub(crank_reward)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.fee_recipient.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        platform_fee,
+    )?;
+
+    if crank_reward > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.migrator.to_account_info(),
+                },
+                &[&curve_vault_seeds[..]],
+            ),
+            crank_reward,
+        )?;
+
+        emit_cpi!(CrankRewardPaid {
+            meme: meme_key,
+            cranker: ctx.accounts.migrator.key(),
+            amount: crank_reward,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Move curve SOL into the pool vault
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        pool_sol,
+    )?;
+
+    // Move unsold tokens into the pool
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.curve_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: ctx.accounts.curve.to_account_info(),
+            },
+            &[&curve_seeds[..]],
+        ),
+        pool_tokens,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Initialize pool - seeded liquidity is locked (no LP tokens are minted for it)
+    let pool = &mut ctx.accounts.pool;
+    pool.meme = meme_key;
+    pool.mint = ctx.accounts.mint.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.sol_reserves = pool_sol;
+    pool.token_reserves = pool_tokens;
+    pool.lp_supply = initial_lp;
+    pool.locked_lp = initial_lp;
+    pool.total_volume = 0;
+    pool.genesis_fees_accumulated = 0;
+    pool.platform_fees_accumulated = 0;
+    pool.burn_fees_accumulated = 0;
+    pool.created_at = clock.unix_timestamp;
+    pool.bump = ctx.bumps.pool;
+    pool.vault_bump = ctx.bumps.pool_vault;
+
+    let meme = &mut ctx.accounts.meme;
+    let curve = &mut ctx.accounts.curve;
+    let platform = &mut ctx.accounts.platform;
+
+    // Update platform stats
+    platform.total_platform_fees = platform.total_platform_fees
+        .checked_add(platform_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    // Curve reserves now live in the native pool
+    curve.real_sol_reserves = 0;
+    curve.real_token_reserves = 0;
+    curve.migration_pool = pool.key();
+
+    // Mark as migrated
+    meme.status = MemeStatus::Migrated;
+    curve.status = CurveStatus::Migrated;
+
+    emit_cpi!(Migrated {
+        meme: meme.key(),
+        pool: curve.migration_pool,
+        sol_amount: pool_sol,
+        token_amount: pool_tokens,
+        migration_fee: MIGRATION_FEE,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Migrated to native pool {}", curve.migration_pool);
+    msg!("Migration fee: {} lamports", MIGRATION_FEE);
+    msg!("Seeded {} lamports and {} tokens, {} LP locked", pool_sol, pool_tokens, initial_lp);
+
+    Ok(())
+}
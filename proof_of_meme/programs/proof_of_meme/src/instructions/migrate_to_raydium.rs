@@ -1,8 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, spl_token, Burn, Mint, SyncNative, Token, TokenAccount};
+use anchor_spl::token_interface::{self, TokenInterface, TransferChecked};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, MigrationTarget};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::{CrankRewardPaid, Migrated};
+use crate::raydium::{self, CpmmInitialize, RAYDIUM_CPMM_PROGRAM_ID};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MigrateToRaydium<'info> {
     /// Anyone can trigger migration once curve is complete
@@ -12,88 +19,348 @@ pub struct MigrateToRaydium<'info> {
     #[account(
         mut,
         seeds = [PLATFORM_SEED],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_MIGRATION) @ ProofOfMemeError::Paused
     )]
-    pub platform: Account<'info, PlatformConfig>,
+    pub platform: Box<Account<'info, PlatformConfig>>,
 
     #[account(
         mut,
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive
+        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive,
+        constraint = meme.curve_params.migration_target == MigrationTarget::Raydium @ ProofOfMemeError::WrongMigrationTarget,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
-    pub meme: Account<'info, Meme>,
+    pub meme: Box<Account<'info, Meme>>,
 
     #[account(
         mut,
         seeds = [CURVE_SEED, meme.key().as_ref()],
         bump = curve.bump,
         constraint = curve.meme == meme.key() @ ProofOfMemeError::InvalidCurveAccount,
-        constraint = curve.status == CurveStatus::Complete @ ProofOfMemeError::CurveNotComplete
+        constraint = curve.status == CurveStatus::Complete @ ProofOfMemeError::CurveNotComplete,
+        constraint = curve.pending_burn_fees() == 0 @ ProofOfMemeError::BurnFeesPending
     )]
-    pub curve: Account<'info, BondingCurve>,
+    pub curve: Box<Account<'info, BondingCurve>>,
 
-    /// Platform authority receives migration fee - MUST match platform config
+    /// Platform fee recipient receives migration fee - MUST match platform config
     #[account(
         mut,
-        constraint = platform_authority.key() == platform.authority @ ProofOfMemeError::InvalidPlatformAuthority
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
     )]
     /// CHECK: Validated against platform config
-    pub platform_authority: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
 
-    /// Curve's SOL vault
+    /// Curve's SOL vault - signs as the pool creator
     #[account(
         mut,
-        seeds = [b"curve_vault", meme.key().as_ref()],
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
         bump = curve.vault_bump
     )]
     /// CHECK: PDA vault validated by seeds
     pub curve_vault: SystemAccount<'info>,
 
+    #[account(
+        address = meme.mint @ ProofOfMemeError::AccountMismatch
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        address = spl_token::native_mint::ID @ ProofOfMemeError::AccountMismatch
+    )]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    /// Curve's token account holding unsold tokens
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = curve,
+        associated_token::token_program = meme_token_program
+    )]
+    pub curve_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// Curve vault's token account - deposits tokens into the pool
+    #[account(
+        init_if_needed,
+        payer = migrator,
+        associated_token::mint = mint,
+        associated_token::authority = curve_vault,
+        associated_token::token_program = meme_token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// Curve vault's wSOL account - deposits SOL into the pool
+    #[account(
+        init_if_needed,
+        payer = migrator,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = curve_vault
+    )]
+    pub vault_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = RAYDIUM_CPMM_PROGRAM_ID)]
+    /// CHECK: Raydium CPMM program, validated by address
+    pub cpmm_program: UncheckedAccount<'info>,
+
+    #[account(owner = RAYDIUM_CPMM_PROGRAM_ID @ ProofOfMemeError::InvalidRaydiumAccount)]
+    /// CHECK: Raydium AMM config, validated by owner and discriminator
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium vault and LP mint authority, validated by CPMM
+    pub cpmm_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Pool state, created and validated by CPMM
+    pub pool_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP mint, created and validated by CPMM
+    pub lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Curve vault's LP token account, created and validated by CPMM
+    pub vault_lp_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Pool vault for the meme token, created and validated by CPMM
+    pub pool_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Pool vault for wSOL, created and validated by CPMM
+    pub pool_wsol_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Raydium pool creation fee receiver, validated by CPMM
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Oracle observation state, created and validated by CPMM
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// SPL Token, for wSOL and the LP mint
+    pub token_program: Program<'info, Token>,
+    /// The meme mint's token program
+    #[account(address = meme.token_program.id() @ ProofOfMemeError::AccountMismatch)]
+    pub meme_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn migrate_to_raydium(ctx: Context<MigrateToRaydium>) -> Result<()> {
-    let meme = &mut ctx.accounts.meme;
-    let curve = &mut ctx.accounts.curve;
-    let platform = &mut ctx.accounts.platform;
+    let meme_key = ctx.accounts.meme.key();
+    let real_sol_reserves = ctx.accounts.curve.real_sol_reserves;
 
-    // Verify vault has enough for migration fee
+    // Curve SOL pays the migration fee, the AMM config's pool creation fee and the pool
+    // account rent; the rest is liquidity
+    let create_pool_fee = raydium::amm_config_create_pool_fee(&ctx.accounts.amm_config.try_borrow_data()?)?;
+    let pool_sol = real_sol_reserves
+        .checked_sub(MIGRATION_FEE)
+        .ok_or(ProofOfMemeError::InsufficientVaultBalance)?
+        .checked_sub(create_pool_fee)
+        .ok_or(ProofOfMemeError::InsufficientVaultBalance)?
+        .checked_sub(RAYDIUM_POOL_RENT)
+        .ok_or(ProofOfMemeError::InsufficientVaultBalance)?;
+    let pool_tokens = ctx.accounts.curve_token_account.amount;
+
+    require!(pool_sol > 0, ProofOfMemeError::InsufficientVaultBalance);
+    require!(pool_tokens > 0, ProofOfMemeError::InsufficientTokens);
+
+    // Verify vault holds the curve reserves (unclaimed genesis fees stay behind)
     let vault_balance = ctx.accounts.curve_vault.lamports();
-    require!(vault_balance >= MIGRATION_FEE, ProofOfMemeError::InsufficientVaultBalance);
+    require!(vault_balance >= real_sol_reserves, ProofOfMemeError::InsufficientVaultBalance);
 
-    // Take migration fee from curve vault
-    **ctx.accounts.curve_vault.try_borrow_mut_lamports()? = ctx.accounts.curve_vault
-        .lamports()
-        .checked_sub(MIGRATION_FEE)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-    **ctx.accounts.platform_authority.try_borrow_mut_lamports()? = ctx.accounts.platform_authority
-        .lamports()
-        .checked_add(MIGRATION_FEE)
+    let curve_vault_seeds = &[
+        CURVE_VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.curve.vault_bump],
+    ];
+    let curve_seeds = &[
+        CURVE_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.curve.bump],
+    ];
+
+    // Take migration fee from curve vault; the migrator's crank reward comes out of it
+    let crank_reward = ctx.accounts.platform.crank_reward.min(MIGRATION_FEE);
+    let platform_fee = MIGRATION_FEE
+        .checked_sub(crank_reward)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.fee_recipient.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        platform_fee,
+    )?;
+
+    if crank_reward > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.migrator.to_account_info(),
+                },
+                &[&curve_vault_seeds[..]],
+            ),
+            crank_reward,
+        )?;
+
+        emit_cpi!(CrankRewardPaid {
+            meme: meme_key,
+            cranker: ctx.accounts.migrator.key(),
+            amount: crank_reward,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    // Move unsold tokens from the curve to the pool creator (curve vault)
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.meme_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.curve_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.curve.to_account_info(),
+            },
+            &[&curve_seeds[..]],
+        ),
+        pool_tokens,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Wrap the pool SOL
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.vault_wsol_account.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        pool_sol,
+    )?;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.vault_wsol_account.to_account_info(),
+        },
+    ))?;
+
+    // CPMM requires token_0_mint < token_1_mint
+    let token_is_0 = ctx.accounts.mint.key() < ctx.accounts.wsol_mint.key();
+    let meme_side = (
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.pool_token_vault.to_account_info(),
+        ctx.accounts.meme_token_program.to_account_info(),
+        pool_tokens,
+    );
+    let wsol_side = (
+        ctx.accounts.wsol_mint.to_account_info(),
+        ctx.accounts.vault_wsol_account.to_account_info(),
+        ctx.accounts.pool_wsol_vault.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        pool_sol,
+    );
+    let (
+        (token_0_mint, creator_token_0, token_0_vault, token_0_program, amount_0),
+        (token_1_mint, creator_token_1, token_1_vault, token_1_program, amount_1),
+    ) = if token_is_0 { (meme_side, wsol_side) } else { (wsol_side, meme_side) };
+
+    // Create the pool and deposit liquidity, signed by the curve vault
+    raydium::cpmm_initialize(
+        ctx.accounts.cpmm_program.to_account_info(),
+        CpmmInitialize {
+            creator: ctx.accounts.curve_vault.to_account_info(),
+            amm_config: ctx.accounts.amm_config.to_account_info(),
+            authority: ctx.accounts.cpmm_authority.to_account_info(),
+            pool_state: ctx.accounts.pool_state.to_account_info(),
+            token_0_mint,
+            token_1_mint,
+            lp_mint: ctx.accounts.lp_mint.to_account_info(),
+            creator_token_0,
+            creator_token_1,
+            creator_lp_token: ctx.accounts.vault_lp_account.to_account_info(),
+            token_0_vault,
+            token_1_vault,
+            create_pool_fee: ctx.accounts.create_pool_fee.to_account_info(),
+            observation_state: ctx.accounts.observation_state.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_0_program,
+            token_1_program,
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        amount_0,
+        amount_1,
+        0, // open immediately
+        &[&curve_vault_seeds[..]],
+    )?;
+
+    // Burn all LP tokens so the liquidity is permanently locked
+    let lp_amount = {
+        let data = ctx.accounts.vault_lp_account.try_borrow_data()?;
+        let lp_account = TokenAccount::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(lp_account.owner, ctx.accounts.curve_vault.key(), ProofOfMemeError::InvalidRaydiumAccount);
+        require_keys_eq!(lp_account.mint, ctx.accounts.lp_mint.key(), ProofOfMemeError::InvalidRaydiumAccount);
+        lp_account.amount
+    };
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.vault_lp_account.to_account_info(),
+                authority: ctx.accounts.curve_vault.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        lp_amount,
+    )?;
+
+    let meme = &mut ctx.accounts.meme;
+    let curve = &mut ctx.accounts.curve;
+    let platform = &mut ctx.accounts.platform;
+
     // Update platform stats
     platform.total_platform_fees = platform.total_platform_fees
-        .checked_add(MIGRATION_FEE)
+        .checked_add(platform_fee)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    // Curve reserves now live in the Raydium pool
+    curve.real_sol_reserves = 0;
+    curve.real_token_reserves = 0;
+    curve.migration_pool = ctx.accounts.pool_state.key();
+
     // Mark as migrated
     meme.status = MemeStatus::Migrated;
     curve.status = CurveStatus::Migrated;
 
-    msg!("Migration initiated!");
+    emit_cpi!(Migrated {
+        meme: meme.key(),
+        pool: curve.migration_pool,
+        sol_amount: pool_sol,
+        token_amount: pool_tokens,
+        migration_fee: MIGRATION_FEE,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Migrated to Raydium CPMM pool {}", curve.migration_pool);
     msg!("Migration fee: {} lamports", MIGRATION_FEE);
+    msg!("Deposited {} lamports and {} tokens, burned {} LP tokens", pool_sol, pool_tokens, lp_amount);
     msg!("Remaining SOL in curve vault: {} lamports", ctx.accounts.curve_vault.lamports());
 
-    // NOTE: In production, this would include CPI to Raydium to:
-    // 1. Create the AMM pool
-    // 2. Transfer remaining tokens from curve to pool
-    // 3. Transfer remaining SOL from curve vault to pool
-    // 4. Burn or lock LP tokens
-    //
-    // For MVP, we mark as migrated and handle pool creation off-chain
-    // The remaining SOL and tokens can be withdrawn by a separate admin instruction
-    // or used in a follow-up transaction with Raydium CPI
-
     Ok(())
 }
@@ -1,19 +1,51 @@
 pub mod initialize_platform;
+pub mod update_platform_config;
+pub mod platform_authority;
+pub mod migrate_platform_config;
+pub mod pause;
 pub mod submit_meme;
+pub mod update_meme_metadata;
 pub mod back_meme;
+pub mod unback_meme;
 pub mod withdraw_backing;
+pub mod claim_backing_refund;
 pub mod finalize_proving;
+pub mod cancel_meme;
 pub mod buy_tokens;
 pub mod sell_tokens;
 pub mod claim_genesis_fees;
+pub mod claim_genesis_tokens;
+pub mod claim_vested;
+pub mod execute_buyback_burn;
 pub mod migrate_to_raydium;
+pub mod migrate_to_pool;
+pub mod add_liquidity;
+pub mod remove_liquidity;
+pub mod swap;
+pub mod execute_pool_buyback_burn;
 
 pub use initialize_platform::*;
+pub use update_platform_config::*;
+pub use platform_authority::*;
+pub use migrate_platform_config::*;
+pub use pause::*;
 pub use submit_meme::*;
+pub use update_meme_metadata::*;
 pub use back_meme::*;
+pub use unback_meme::*;
 pub use withdraw_backing::*;
+pub use claim_backing_refund::*;
 pub use finalize_proving::*;
+pub use cancel_meme::*;
 pub use buy_tokens::*;
 pub use sell_tokens::*;
 pub use claim_genesis_fees::*;
+pub use claim_genesis_tokens::*;
+pub use claim_vested::*;
+pub use execute_buyback_burn::*;
 pub use migrate_to_raydium::*;
+pub use migrate_to_pool::*;
+pub use add_liquidity::*;
+pub use remove_liquidity::*;
+pub use swap::*;
+pub use execute_pool_buyback_burn::*;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, Meme};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct SetPlatformPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: u8) -> Result<()> {
+    require!(
+        PlatformConfig::is_valid_pause_mask(paused),
+        ProofOfMemeError::InvalidPauseFlags
+    );
+
+    let platform = &mut ctx.accounts.platform;
+
+    // Replaces the whole mask; 0 resumes everything
+    platform.paused = paused;
+
+    msg!("Platform pause flags set to {:#07b}", paused);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMemeFrozen<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump
+    )]
+    pub meme: Account<'info, Meme>,
+}
+
+pub fn set_meme_frozen(ctx: Context<SetMemeFrozen>, frozen: bool) -> Result<()> {
+    let meme = &mut ctx.accounts.meme;
+
+    meme.frozen = frozen;
+
+    msg!("Meme {} frozen: {}", meme.get_name(), frozen);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+
+    // Nominee must accept before taking over; Pubkey::default() cancels
+    platform.pending_authority = new_authority;
+
+    if new_authority == Pubkey::default() {
+        msg!("Pending authority transfer cancelled");
+    } else {
+        msg!("Proposed new platform authority: {}", new_authority);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.pending_authority != Pubkey::default() @ ProofOfMemeError::NoPendingAuthority,
+        constraint = platform.pending_authority == new_authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let previous_authority = platform.authority;
+
+    platform.authority = ctx.accounts.new_authority.key();
+    platform.pending_authority = Pubkey::default();
+
+    msg!("Platform authority transferred from {} to {}", previous_authority, platform.authority);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    /// New treasury for platform fees
+    /// CHECK: Any system account can receive fees
+    pub fee_recipient: SystemAccount<'info>,
+}
+
+pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+
+    platform.fee_recipient = ctx.accounts.fee_recipient.key();
+
+    msg!("Platform fee recipient set to {}", platform.fee_recipient);
+
+    Ok(())
+}
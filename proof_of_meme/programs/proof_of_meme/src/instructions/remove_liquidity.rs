@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{PlatformConfig, Meme, MemeStatus, Pool};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotComplete,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Box<Account<'info, Meme>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, meme.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.meme == meme.key() @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        address = pool.lp_mint @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool's token reserves
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's SOL vault
+    #[account(
+        mut,
+        seeds = [POOL_VAULT_SEED, meme.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub pool_vault: SystemAccount<'info>,
+
+    /// Provider's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's LP token account
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn remove_liquidity(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_sol_out: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    // Validate input amount
+    require!(lp_amount > 0, ProofOfMemeError::ZeroAmount);
+    require!(lp_amount <= ctx.accounts.provider_lp_account.amount, ProofOfMemeError::InsufficientTokens);
+
+    // Pro-rata share of reserves
+    let (sol_out, tokens_out) = ctx.accounts.pool.calculate_withdraw(lp_amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    require!(sol_out > 0 && tokens_out > 0, ProofOfMemeError::InvalidTokenAmount);
+    require!(sol_out >= min_sol_out, ProofOfMemeError::SlippageExceeded);
+    require!(tokens_out >= min_tokens_out, ProofOfMemeError::SlippageExceeded);
+
+    // Burn LP tokens FIRST (receive before send pattern)
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+
+    let meme_key = ctx.accounts.meme.key();
+
+    // Transfer SOL from pool vault
+    let pool_vault_seeds = &[
+        POOL_VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.pool.vault_bump],
+    ];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.provider.to_account_info(),
+            },
+            &[&pool_vault_seeds[..]],
+        ),
+        sol_out,
+    )?;
+
+    // Transfer tokens from pool
+    let pool_seeds = &[
+        POOL_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.pool.bump],
+    ];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.provider_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            &[&pool_seeds[..]],
+        ),
+        tokens_out,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Update pool state
+    let pool = &mut ctx.accounts.pool;
+    pool.sol_reserves = pool.sol_reserves.checked_sub(sol_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.token_reserves = pool.token_reserves.checked_sub(tokens_out)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.lp_supply = pool.lp_supply.checked_sub(lp_amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    msg!("Removed {} LP for {} lamports and {} tokens", lp_amount, sol_out, tokens_out);
+
+    Ok(())
+}
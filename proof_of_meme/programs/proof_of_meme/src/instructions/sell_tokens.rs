@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, CurveStatus, GenesisPool};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::TradeExecuted;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SellTokens<'info> {
     #[account(mut)]
@@ -11,7 +14,8 @@ pub struct SellTokens<'info> {
 
     #[account(
         seeds = [PLATFORM_SEED],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -19,7 +23,8 @@ pub struct SellTokens<'info> {
         // Validate meme has correct seed derivation
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive
+        constraint = meme.status == MemeStatus::Launched @ ProofOfMemeError::CurveNotActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -42,26 +47,31 @@ pub struct SellTokens<'info> {
     )]
     pub genesis_pool: Account<'info, GenesisPool>,
 
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     /// Curve's token account
     #[account(
         mut,
-        associated_token::mint = meme.mint,
-        associated_token::authority = curve
+        associated_token::mint = mint,
+        associated_token::authority = curve,
+        associated_token::token_program = token_program
     )]
-    pub curve_token_account: Account<'info, TokenAccount>,
+    pub curve_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Seller's token account
     #[account(
         mut,
-        associated_token::mint = meme.mint,
-        associated_token::authority = seller
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program
     )]
-    pub seller_token_account: Account<'info, TokenAccount>,
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Curve's SOL vault
     #[account(
         mut,
-        seeds = [b"curve_vault", meme.key().as_ref()],
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
         bump = curve.vault_bump
     )]
     /// CHECK: PDA vault validated by seeds
@@ -70,12 +80,12 @@ pub struct SellTokens<'info> {
     /// Platform fee recipient - MUST match platform config
     #[account(
         mut,
-        constraint = platform_authority.key() == platform.authority @ ProofOfMemeError::InvalidPlatformAuthority
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
     )]
     /// CHECK: Validated against platform config
-    pub platform_authority: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -113,67 +123,70 @@ pub fn sell_tokens(ctx: Context<SellTokens>, token_amount: u64, min_sol_out: u64
     require!(sol_out_gross <= curve.real_sol_reserves, ProofOfMemeError::InsufficientSol);
 
     // Calculate fee distribution
-    let genesis_fee = trading_fee
-        .checked_mul(platform.genesis_fee_bps as u64)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-
-    let platform_fee = trading_fee
-        .checked_mul(platform.platform_fee_bps as u64)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-
-    let burn_fee = trading_fee.checked_sub(genesis_fee)
-        .ok_or(ProofOfMemeError::MathOverflow)?
-        .checked_sub(platform_fee)
+    let (genesis_fee, platform_fee, burn_fee) = platform.split_trading_fee(trading_fee)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
     // Transfer tokens from seller to curve FIRST (receive before send pattern)
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
+            TransferChecked {
                 from: ctx.accounts.seller_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.curve_token_account.to_account_info(),
                 authority: ctx.accounts.seller.to_account_info(),
             },
         ),
         token_amount,
+        ctx.accounts.mint.decimals,
     )?;
 
     // Calculate amounts to transfer
     // Genesis fee stays in vault for genesis backers to claim
     // Platform fee goes to platform authority
-    // Burn fee is tracked but stays in vault (effectively reducing supply value)
+    // Burn fee stays in vault until execute_buyback_burn spends it
     let sol_to_seller = sol_out_net;
 
+    let meme_key = ctx.accounts.meme.key();
+    let curve_vault_seeds = &[
+        CURVE_VAULT_SEED,
+        meme_key.as_ref(),
+        &[curve.vault_bump],
+    ];
+
     // Transfer SOL from curve vault to seller
-    **ctx.accounts.curve_vault.try_borrow_mut_lamports()? = ctx.accounts.curve_vault
-        .lamports()
-        .checked_sub(sol_to_seller)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-    **ctx.accounts.seller.try_borrow_mut_lamports()? = ctx.accounts.seller
-        .lamports()
-        .checked_add(sol_to_seller)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+            &[&curve_vault_seeds[..]],
+        ),
+        sol_to_seller,
+    )?;
 
     // Transfer platform fee from vault
     if platform_fee > 0 {
-        **ctx.accounts.curve_vault.try_borrow_mut_lamports()? = ctx.accounts.curve_vault
-            .lamports()
-            .checked_sub(platform_fee)
-            .ok_or(ProofOfMemeError::MathOverflow)?;
-        **ctx.accounts.platform_authority.try_borrow_mut_lamports()? = ctx.accounts.platform_authority
-            .lamports()
-            .checked_add(platform_fee)
-            .ok_or(ProofOfMemeError::MathOverflow)?;
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
+                },
+                &[&curve_vault_seeds[..]],
+            ),
+            platform_fee,
+        )?;
     }
 
     // Genesis fee stays in vault - will be claimed by genesis backers
 
     // Update curve state
+    let pre_sol_reserves = curve.real_sol_reserves;
+    let pre_token_reserves = curve.real_token_reserves;
     curve.apply_sell(token_amount, sol_out_gross);
     curve.genesis_fees_accumulated = curve.genesis_fees_accumulated
         .checked_add(genesis_fee)
@@ -190,6 +203,21 @@ pub fn sell_tokens(ctx: Context<SellTokens>, token_amount: u64, min_sol_out: u64
         .checked_add(genesis_fee)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    emit_cpi!(TradeExecuted {
+        meme: meme_key,
+        trader: ctx.accounts.seller.key(),
+        is_buy: false,
+        sol_amount: sol_out_gross,
+        token_amount,
+        trading_fee,
+        pre_sol_reserves,
+        pre_token_reserves,
+        post_sol_reserves: curve.real_sol_reserves,
+        post_token_reserves: curve.real_token_reserves,
+        post_price: curve.get_current_price().ok_or(ProofOfMemeError::MathOverflow)?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Sold {} tokens for {} lamports (fee: {} lamports)",
         token_amount, sol_out_net, trading_fee);
 
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{PlatformConfig, Meme, MemeStatus};
+use crate::state::{PlatformConfig, Meme, MemeStatus, TokenProgram, CurveParams};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::MemeSubmitted;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SubmitMeme<'info> {
     #[account(mut)]
@@ -12,7 +14,8 @@ pub struct SubmitMeme<'info> {
     #[account(
         mut,
         seeds = [PLATFORM_SEED],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_SUBMISSIONS) @ ProofOfMemeError::Paused
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -37,14 +40,15 @@ pub struct SubmitMeme<'info> {
     /// Platform fee recipient - MUST match platform config
     #[account(
         mut,
-        constraint = platform_authority.key() == platform.authority @ ProofOfMemeError::InvalidPlatformAuthority
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
     )]
     /// CHECK: Validated against platform config
-    pub platform_authority: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn submit_meme(
     ctx: Context<SubmitMeme>,
     name: String,
@@ -54,6 +58,10 @@ pub fn submit_meme(
     sol_goal: u64,
     min_backers: u32,
     duration_seconds: i64,
+    launch_on_goal: bool,
+    sol_cap: u64,
+    token_program: TokenProgram,
+    curve_params: CurveParams,
 ) -> Result<()> {
     // Validate name is not empty
     require!(!name.is_empty(), ProofOfMemeError::EmptyName);
@@ -72,6 +80,9 @@ pub fn submit_meme(
     require!(sol_goal <= MAX_SOL_GOAL, ProofOfMemeError::GoalTooHigh);
     require!(min_backers >= MIN_BACKERS, ProofOfMemeError::MinBackersTooLow);
 
+    // Optional cap on accepted backing (0 = uncapped)
+    require!(sol_cap == 0 || sol_cap >= sol_goal, ProofOfMemeError::InvalidSolCap);
+
     // Validate duration
     require!(duration_seconds >= MIN_PROVING_DURATION, ProofOfMemeError::DurationTooShort);
     require!(duration_seconds <= MAX_PROVING_DURATION, ProofOfMemeError::DurationTooLong);
@@ -80,17 +91,42 @@ pub fn submit_meme(
     let meme = &mut ctx.accounts.meme;
     let clock = Clock::get()?;
 
-    // Transfer submission fee to platform authority
-    if platform.submission_fee > 0 {
+    // Curve parameters must fall inside the platform's bounds once defaults are filled in
+    let curve_params = curve_params.with_defaults();
+    require!(platform.curve_bounds.allows(&curve_params), ProofOfMemeError::InvalidCurveParams);
+    require!(curve_params.kind.is_valid(), ProofOfMemeError::InvalidCurveShape);
+    require!(curve_params.completes_before_sellout(sol_goal), ProofOfMemeError::CurveSellsOut);
+
+    // Escrow the crank reward out of the submission fee; config validation keeps it <= the fee
+    let crank_reward = platform.crank_reward.min(platform.submission_fee);
+    let platform_fee = platform.submission_fee
+        .checked_sub(crank_reward)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    if crank_reward > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            crank_reward,
+        )?;
+    }
+
+    // Transfer the rest of the submission fee to platform fee recipient
+    if platform_fee > 0 {
         system_program::transfer(
             CpiContext::new(
                 ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
                     from: ctx.accounts.creator.to_account_info(),
-                    to: ctx.accounts.platform_authority.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
                 },
             ),
-            platform.submission_fee,
+            platform_fee,
         )?;
     }
 
@@ -115,12 +151,40 @@ pub fn submit_meme(
     meme.index = platform.total_memes_submitted;
     meme.bump = ctx.bumps.meme;
     meme.vault_bump = ctx.bumps.vault;
+    meme.frozen = false;
+    meme.crank_reward = crank_reward;
+    // Snapshot the launch window so later config changes don't move it
+    meme.launch_on_goal = launch_on_goal;
+    meme.early_launch_at = clock.unix_timestamp
+        .checked_add(platform.launch_window_seconds)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    meme.sol_cap = sol_cap;
+    meme.sol_oversubscribed = 0;
+    meme.metadata_updates = 0;
+    meme.metadata_updated_at = 0;
+    meme.metadata_locked = false;
+    meme.token_program = token_program;
+    meme.curve_params = curve_params;
 
     // Increment counter
     platform.total_memes_submitted = platform.total_memes_submitted
         .checked_add(1)
         .ok_or(ProofOfMemeError::MathOverflow)?;
 
+    emit_cpi!(MemeSubmitted {
+        meme: meme.key(),
+        creator: meme.creator,
+        index: meme.index,
+        sol_goal,
+        min_backers,
+        proving_ends_at: meme.proving_ends_at,
+        launch_on_goal,
+        sol_cap,
+        token_program,
+        curve_params,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Meme submitted to Proving Grounds");
     msg!("Index: {}, Goal: {} SOL, Min backers: {}",
         meme.index,
@@ -0,0 +1,327 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{PlatformConfig, Meme, MemeStatus, BondingCurve, GenesisPool, Pool, SwapDirection};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::TradeExecuted;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_TRADING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Migrated @ ProofOfMemeError::CurveNotComplete,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Box<Account<'info, Meme>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_SEED, meme.key().as_ref()],
+        bump = curve.bump,
+        constraint = curve.meme == meme.key() @ ProofOfMemeError::InvalidCurveAccount,
+        constraint = curve.migration_pool == pool.key() @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [GENESIS_POOL_SEED, meme.key().as_ref()],
+        bump = genesis_pool.bump,
+        constraint = genesis_pool.meme == meme.key() @ ProofOfMemeError::AccountMismatch
+    )]
+    pub genesis_pool: Box<Account<'info, GenesisPool>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, meme.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.meme == meme.key() @ ProofOfMemeError::InvalidPoolAccount
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(address = meme.mint @ ProofOfMemeError::AccountMismatch)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool's token reserves
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Trader's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = trader,
+        associated_token::token_program = token_program
+    )]
+    pub trader_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's SOL vault
+    #[account(
+        mut,
+        seeds = [POOL_VAULT_SEED, meme.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub pool_vault: SystemAccount<'info>,
+
+    /// Curve's SOL vault - genesis fees are paid here so claim_genesis_fees keeps working
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, meme.key().as_ref()],
+        bump = curve.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub curve_vault: SystemAccount<'info>,
+
+    /// Platform fee recipient - MUST match platform config
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
+    )]
+    /// CHECK: Validated against platform config
+    pub fee_recipient: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn swap(
+    ctx: Context<Swap>,
+    amount_in: u64,
+    min_amount_out: u64,
+    direction: SwapDirection,
+) -> Result<()> {
+    // Validate input amount
+    require!(amount_in > 0, ProofOfMemeError::ZeroAmount);
+
+    let meme_key = ctx.accounts.meme.key();
+    let pool_vault_seeds = &[
+        POOL_VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.pool.vault_bump],
+    ];
+
+    let is_buy = matches!(direction, SwapDirection::SolToToken);
+    let pre_sol_reserves = ctx.accounts.pool.sol_reserves;
+    let pre_token_reserves = ctx.accounts.pool.token_reserves;
+
+    // Trading fee (1%) is always taken on the SOL side, split like the bonding curve
+    let (sol_volume, tokens_moved, trading_fee, genesis_fee, platform_fee, burn_fee) = match direction {
+        SwapDirection::SolToToken => {
+            let trading_fee = amount_in
+                .checked_mul(TRADING_FEE_BPS)
+                .ok_or(ProofOfMemeError::MathOverflow)?
+                .checked_div(BPS_DENOMINATOR)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            let (genesis_fee, platform_fee, burn_fee) = ctx.accounts.platform.split_trading_fee(trading_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            let sol_after_fee = amount_in.checked_sub(trading_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            let tokens_out = ctx.accounts.pool.calculate_buy_tokens(sol_after_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            require!(tokens_out > 0, ProofOfMemeError::InvalidTokenAmount);
+            require!(tokens_out >= min_amount_out, ProofOfMemeError::SlippageExceeded);
+            require!(tokens_out < ctx.accounts.pool.token_reserves, ProofOfMemeError::InsufficientLiquidity);
+
+            // Reserves + burn fee to the pool vault
+            let sol_to_pool = sol_after_fee.checked_add(burn_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.trader.to_account_info(),
+                        to: ctx.accounts.pool_vault.to_account_info(),
+                    },
+                ),
+                sol_to_pool,
+            )?;
+
+            // Genesis fee to the curve vault
+            if genesis_fee > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.trader.to_account_info(),
+                            to: ctx.accounts.curve_vault.to_account_info(),
+                        },
+                    ),
+                    genesis_fee,
+                )?;
+            }
+
+            // Platform fee
+            if platform_fee > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.trader.to_account_info(),
+                            to: ctx.accounts.fee_recipient.to_account_info(),
+                        },
+                    ),
+                    platform_fee,
+                )?;
+            }
+
+            // Tokens from pool to trader
+            let pool_seeds = &[
+                POOL_SEED,
+                meme_key.as_ref(),
+                &[ctx.accounts.pool.bump],
+            ];
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.pool_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.trader_token_account.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    &[&pool_seeds[..]],
+                ),
+                tokens_out,
+                ctx.accounts.mint.decimals,
+            )?;
+
+            let pool = &mut ctx.accounts.pool;
+            pool.sol_reserves = pool.sol_reserves.checked_add(sol_after_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            pool.token_reserves = pool.token_reserves.checked_sub(tokens_out)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            (amount_in, tokens_out, trading_fee, genesis_fee, platform_fee, burn_fee)
+        }
+        SwapDirection::TokenToSol => {
+            let sol_out_gross = ctx.accounts.pool.calculate_sell_sol(amount_in)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            require!(sol_out_gross > 0, ProofOfMemeError::InvalidTokenAmount);
+            require!(sol_out_gross < ctx.accounts.pool.sol_reserves, ProofOfMemeError::InsufficientLiquidity);
+
+            let trading_fee = sol_out_gross
+                .checked_mul(TRADING_FEE_BPS)
+                .ok_or(ProofOfMemeError::MathOverflow)?
+                .checked_div(BPS_DENOMINATOR)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            let (genesis_fee, platform_fee, burn_fee) = ctx.accounts.platform.split_trading_fee(trading_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            let sol_out_net = sol_out_gross.checked_sub(trading_fee)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            require!(sol_out_net >= min_amount_out, ProofOfMemeError::SlippageExceeded);
+
+            // Tokens from trader to pool FIRST (receive before send pattern)
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.trader_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.pool_token_account.to_account_info(),
+                        authority: ctx.accounts.trader.to_account_info(),
+                    },
+                ),
+                amount_in,
+                ctx.accounts.mint.decimals,
+            )?;
+
+            // SOL to trader, genesis fee to curve vault, platform fee to platform; burn fee stays
+            for (to, amount) in [
+                (ctx.accounts.trader.to_account_info(), sol_out_net),
+                (ctx.accounts.curve_vault.to_account_info(), genesis_fee),
+                (ctx.accounts.fee_recipient.to_account_info(), platform_fee),
+            ] {
+                if amount > 0 {
+                    system_program::transfer(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.system_program.to_account_info(),
+                            system_program::Transfer {
+                                from: ctx.accounts.pool_vault.to_account_info(),
+                                to,
+                            },
+                            &[&pool_vault_seeds[..]],
+                        ),
+                        amount,
+                    )?;
+                }
+            }
+
+            let pool = &mut ctx.accounts.pool;
+            pool.sol_reserves = pool.sol_reserves.checked_sub(sol_out_gross)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+            pool.token_reserves = pool.token_reserves.checked_add(amount_in)
+                .ok_or(ProofOfMemeError::MathOverflow)?;
+
+            (sol_out_gross, amount_in, trading_fee, genesis_fee, platform_fee, burn_fee)
+        }
+    };
+
+    // Fee tracking
+    let pool = &mut ctx.accounts.pool;
+    pool.total_volume = pool.total_volume.checked_add(sol_volume)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.genesis_fees_accumulated = pool.genesis_fees_accumulated
+        .checked_add(genesis_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.platform_fees_accumulated = pool.platform_fees_accumulated
+        .checked_add(platform_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    pool.burn_fees_accumulated = pool.burn_fees_accumulated
+        .checked_add(burn_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    // Genesis fees keep accruing to backers after graduation
+    let curve = &mut ctx.accounts.curve;
+    curve.genesis_fees_accumulated = curve.genesis_fees_accumulated
+        .checked_add(genesis_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    let genesis_pool = &mut ctx.accounts.genesis_pool;
+    genesis_pool.total_fees = genesis_pool.total_fees
+        .checked_add(genesis_fee)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    emit_cpi!(TradeExecuted {
+        meme: meme_key,
+        trader: ctx.accounts.trader.key(),
+        is_buy,
+        sol_amount: sol_volume,
+        token_amount: tokens_moved,
+        trading_fee,
+        pre_sol_reserves,
+        pre_token_reserves,
+        post_sol_reserves: ctx.accounts.pool.sol_reserves,
+        post_token_reserves: ctx.accounts.pool.token_reserves,
+        post_price: ctx.accounts.pool.get_current_price().ok_or(ProofOfMemeError::MathOverflow)?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Swapped {} tokens for {} lamports in pool (fee: {} lamports)",
+        tokens_moved, sol_volume, trading_fee);
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, Meme, MemeStatus, Backing};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::MemeUnbacked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnbackMeme<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
+    )]
+    pub meme: Account<'info, Meme>,
+
+    #[account(
+        mut,
+        seeds = [BACKING_SEED, meme.key().as_ref(), backer.key().as_ref()],
+        bump = backing.bump,
+        constraint = backing.backer == backer.key() @ ProofOfMemeError::NoBackingFound,
+        constraint = backing.meme == meme.key() @ ProofOfMemeError::AccountMismatch,
+        constraint = backing.amount > 0 @ ProofOfMemeError::NoBackingFound
+    )]
+    pub backing: Account<'info, Backing>,
+
+    /// The vault that holds backing SOL
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, meme.key().as_ref()],
+        bump = meme.vault_bump
+    )]
+    /// CHECK: PDA vault validated by seeds
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == platform.fee_recipient @ ProofOfMemeError::InvalidFeeRecipient
+    )]
+    /// CHECK: Validated against platform config
+    pub fee_recipient: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn unback_meme(ctx: Context<UnbackMeme>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProofOfMemeError::ZeroAmount);
+
+    let clock = Clock::get()?;
+    let backing_amount = ctx.accounts.backing.amount;
+
+    // Backing is locked in once the meme can be finalized (proving ended or an early
+    // launch is open), even before anyone finalizes
+    require!(!ctx.accounts.meme.can_finalize(clock.unix_timestamp), ProofOfMemeError::ProvingEnded);
+    require!(amount <= backing_amount, ProofOfMemeError::UnbackExceedsBacking);
+
+    // A partial unback must leave a backing that would have been accepted as new
+    let remaining = backing_amount - amount;
+    require!(remaining == 0 || remaining >= MIN_BACKING_AMOUNT, ProofOfMemeError::BackingTooLow);
+
+    let penalty = ctx.accounts.platform.unback_penalty(amount).ok_or(ProofOfMemeError::MathOverflow)?;
+    let returned = amount.checked_sub(penalty).ok_or(ProofOfMemeError::MathOverflow)?;
+
+    // CRITICAL: Verify vault has enough balance
+    require!(amount <= ctx.accounts.vault.lamports(), ProofOfMemeError::InsufficientVaultBalance);
+
+    let meme_key = ctx.accounts.meme.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.meme.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.backer.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        returned,
+    )?;
+
+    if penalty > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
+                },
+                &[&vault_seeds[..]],
+            ),
+            penalty,
+        )?;
+
+        let platform = &mut ctx.accounts.platform;
+        platform.total_platform_fees = platform.total_platform_fees
+            .checked_add(penalty)
+            .ok_or(ProofOfMemeError::MathOverflow)?;
+    }
+
+    let meme = &mut ctx.accounts.meme;
+    meme.sol_backed = meme.sol_backed.checked_sub(amount)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    if remaining == 0 {
+        meme.backer_count = meme.backer_count.checked_sub(1)
+            .ok_or(ProofOfMemeError::MathOverflow)?;
+    }
+
+    if ctx.accounts.backer.key() == meme.creator {
+        meme.creator_backing = remaining;
+    }
+
+    emit_cpi!(MemeUnbacked {
+        meme: meme.key(),
+        backer: ctx.accounts.backer.key(),
+        amount,
+        penalty,
+        backer_total: remaining,
+        sol_backed: meme.sol_backed,
+        backer_count: meme.backer_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Unbacked {} lamports ({} penalty, remaining backing: {})", amount, penalty, remaining);
+
+    // A full exit closes the record; backing again starts a new one
+    if remaining == 0 {
+        ctx.accounts.backing.close(ctx.accounts.backer.to_account_info())?;
+    } else {
+        let backing = &mut ctx.accounts.backing;
+        backing.amount = remaining;
+        backing.qualifies_for_fees = remaining >= MIN_BACKING_AMOUNT;
+    }
+
+    Ok(())
+}
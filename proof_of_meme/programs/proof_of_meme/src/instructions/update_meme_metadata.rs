@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, Meme, MemeStatus};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+use crate::events::MemeMetadataUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMemeMetadata<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_SUBMISSIONS) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEME_SEED, &meme.index.to_le_bytes()],
+        bump = meme.bump,
+        constraint = meme.creator == creator.key() @ ProofOfMemeError::NotMemeCreator,
+        constraint = meme.status == MemeStatus::Proving @ ProofOfMemeError::AlreadyLaunched,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused,
+        constraint = !meme.metadata_locked @ ProofOfMemeError::MetadataLocked
+    )]
+    pub meme: Account<'info, Meme>,
+}
+
+pub fn update_meme_metadata(ctx: Context<UpdateMemeMetadata>, uri: String, description: String) -> Result<()> {
+    // Same limits as submit_meme
+    require!(uri.len() <= MAX_URI_LENGTH, ProofOfMemeError::UriTooLong);
+    require!(description.len() <= MAX_DESCRIPTION_LENGTH, ProofOfMemeError::DescriptionTooLong);
+
+    let meme = &mut ctx.accounts.meme;
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp < meme.proving_ends_at, ProofOfMemeError::ProvingEnded);
+
+    meme.set_uri(&uri);
+    meme.set_description(&description);
+    meme.metadata_updates = meme.metadata_updates.checked_add(1)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+    meme.metadata_updated_at = clock.unix_timestamp;
+
+    emit_cpi!(MemeMetadataUpdated {
+        meme: meme.key(),
+        uri,
+        description,
+        metadata_updates: meme.metadata_updates,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Metadata updated for {} (change #{})", meme.get_name(), meme.metadata_updates);
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, CurveBounds};
+use crate::constants::*;
+use crate::errors::ProofOfMemeError;
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    submission_fee: u64,
+    platform_fee_bps: u16,
+    genesis_fee_bps: u16,
+    burn_fee_bps: u16,
+    crank_reward: u64,
+    launch_window_seconds: i64,
+    unback_penalty_bps: u16,
+    config_timelock_seconds: i64,
+    curve_bounds: CurveBounds,
+) -> Result<()> {
+    // Same validation as initialize_platform
+    require!(
+        PlatformConfig::is_valid_fee_split(platform_fee_bps, genesis_fee_bps, burn_fee_bps),
+        ProofOfMemeError::InvalidFeeConfig
+    );
+    require!(
+        PlatformConfig::is_valid_crank_reward(crank_reward, submission_fee),
+        ProofOfMemeError::InvalidCrankReward
+    );
+    require!(
+        PlatformConfig::is_valid_launch_window(launch_window_seconds),
+        ProofOfMemeError::InvalidLaunchWindow
+    );
+    require!(
+        PlatformConfig::is_valid_unback_penalty(unback_penalty_bps),
+        ProofOfMemeError::InvalidUnbackPenalty
+    );
+    require!(
+        PlatformConfig::is_valid_config_timelock(config_timelock_seconds),
+        ProofOfMemeError::InvalidConfigTimelock
+    );
+    require!(curve_bounds.is_valid(), ProofOfMemeError::InvalidCurveBounds);
+
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    // No timelock configured - apply immediately
+    if platform.config_timelock_seconds == 0 {
+        platform.submission_fee = submission_fee;
+        platform.platform_fee_bps = platform_fee_bps;
+        platform.genesis_fee_bps = genesis_fee_bps;
+        platform.burn_fee_bps = burn_fee_bps;
+        platform.crank_reward = crank_reward;
+        platform.launch_window_seconds = launch_window_seconds;
+        platform.unback_penalty_bps = unback_penalty_bps;
+        platform.config_timelock_seconds = config_timelock_seconds;
+        platform.curve_bounds = curve_bounds;
+        platform.pending_config_activates_at = 0;
+
+        msg!("Platform config updated with submission fee: {} lamports", submission_fee);
+        msg!("Fee split - Platform: {}bps, Genesis: {}bps, Burn: {}bps",
+            platform_fee_bps, genesis_fee_bps, burn_fee_bps);
+        msg!("Crank reward: {} lamports, launch window: {}s", crank_reward, launch_window_seconds);
+        msg!("Unback penalty: {}bps", unback_penalty_bps);
+        msg!("Curve bounds: {:?}", curve_bounds);
+
+        return Ok(());
+    }
+
+    // Otherwise queue the change behind the current timelock (replaces any pending change)
+    let activates_at = clock.unix_timestamp
+        .checked_add(platform.config_timelock_seconds)
+        .ok_or(ProofOfMemeError::MathOverflow)?;
+
+    platform.pending_submission_fee = submission_fee;
+    platform.pending_platform_fee_bps = platform_fee_bps;
+    platform.pending_genesis_fee_bps = genesis_fee_bps;
+    platform.pending_burn_fee_bps = burn_fee_bps;
+    platform.pending_crank_reward = crank_reward;
+    platform.pending_launch_window_seconds = launch_window_seconds;
+    platform.pending_unback_penalty_bps = unback_penalty_bps;
+    platform.pending_config_timelock_seconds = config_timelock_seconds;
+    platform.pending_curve_bounds = curve_bounds;
+    platform.pending_config_activates_at = activates_at;
+
+    msg!("Platform config update queued, activates at {}", activates_at);
+    msg!("Pending fee split - Platform: {}bps, Genesis: {}bps, Burn: {}bps",
+        platform_fee_bps, genesis_fee_bps, burn_fee_bps);
+    msg!("Pending crank reward: {} lamports, launch window: {}s", crank_reward, launch_window_seconds);
+    msg!("Pending unback penalty: {}bps", unback_penalty_bps);
+    msg!("Pending curve bounds: {:?}", curve_bounds);
+
+    Ok(())
+}
+
+// Anyone can apply a queued config once its timelock has elapsed
+#[derive(Accounts)]
+pub struct ApplyPlatformConfig<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.has_pending_config() @ ProofOfMemeError::NoPendingConfig
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+pub fn apply_platform_config(ctx: Context<ApplyPlatformConfig>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= platform.pending_config_activates_at,
+        ProofOfMemeError::ConfigTimelockActive
+    );
+
+    platform.submission_fee = platform.pending_submission_fee;
+    platform.platform_fee_bps = platform.pending_platform_fee_bps;
+    platform.genesis_fee_bps = platform.pending_genesis_fee_bps;
+    platform.burn_fee_bps = platform.pending_burn_fee_bps;
+    platform.crank_reward = platform.pending_crank_reward;
+    platform.launch_window_seconds = platform.pending_launch_window_seconds;
+    platform.unback_penalty_bps = platform.pending_unback_penalty_bps;
+    platform.config_timelock_seconds = platform.pending_config_timelock_seconds;
+    platform.curve_bounds = platform.pending_curve_bounds;
+    platform.pending_config_activates_at = 0;
+
+    msg!("Platform config applied with submission fee: {} lamports", platform.submission_fee);
+    msg!("Fee split - Platform: {}bps, Genesis: {}bps, Burn: {}bps",
+        platform.platform_fee_bps, platform.genesis_fee_bps, platform.burn_fee_bps);
+    msg!("Crank reward: {} lamports, launch window: {}s", platform.crank_reward, platform.launch_window_seconds);
+    msg!("Unback penalty: {}bps", platform.unback_penalty_bps);
+    msg!("Curve bounds: {:?}", platform.curve_bounds);
+
+    Ok(())
+}
+
+// Authority can withdraw a queued config before it is applied
+#[derive(Accounts)]
+pub struct CancelPlatformConfigUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ProofOfMemeError::Unauthorized,
+        constraint = platform.has_pending_config() @ ProofOfMemeError::NoPendingConfig
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+pub fn cancel_platform_config_update(ctx: Context<CancelPlatformConfigUpdate>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+
+    platform.pending_config_activates_at = 0;
+
+    msg!("Pending platform config update cancelled");
+
+    Ok(())
+}
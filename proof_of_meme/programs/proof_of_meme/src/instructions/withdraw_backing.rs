@@ -1,18 +1,29 @@
 use anchor_lang::prelude::*;
-use crate::state::{Meme, MemeStatus, Backing};
+use anchor_lang::system_program;
+use crate::state::{PlatformConfig, Meme, Backing};
 use crate::constants::*;
 use crate::errors::ProofOfMemeError;
+use crate::events::BackingWithdrawn;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawBacking<'info> {
     #[account(mut)]
     pub backer: Signer<'info>,
 
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BACKING) @ ProofOfMemeError::Paused
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
     #[account(
         mut,
         seeds = [MEME_SEED, &meme.index.to_le_bytes()],
         bump = meme.bump,
-        constraint = meme.status == MemeStatus::Failed @ ProofOfMemeError::ProvingStillActive
+        constraint = meme.is_refundable() @ ProofOfMemeError::ProvingStillActive,
+        constraint = !meme.frozen @ ProofOfMemeError::Paused
     )]
     pub meme: Account<'info, Meme>,
 
@@ -40,29 +51,46 @@ pub struct WithdrawBacking<'info> {
 }
 
 pub fn withdraw_backing(ctx: Context<WithdrawBacking>) -> Result<()> {
-    let _meme = &ctx.accounts.meme;
-    let backing = &mut ctx.accounts.backing;
-    let amount = backing.amount;
+    let amount = ctx.accounts.backing.amount;
 
     // CRITICAL: Verify vault has enough balance
     let vault_balance = ctx.accounts.vault.lamports();
     require!(amount <= vault_balance, ProofOfMemeError::InsufficientVaultBalance);
 
     // Transfer SOL back to backer from vault
-    **ctx.accounts.vault.try_borrow_mut_lamports()? = ctx.accounts.vault
-        .lamports()
-        .checked_sub(amount)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
-    **ctx.accounts.backer.try_borrow_mut_lamports()? = ctx.accounts.backer
-        .lamports()
-        .checked_add(amount)
-        .ok_or(ProofOfMemeError::MathOverflow)?;
+    let meme_key = ctx.accounts.meme.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        meme_key.as_ref(),
+        &[ctx.accounts.meme.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.backer.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    let backing = &mut ctx.accounts.backing;
 
     // Mark as withdrawn
     backing.withdrawn = true;
     backing.amount = 0;
 
-    msg!("Withdrew {} lamports for failed meme", amount);
+    emit_cpi!(BackingWithdrawn {
+        meme: ctx.accounts.meme.key(),
+        backer: ctx.accounts.backer.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Withdrew {} lamports for {:?} meme", amount, ctx.accounts.meme.status);
 
     Ok(())
 }
@@ -6,8 +6,11 @@ pub mod state;
 pub mod instructions;
 pub mod errors;
 pub mod constants;
+pub mod events;
+pub mod raydium;
 
 use instructions::*;
+use state::{CurveBounds, CurveParams, SwapDirection, TokenProgram};
 
 #[program]
 pub mod proof_of_meme {
@@ -20,11 +23,71 @@ pub mod proof_of_meme {
         platform_fee_bps: u16,
         genesis_fee_bps: u16,
         burn_fee_bps: u16,
+        vesting_cliff_seconds: i64,
+        vesting_duration_seconds: i64,
     ) -> Result<()> {
-        instructions::initialize_platform::initialize_platform(ctx, submission_fee, platform_fee_bps, genesis_fee_bps, burn_fee_bps)
+        instructions::initialize_platform::initialize_platform(ctx, submission_fee, platform_fee_bps, genesis_fee_bps, burn_fee_bps, vesting_cliff_seconds, vesting_duration_seconds)
+    }
+
+    /// Update platform fees, crank reward, launch window, unback penalty and curve bounds (queued behind the config timelock if one is set)
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        submission_fee: u64,
+        platform_fee_bps: u16,
+        genesis_fee_bps: u16,
+        burn_fee_bps: u16,
+        crank_reward: u64,
+        launch_window_seconds: i64,
+        unback_penalty_bps: u16,
+        config_timelock_seconds: i64,
+        curve_bounds: CurveBounds,
+    ) -> Result<()> {
+        instructions::update_platform_config::update_platform_config(ctx, submission_fee, platform_fee_bps, genesis_fee_bps, burn_fee_bps, crank_reward, launch_window_seconds, unback_penalty_bps, config_timelock_seconds, curve_bounds)
+    }
+
+    /// Apply a queued platform config once its timelock has elapsed
+    pub fn apply_platform_config(ctx: Context<ApplyPlatformConfig>) -> Result<()> {
+        instructions::update_platform_config::apply_platform_config(ctx)
+    }
+
+    /// Cancel a queued platform config update
+    pub fn cancel_platform_config_update(ctx: Context<CancelPlatformConfigUpdate>) -> Result<()> {
+        instructions::update_platform_config::cancel_platform_config_update(ctx)
+    }
+
+    /// Current authority proposes a new platform authority
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::platform_authority::propose_authority(ctx, new_authority)
+    }
+
+    /// Proposed authority accepts the platform authority role
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::platform_authority::accept_authority(ctx)
+    }
+
+    /// Set the account that receives platform fees
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+        instructions::platform_authority::set_fee_recipient(ctx)
+    }
+
+    /// Grow a platform config created before the config fields were added to the current layout
+    pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+        instructions::migrate_platform_config::migrate_platform_config(ctx)
+    }
+
+    /// Authority sets the emergency pause bitmask
+    pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: u8) -> Result<()> {
+        instructions::pause::set_platform_paused(ctx, paused)
+    }
+
+    /// Authority freezes or unfreezes a single meme
+    pub fn set_meme_frozen(ctx: Context<SetMemeFrozen>, frozen: bool) -> Result<()> {
+        instructions::pause::set_meme_frozen(ctx, frozen)
     }
 
     /// Creator submits a new meme to the Proving Grounds
+    #[allow(clippy::too_many_arguments)]
     pub fn submit_meme(
         ctx: Context<SubmitMeme>,
         name: String,
@@ -34,8 +97,17 @@ pub mod proof_of_meme {
         sol_goal: u64,
         min_backers: u32,
         duration_seconds: i64,
+        launch_on_goal: bool,
+        sol_cap: u64,
+        token_program: TokenProgram,
+        curve_params: CurveParams,
     ) -> Result<()> {
-        instructions::submit_meme::submit_meme(ctx, name, symbol, uri, description, sol_goal, min_backers, duration_seconds)
+        instructions::submit_meme::submit_meme(ctx, name, symbol, uri, description, sol_goal, min_backers, duration_seconds, launch_on_goal, sol_cap, token_program, curve_params)
+    }
+
+    /// Creator changes the uri and description of a proving meme before the metadata lock
+    pub fn update_meme_metadata(ctx: Context<UpdateMemeMetadata>, uri: String, description: String) -> Result<()> {
+        instructions::update_meme_metadata::update_meme_metadata(ctx, uri, description)
     }
 
     /// Back a meme in the Proving Grounds
@@ -43,12 +115,22 @@ pub mod proof_of_meme {
         instructions::back_meme::back_meme(ctx, amount)
     }
 
+    /// Reduce or cancel backing while the meme is still proving
+    pub fn unback_meme(ctx: Context<UnbackMeme>, amount: u64) -> Result<()> {
+        instructions::unback_meme::unback_meme(ctx, amount)
+    }
+
     /// Withdraw backing before launch (if goal not met)
     pub fn withdraw_backing(ctx: Context<WithdrawBacking>) -> Result<()> {
         instructions::withdraw_backing::withdraw_backing(ctx)
     }
 
-    /// Finalize proving and launch token
+    /// Reclaim the pro-rata excess of an oversubscribed launch
+    pub fn claim_backing_refund(ctx: Context<ClaimBackingRefund>) -> Result<()> {
+        instructions::claim_backing_refund::claim_backing_refund(ctx)
+    }
+
+    /// Finalize proving and launch token (early for launch_on_goal memes that reached their goal)
     pub fn finalize_proving(ctx: Context<FinalizeProving>) -> Result<()> {
         instructions::finalize_proving::finalize_proving(ctx)
     }
@@ -58,6 +140,11 @@ pub mod proof_of_meme {
         instructions::finalize_proving::mark_meme_failed(ctx)
     }
 
+    /// Creator cancels a meme that is still proving; backers withdraw as if it failed
+    pub fn cancel_meme(ctx: Context<CancelMeme>) -> Result<()> {
+        instructions::cancel_meme::cancel_meme(ctx)
+    }
+
     /// Buy tokens on the bonding curve
     pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
         instructions::buy_tokens::buy_tokens(ctx, sol_amount, min_tokens_out)
@@ -73,8 +160,48 @@ pub mod proof_of_meme {
         instructions::claim_genesis_fees::claim_genesis_fees(ctx)
     }
 
+    /// Genesis backers claim their share of the genesis token allocation
+    pub fn claim_genesis_tokens(ctx: Context<ClaimGenesisTokens>) -> Result<()> {
+        instructions::claim_genesis_tokens::claim_genesis_tokens(ctx)
+    }
+
+    /// Genesis backers claim the unlocked portion of their vesting genesis tokens
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested::claim_vested(ctx)
+    }
+
+    /// Anyone spends accumulated burn fees to buy tokens off the curve and burn them
+    pub fn execute_buyback_burn(ctx: Context<ExecuteBuybackBurn>) -> Result<()> {
+        instructions::execute_buyback_burn::execute_buyback_burn(ctx)
+    }
+
     /// Migrate to Raydium when bonding curve is complete
     pub fn migrate_to_raydium(ctx: Context<MigrateToRaydium>) -> Result<()> {
         instructions::migrate_to_raydium::migrate_to_raydium(ctx)
     }
+
+    /// Migrate to the native constant-product pool when bonding curve is complete
+    pub fn migrate_to_pool(ctx: Context<MigrateToPool>) -> Result<()> {
+        instructions::migrate_to_pool::migrate_to_pool(ctx)
+    }
+
+    /// Add liquidity to the native pool
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, sol_amount: u64, max_token_amount: u64, min_lp_out: u64) -> Result<()> {
+        instructions::add_liquidity::add_liquidity(ctx, sol_amount, max_token_amount, min_lp_out)
+    }
+
+    /// Remove liquidity from the native pool
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, lp_amount: u64, min_sol_out: u64, min_tokens_out: u64) -> Result<()> {
+        instructions::remove_liquidity::remove_liquidity(ctx, lp_amount, min_sol_out, min_tokens_out)
+    }
+
+    /// Swap against the native pool
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64, direction: SwapDirection) -> Result<()> {
+        instructions::swap::swap(ctx, amount_in, min_amount_out, direction)
+    }
+
+    /// Anyone spends the native pool's burn fees to buy tokens out of the pool and burn them
+    pub fn execute_pool_buyback_burn(ctx: Context<ExecutePoolBuybackBurn>) -> Result<()> {
+        instructions::execute_pool_buyback_burn::execute_pool_buyback_burn(ctx)
+    }
 }
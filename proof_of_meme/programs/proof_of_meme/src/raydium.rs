@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::errors::ProofOfMemeError;
+
+/// Raydium CPMM (CP-Swap) program
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// Anchor discriminator for the CPMM `initialize` instruction (sha256("global:initialize")[..8])
+pub const CPMM_INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+/// Anchor discriminator for the CPMM `AmmConfig` account (sha256("account:AmmConfig")[..8])
+pub const AMM_CONFIG_DISCRIMINATOR: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+
+/// Offset of `create_pool_fee` in `AmmConfig`: discriminator, bump, disable_create_pool,
+/// index, then the trade, protocol and fund fee rates
+const AMM_CONFIG_CREATE_POOL_FEE_OFFSET: usize = 8 + 1 + 1 + 2 + 8 * 3;
+
+/// Lamports CPMM `initialize` charges the creator under the AMM config in `data`
+pub fn amm_config_create_pool_fee(data: &[u8]) -> Result<u64> {
+    let fee = AMM_CONFIG_CREATE_POOL_FEE_OFFSET..AMM_CONFIG_CREATE_POOL_FEE_OFFSET + 8;
+    require!(
+        data.len() >= fee.end && data[..8] == AMM_CONFIG_DISCRIMINATOR,
+        ProofOfMemeError::InvalidRaydiumAccount
+    );
+    Ok(u64::from_le_bytes(data[fee].try_into().unwrap()))
+}
+
+/// Accounts for the CPMM `initialize` instruction, in the order the program expects.
+/// token_0 / token_1 must already be sorted so that token_0_mint < token_1_mint.
+pub struct CpmmInitialize<'info> {
+    pub creator: AccountInfo<'info>,
+    pub amm_config: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub pool_state: AccountInfo<'info>,
+    pub token_0_mint: AccountInfo<'info>,
+    pub token_1_mint: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub creator_token_0: AccountInfo<'info>,
+    pub creator_token_1: AccountInfo<'info>,
+    pub creator_lp_token: AccountInfo<'info>,
+    pub token_0_vault: AccountInfo<'info>,
+    pub token_1_vault: AccountInfo<'info>,
+    pub create_pool_fee: AccountInfo<'info>,
+    pub observation_state: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_0_program: AccountInfo<'info>,
+    pub token_1_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// CPI into Raydium CPMM to create a pool and deposit the initial liquidity
+pub fn cpmm_initialize<'info>(
+    cpmm_program: AccountInfo<'info>,
+    accounts: CpmmInitialize<'info>,
+    init_amount_0: u64,
+    init_amount_1: u64,
+    open_time: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = Vec::with_capacity(8 + 8 * 3);
+    data.extend_from_slice(&CPMM_INITIALIZE_DISCRIMINATOR);
+    data.extend_from_slice(&init_amount_0.to_le_bytes());
+    data.extend_from_slice(&init_amount_1.to_le_bytes());
+    data.extend_from_slice(&open_time.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: cpmm_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.creator.key(), true),
+            AccountMeta::new_readonly(accounts.amm_config.key(), false),
+            AccountMeta::new_readonly(accounts.authority.key(), false),
+            AccountMeta::new(accounts.pool_state.key(), false),
+            AccountMeta::new_readonly(accounts.token_0_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_1_mint.key(), false),
+            AccountMeta::new(accounts.lp_mint.key(), false),
+            AccountMeta::new(accounts.creator_token_0.key(), false),
+            AccountMeta::new(accounts.creator_token_1.key(), false),
+            AccountMeta::new(accounts.creator_lp_token.key(), false),
+            AccountMeta::new(accounts.token_0_vault.key(), false),
+            AccountMeta::new(accounts.token_1_vault.key(), false),
+            AccountMeta::new(accounts.create_pool_fee.key(), false),
+            AccountMeta::new(accounts.observation_state.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_0_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_1_program.key(), false),
+            AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.creator,
+            accounts.amm_config,
+            accounts.authority,
+            accounts.pool_state,
+            accounts.token_0_mint,
+            accounts.token_1_mint,
+            accounts.lp_mint,
+            accounts.creator_token_0,
+            accounts.creator_token_1,
+            accounts.creator_lp_token,
+            accounts.token_0_vault,
+            accounts.token_1_vault,
+            accounts.create_pool_fee,
+            accounts.observation_state,
+            accounts.token_program,
+            accounts.token_0_program,
+            accounts.token_1_program,
+            accounts.associated_token_program,
+            accounts.system_program,
+            accounts.rent,
+            cpmm_program,
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -23,6 +23,10 @@ pub struct Backing {
     pub genesis_share_bps: u64,
     /// Bump seed
     pub bump: u8,
+    /// Genesis tokens released so far (vested portion of tokens_received)
+    pub tokens_claimed: u64,
+    /// Oversubscription refund paid back (amount - refunded stayed in the launch)
+    pub refunded: u64,
 }
 
 impl Backing {
@@ -37,5 +41,7 @@ impl Backing {
         8 + // fees_claimed
         8 + // genesis_share_bps
         1 + // bump
-        32; // padding
+        8 + // tokens_claimed
+        8 + // refunded
+        16; // padding
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::constants::{BPS_DENOMINATOR, CURVE_COMPLETION_SOL, GENESIS_ALLOCATION_BPS, MAX_GENESIS_ALLOCATION_BPS, TOTAL_SUPPLY};
+use crate::state::{ConstantProduct, CurveKind, CurvePricing, MigrationTarget, PriceSchedule};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum CurveStatus {
     /// Active trading
+    #[default]
     Active,
     /// Curve filled, ready for migration
     Complete,
@@ -10,9 +13,120 @@ pub enum CurveStatus {
     Migrated,
 }
 
-impl Default for CurveStatus {
-    fn default() -> Self {
-        CurveStatus::Active
+/// Curve parameters a creator picks at submission; a zero field takes the platform default
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct CurveParams {
+    /// Virtual SOL reserves at launch (0 = open at the price genesis backers paid)
+    pub initial_virtual_sol: u64,
+    /// SOL the curve raises on top of the accepted backing before it completes
+    pub completion_sol: u64,
+    /// Share of the supply minted to genesis backers (basis points)
+    pub genesis_allocation_bps: u16,
+    /// Shape of the curve
+    pub kind: CurveKind,
+    /// Where the curve's liquidity migrates once it completes
+    pub migration_target: MigrationTarget,
+}
+
+impl CurveParams {
+    pub const SIZE: usize = 8 + // initial_virtual_sol
+        8 + // completion_sol
+        2 + // genesis_allocation_bps
+        CurveKind::SIZE + // kind
+        1; // migration_target
+
+    /// Fill unset fields with the platform defaults
+    pub fn with_defaults(self) -> Self {
+        Self {
+            initial_virtual_sol: self.initial_virtual_sol,
+            completion_sol: if self.completion_sol == 0 { CURVE_COMPLETION_SOL } else { self.completion_sol },
+            genesis_allocation_bps: if self.genesis_allocation_bps == 0 {
+                GENESIS_ALLOCATION_BPS as u16
+            } else {
+                self.genesis_allocation_bps
+            },
+            kind: self.kind,
+            migration_target: self.migration_target,
+        }
+    }
+
+    /// Whether a curve launched with at least `sol_goal` of backing completes before its
+    /// supply sells out. Constant product never sells out; a price schedule opens no lower
+    /// than the goal implies, so pricing the whole supply from there has to beat `completion_sol`
+    pub fn completes_before_sellout(&self, sol_goal: u64) -> bool {
+        let Some(average_price_bps) = self.kind.average_price_bps() else {
+            return true;
+        };
+        let genesis_tokens = TOTAL_SUPPLY as u128 * self.genesis_allocation_bps as u128 / BPS_DENOMINATOR as u128;
+        let curve_tokens = TOTAL_SUPPLY as u128 - genesis_tokens;
+        let virtual_sol = if self.initial_virtual_sol > 0 {
+            self.initial_virtual_sol as u128
+        } else if genesis_tokens > 0 {
+            sol_goal as u128 * curve_tokens / genesis_tokens
+        } else {
+            return false;
+        };
+        virtual_sol * average_price_bps as u128 / BPS_DENOMINATOR as u128 > self.completion_sol as u128
+    }
+}
+
+/// Range of curve parameters creators may pick, set by the platform authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct CurveBounds {
+    /// Custom initial virtual SOL range (both 0 = creators can't override it)
+    pub min_initial_virtual_sol: u64,
+    pub max_initial_virtual_sol: u64,
+    pub min_completion_sol: u64,
+    pub max_completion_sol: u64,
+    pub min_genesis_allocation_bps: u16,
+    pub max_genesis_allocation_bps: u16,
+    /// Whether creators may pick the native pool over Raydium
+    pub allow_native_pool: bool,
+}
+
+impl CurveBounds {
+    pub const SIZE: usize = 8 + // min_initial_virtual_sol
+        8 + // max_initial_virtual_sol
+        8 + // min_completion_sol
+        8 + // max_completion_sol
+        2 + // min_genesis_allocation_bps
+        2 + // max_genesis_allocation_bps
+        1; // allow_native_pool
+
+    /// Only the default parameters
+    pub const DEFAULT: Self = Self {
+        min_initial_virtual_sol: 0,
+        max_initial_virtual_sol: 0,
+        min_completion_sol: CURVE_COMPLETION_SOL,
+        max_completion_sol: CURVE_COMPLETION_SOL,
+        min_genesis_allocation_bps: GENESIS_ALLOCATION_BPS as u16,
+        max_genesis_allocation_bps: GENESIS_ALLOCATION_BPS as u16,
+        allow_native_pool: false,
+    };
+
+    /// Bounds are valid if every range is ordered and the defaults stay inside them,
+    /// so a meme that picks nothing can always be submitted
+    pub fn is_valid(&self) -> bool {
+        let virtual_sol_valid = (self.min_initial_virtual_sol == 0 && self.max_initial_virtual_sol == 0)
+            || (self.min_initial_virtual_sol > 0 && self.min_initial_virtual_sol <= self.max_initial_virtual_sol);
+        let completion_valid = self.min_completion_sol > 0
+            && (self.min_completion_sol..=self.max_completion_sol).contains(&CURVE_COMPLETION_SOL);
+        let genesis_valid = self.min_genesis_allocation_bps > 0
+            && (self.min_genesis_allocation_bps..=self.max_genesis_allocation_bps)
+                .contains(&(GENESIS_ALLOCATION_BPS as u16))
+            && self.max_genesis_allocation_bps as u64 <= MAX_GENESIS_ALLOCATION_BPS;
+
+        virtual_sol_valid && completion_valid && genesis_valid
+    }
+
+    /// Whether `params`, with defaults filled in, fall inside the bounds
+    pub fn allows(&self, params: &CurveParams) -> bool {
+        (params.initial_virtual_sol == 0
+            || (self.min_initial_virtual_sol..=self.max_initial_virtual_sol).contains(&params.initial_virtual_sol))
+            && (self.min_completion_sol..=self.max_completion_sol).contains(&params.completion_sol)
+            && (self.min_genesis_allocation_bps..=self.max_genesis_allocation_bps)
+                .contains(&params.genesis_allocation_bps)
+            && (params.migration_target == MigrationTarget::Raydium || self.allow_native_pool)
     }
 }
 
@@ -22,9 +136,10 @@ pub struct BondingCurve {
     pub meme: Pubkey,
     /// Token mint
     pub mint: Pubkey,
-    /// Virtual SOL reserves (for pricing)
+    /// Virtual SOL reserves (for pricing). Only constant-product curves move these;
+    /// price schedules price off `real_token_reserves` and leave them at launch values
     pub virtual_sol_reserves: u64,
-    /// Virtual token reserves (for pricing)
+    /// Virtual token reserves (for pricing, constant product only)
     pub virtual_token_reserves: u64,
     /// Real SOL in the curve
     pub real_sol_reserves: u64,
@@ -50,6 +165,18 @@ pub struct BondingCurve {
     pub bump: u8,
     /// Vault bump
     pub vault_bump: u8,
+    /// Raydium pool the curve migrated into (default until migration)
+    pub migration_pool: Pubkey,
+    /// Burn fees already spent on buyback
+    pub burn_fees_spent: u64,
+    /// Tokens bought back with burn fees and burned
+    pub tokens_burned: u64,
+    /// Opening price, in lamports per token base unit scaled by `CURVE_PRICE_SCALE`
+    pub opening_price: u64,
+    /// Tokens the curve launched with
+    pub curve_supply: u64,
+    /// Shape of the curve
+    pub kind: CurveKind,
 }
 
 impl BondingCurve {
@@ -70,48 +197,56 @@ impl BondingCurve {
         8 + // completion_threshold
         1 + // bump
         1 + // vault_bump
-        64; // padding
+        32 + // migration_pool
+        8 + // burn_fees_spent
+        8 + // tokens_burned
+        8 + // opening_price
+        8 + // curve_supply
+        CurveKind::SIZE; // kind
 
-    /// Calculate tokens out for a given SOL input using constant product formula
-    /// x * y = k (simplified bonding curve)
+    /// Run `f` with the pricing for this curve's kind
+    fn with_pricing<T>(&self, f: impl FnOnce(&dyn CurvePricing) -> T) -> T {
+        match PriceSchedule::new(&self.kind, self.curve_supply, self.opening_price) {
+            Some(schedule) => f(&schedule),
+            None => f(&ConstantProduct),
+        }
+    }
+
+    /// Calculate tokens out for a given SOL input along the curve's shape
+    /// Returns None if the SOL reserves would overflow, since `apply_buy` saturates
     pub fn calculate_buy_tokens(&self, sol_in: u64) -> Option<u64> {
-        // tokens_out = (sol_in * virtual_token_reserves) / (virtual_sol_reserves + sol_in)
-        let numerator = (sol_in as u128).checked_mul(self.virtual_token_reserves as u128)?;
-        let denominator = (self.virtual_sol_reserves as u128).checked_add(sol_in as u128)?;
-        let tokens_out = numerator.checked_div(denominator)?;
+        self.virtual_sol_reserves.checked_add(sol_in)?;
+        self.real_sol_reserves.checked_add(sol_in)?;
 
-        // Cap at available tokens
-        let tokens_out = tokens_out.min(self.real_token_reserves as u128);
+        let tokens_out = self.with_pricing(|pricing| pricing.buy_tokens(self, sol_in))?;
 
-        Some(tokens_out as u64)
+        // Cap at available tokens
+        Some(tokens_out.min(self.real_token_reserves))
     }
 
     /// Calculate SOL out for a given token input
+    /// Returns None if the token reserves would overflow, since `apply_sell` saturates
     pub fn calculate_sell_sol(&self, tokens_in: u64) -> Option<u64> {
-        // sol_out = (tokens_in * virtual_sol_reserves) / (virtual_token_reserves + tokens_in)
-        let numerator = (tokens_in as u128).checked_mul(self.virtual_sol_reserves as u128)?;
-        let denominator = (self.virtual_token_reserves as u128).checked_add(tokens_in as u128)?;
-        let sol_out = numerator.checked_div(denominator)?;
+        self.virtual_token_reserves.checked_add(tokens_in)?;
+        self.real_token_reserves.checked_add(tokens_in)?;
 
-        // Cap at available SOL
-        let sol_out = sol_out.min(self.real_sol_reserves as u128);
+        let sol_out = self.with_pricing(|pricing| pricing.sell_sol(self, tokens_in))?;
 
-        Some(sol_out as u64)
+        // Cap at available SOL
+        Some(sol_out.min(self.real_sol_reserves))
     }
 
     /// Calculate current token price in lamports
     pub fn get_current_price(&self) -> Option<u64> {
-        // price = virtual_sol_reserves / virtual_token_reserves (in lamports per token)
-        let price = (self.virtual_sol_reserves as u128)
-            .checked_mul(1_000_000)? // 6 decimal precision
-            .checked_div(self.virtual_token_reserves as u128)?;
-        Some(price as u64)
+        self.with_pricing(|pricing| pricing.price(self))
     }
 
     /// Update reserves after a buy
     pub fn apply_buy(&mut self, sol_in: u64, tokens_out: u64) {
-        self.virtual_sol_reserves = self.virtual_sol_reserves.saturating_add(sol_in);
-        self.virtual_token_reserves = self.virtual_token_reserves.saturating_sub(tokens_out);
+        if self.kind == CurveKind::ConstantProduct {
+            self.virtual_sol_reserves = self.virtual_sol_reserves.saturating_add(sol_in);
+            self.virtual_token_reserves = self.virtual_token_reserves.saturating_sub(tokens_out);
+        }
         self.real_sol_reserves = self.real_sol_reserves.saturating_add(sol_in);
         self.real_token_reserves = self.real_token_reserves.saturating_sub(tokens_out);
         self.tokens_sold = self.tokens_sold.saturating_add(tokens_out);
@@ -120,17 +255,24 @@ impl BondingCurve {
 
     /// Update reserves after a sell
     pub fn apply_sell(&mut self, tokens_in: u64, sol_out: u64) {
-        self.virtual_sol_reserves = self.virtual_sol_reserves.saturating_sub(sol_out);
-        self.virtual_token_reserves = self.virtual_token_reserves.saturating_add(tokens_in);
+        if self.kind == CurveKind::ConstantProduct {
+            self.virtual_sol_reserves = self.virtual_sol_reserves.saturating_sub(sol_out);
+            self.virtual_token_reserves = self.virtual_token_reserves.saturating_add(tokens_in);
+        }
         self.real_sol_reserves = self.real_sol_reserves.saturating_sub(sol_out);
         self.real_token_reserves = self.real_token_reserves.saturating_add(tokens_in);
         self.tokens_sold = self.tokens_sold.saturating_sub(tokens_in);
         self.total_volume = self.total_volume.saturating_add(sol_out);
     }
 
+    /// Burn fees collected but not yet spent on buyback
+    pub fn pending_burn_fees(&self) -> u64 {
+        self.burn_fees_accumulated.saturating_sub(self.burn_fees_spent)
+    }
+
     /// Check if curve is complete
     pub fn is_complete(&self) -> bool {
-        self.real_sol_reserves >= self.completion_threshold
+        self.with_pricing(|pricing| pricing.is_complete(self))
     }
 }
 
@@ -149,6 +291,16 @@ pub struct GenesisPool {
     pub qualified_backer_count: u32,
     /// Bump seed
     pub bump: u8,
+    /// Genesis token allocation held in escrow for backers
+    pub total_tokens: u64,
+    /// Genesis tokens already claimed by backers
+    pub tokens_distributed: u64,
+    /// Vesting start (meme launch timestamp)
+    pub vesting_start: i64,
+    /// Cliff before any genesis tokens unlock (seconds)
+    pub vesting_cliff_seconds: i64,
+    /// Linear unlock duration for genesis tokens (seconds)
+    pub vesting_duration_seconds: i64,
 }
 
 impl GenesisPool {
@@ -159,7 +311,12 @@ impl GenesisPool {
         8 + // total_claimed
         4 + // qualified_backer_count
         1 + // bump
-        32; // padding
+        8 + // total_tokens
+        8 + // tokens_distributed
+        8 + // vesting_start
+        8 + // vesting_cliff_seconds
+        8 + // vesting_duration_seconds
+        16; // padding
 
     /// Calculate a backer's claimable fees based on their share
     pub fn calculate_claimable(&self, backer_amount: u64, already_claimed: u64) -> Option<u64> {
@@ -175,4 +332,51 @@ impl GenesisPool {
         let claimable = (total_entitled as u64).saturating_sub(already_claimed);
         Some(claimable)
     }
+
+    /// Calculate a backer's share of the genesis token allocation
+    pub fn calculate_token_share(&self, backer_amount: u64) -> Option<u64> {
+        if self.total_qualified_backing == 0 {
+            return Some(0);
+        }
+
+        // token_share = (backer_amount * total_tokens) / total_qualified_backing
+        let share = (backer_amount as u128)
+            .checked_mul(self.total_tokens as u128)?
+            .checked_div(self.total_qualified_backing as u128)?;
+
+        u64::try_from(share).ok()
+    }
+
+    /// Calculate how much of an allocation has unlocked at `now`
+    /// Nothing unlocks before the cliff, then tokens unlock linearly until the duration ends
+    pub fn calculate_vested(&self, allocation: u64, now: i64) -> Option<u64> {
+        let elapsed = now.saturating_sub(self.vesting_start);
+
+        if elapsed < self.vesting_cliff_seconds {
+            return Some(0);
+        }
+        if self.vesting_duration_seconds == 0 || elapsed >= self.vesting_duration_seconds {
+            return Some(allocation);
+        }
+
+        // vested = allocation * elapsed / duration
+        let vested = (allocation as u128)
+            .checked_mul(elapsed as u128)?
+            .checked_div(self.vesting_duration_seconds as u128)?;
+
+        u64::try_from(vested).ok()
+    }
+
+    /// Calculate a backer's share of the pool in basis points
+    pub fn calculate_share_bps(&self, backer_amount: u64) -> Option<u64> {
+        if self.total_qualified_backing == 0 {
+            return Some(0);
+        }
+
+        let share_bps = (backer_amount as u128)
+            .checked_mul(BPS_DENOMINATOR as u128)?
+            .checked_div(self.total_qualified_backing as u128)?;
+
+        u64::try_from(share_bps).ok()
+    }
 }
@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    BPS_DENOMINATOR, CURVE_PRICE_SCALE, EXPONENTIAL_CURVE_STEPS, MAX_CURVE_BREAKPOINTS, MAX_CURVE_PRICE_MULTIPLE_BPS,
+};
+use crate::state::BondingCurve;
+
+/// Most price points any curve shape needs: the opening price plus one per step
+const MAX_PRICE_POINTS: usize = EXPONENTIAL_CURVE_STEPS + 1;
+const _: () = assert!(MAX_CURVE_BREAKPOINTS < MAX_PRICE_POINTS);
+
+/// Point on a piecewise-linear curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct CurveBreakpoint {
+    /// Share of the curve supply sold at this point (basis points)
+    pub supply_bps: u16,
+    /// Price at this point as a multiple of the opening price (basis points, 10_000 = 1x)
+    pub price_bps: u32,
+}
+
+impl CurveBreakpoint {
+    pub const SIZE: usize = 2 + // supply_bps
+        4; // price_bps
+}
+
+/// Shape of the bonding curve a creator picks at submission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CurveKind {
+    /// x * y = k over the virtual reserves
+    #[default]
+    ConstantProduct,
+    /// Price rises in a straight line from the opening price to `end_price_bps` of it
+    /// once the whole curve supply is sold
+    Linear { end_price_bps: u32 },
+    /// Price compounds by `growth_bps` over each of `EXPONENTIAL_CURVE_STEPS` equal
+    /// slices of the curve supply, linear within a slice
+    Exponential { growth_bps: u16 },
+    /// Price runs from the opening price through the first `count` breakpoints, linear
+    /// in between; the last one must sit at the end of the supply
+    PiecewiseLinear { breakpoints: [CurveBreakpoint; MAX_CURVE_BREAKPOINTS], count: u8 },
+}
+
+impl CurveKind {
+    pub const SIZE: usize = 1 + // variant
+        MAX_CURVE_BREAKPOINTS * CurveBreakpoint::SIZE + // breakpoints (largest variant)
+        1; // count
+
+    /// Prices never fall as the supply sells, stay within `MAX_CURVE_PRICE_MULTIPLE_BPS`
+    /// of the opening price, and every breakpoint past `count` is left empty
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::ConstantProduct => true,
+            Self::Linear { end_price_bps } => {
+                (BPS_DENOMINATOR as u32..=MAX_CURVE_PRICE_MULTIPLE_BPS).contains(end_price_bps)
+            }
+            Self::Exponential { growth_bps } => {
+                *growth_bps > 0
+                    && self.price_multiples().is_some_and(|(points, len)| {
+                        points[len - 1].1 <= MAX_CURVE_PRICE_MULTIPLE_BPS as u64
+                    })
+            }
+            Self::PiecewiseLinear { breakpoints, count } => {
+                let count = *count as usize;
+                if count == 0 || count > MAX_CURVE_BREAKPOINTS {
+                    return false;
+                }
+                let (used, unused) = breakpoints.split_at(count);
+                let mut previous = CurveBreakpoint { supply_bps: 0, price_bps: BPS_DENOMINATOR as u32 };
+                for breakpoint in used {
+                    if breakpoint.supply_bps <= previous.supply_bps
+                        || breakpoint.price_bps < previous.price_bps
+                        || breakpoint.price_bps > MAX_CURVE_PRICE_MULTIPLE_BPS
+                    {
+                        return false;
+                    }
+                    previous = *breakpoint;
+                }
+                previous.supply_bps as u64 == BPS_DENOMINATOR
+                    && unused.iter().all(|breakpoint| *breakpoint == CurveBreakpoint::default())
+            }
+        }
+    }
+
+    /// Average price over the whole curve supply as a multiple of the opening price
+    /// (basis points); None for constant product
+    pub fn average_price_bps(&self) -> Option<u64> {
+        let (points, len) = self.price_multiples()?;
+        let weighted = points[..len]
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0) * (pair[0].1 + pair[1].1))
+            .sum::<u64>();
+        Some(weighted / (2 * BPS_DENOMINATOR))
+    }
+
+    /// (supply sold, price multiple) points in basis points, starting at (0, 1x);
+    /// None for constant product, which has no price schedule
+    fn price_multiples(&self) -> Option<([(u64, u64); MAX_PRICE_POINTS], usize)> {
+        let mut points = [(0, BPS_DENOMINATOR); MAX_PRICE_POINTS];
+        let len = match self {
+            Self::ConstantProduct => return None,
+            Self::Linear { end_price_bps } => {
+                points[1] = (BPS_DENOMINATOR, *end_price_bps as u64);
+                2
+            }
+            Self::Exponential { growth_bps } => {
+                for step in 1..=EXPONENTIAL_CURVE_STEPS {
+                    let multiple = points[step - 1].1
+                        .checked_mul(BPS_DENOMINATOR + *growth_bps as u64)?
+                        / BPS_DENOMINATOR;
+                    points[step] = (BPS_DENOMINATOR * step as u64 / EXPONENTIAL_CURVE_STEPS as u64, multiple);
+                }
+                EXPONENTIAL_CURVE_STEPS + 1
+            }
+            Self::PiecewiseLinear { breakpoints, count } => {
+                let count = (*count as usize).min(MAX_CURVE_BREAKPOINTS);
+                for (point, breakpoint) in points[1..].iter_mut().zip(&breakpoints[..count]) {
+                    *point = (breakpoint.supply_bps as u64, breakpoint.price_bps as u64);
+                }
+                count + 1
+            }
+        };
+        Some((points, len))
+    }
+}
+
+/// Pricing behind one curve shape. `BondingCurve` dispatches to the implementation for
+/// its `kind` and applies the reserve caps and overflow guards common to all of them.
+pub trait CurvePricing {
+    /// Tokens out for `sol_in` lamports
+    fn buy_tokens(&self, curve: &BondingCurve, sol_in: u64) -> Option<u64>;
+    /// Lamports out for `tokens_in` tokens
+    fn sell_sol(&self, curve: &BondingCurve, tokens_in: u64) -> Option<u64>;
+    /// Current price in lamports per whole token
+    fn price(&self, curve: &BondingCurve) -> Option<u64>;
+    /// Whether the curve is done trading and ready to migrate
+    fn is_complete(&self, curve: &BondingCurve) -> bool {
+        curve.real_sol_reserves >= curve.completion_threshold
+    }
+}
+
+/// x * y = k over the virtual reserves
+pub struct ConstantProduct;
+
+impl CurvePricing for ConstantProduct {
+    fn buy_tokens(&self, curve: &BondingCurve, sol_in: u64) -> Option<u64> {
+        // tokens_out = (sol_in * virtual_token_reserves) / (virtual_sol_reserves + sol_in)
+        let numerator = (sol_in as u128).checked_mul(curve.virtual_token_reserves as u128)?;
+        let denominator = (curve.virtual_sol_reserves as u128).checked_add(sol_in as u128)?;
+        let tokens_out = numerator.checked_div(denominator)?;
+        u64::try_from(tokens_out).ok()
+    }
+
+    fn sell_sol(&self, curve: &BondingCurve, tokens_in: u64) -> Option<u64> {
+        // sol_out = (tokens_in * virtual_sol_reserves) / (virtual_token_reserves + tokens_in)
+        let numerator = (tokens_in as u128).checked_mul(curve.virtual_sol_reserves as u128)?;
+        let denominator = (curve.virtual_token_reserves as u128).checked_add(tokens_in as u128)?;
+        let sol_out = numerator.checked_div(denominator)?;
+        u64::try_from(sol_out).ok()
+    }
+
+    fn price(&self, curve: &BondingCurve) -> Option<u64> {
+        // price = virtual_sol_reserves / virtual_token_reserves (in lamports per token)
+        let price = (curve.virtual_sol_reserves as u128)
+            .checked_mul(1_000_000)? // 6 decimal precision
+            .checked_div(curve.virtual_token_reserves as u128)?;
+        Some(price as u64)
+    }
+}
+
+/// Price as a function of the curve supply sold, linear between points. Linear,
+/// exponential and piecewise-linear curves all price through one of these.
+///
+/// Tokens sold back into the curve past its launch supply (genesis tokens) trade at the
+/// opening price. Submission checks the schedule can't sell out before the curve completes.
+/// Buys round in the curve's favour and sells against the seller, so a round trip never
+/// pays out more than it put in.
+pub struct PriceSchedule {
+    supply: u64,
+    points: [PricePoint; MAX_PRICE_POINTS],
+    len: usize,
+}
+
+#[derive(Clone, Copy, Default)]
+struct PricePoint {
+    /// Curve supply sold
+    position: u64,
+    /// Lamports per token base unit, scaled by `CURVE_PRICE_SCALE`
+    price: u128,
+}
+
+/// Stretch of the schedule between two points
+struct Segment {
+    start: PricePoint,
+    end: PricePoint,
+}
+
+impl PriceSchedule {
+    /// Schedule for `kind` over `supply` curve tokens; None for constant product
+    pub fn new(kind: &CurveKind, supply: u64, opening_price: u64) -> Option<Self> {
+        let (multiples, len) = kind.price_multiples()?;
+
+        let mut points = [PricePoint::default(); MAX_PRICE_POINTS];
+        for (point, &(supply_bps, price_bps)) in points.iter_mut().zip(&multiples[..len]) {
+            *point = PricePoint {
+                position: (supply as u128 * supply_bps as u128 / BPS_DENOMINATOR as u128) as u64,
+                price: opening_price as u128 * price_bps as u128 / BPS_DENOMINATOR as u128,
+            };
+        }
+
+        Some(Self { supply, points, len })
+    }
+
+    fn opening_price(&self) -> u128 {
+        self.points[0].price
+    }
+
+    /// Curve supply sold, given the tokens left in the curve
+    fn position(&self, curve: &BondingCurve) -> u64 {
+        self.supply.saturating_sub(curve.real_token_reserves)
+    }
+
+    /// Segments in order, skipping any that rounding left empty
+    fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.points[..self.len]
+            .windows(2)
+            .filter(|pair| pair[1].position > pair[0].position)
+            .map(|pair| Segment { start: pair[0], end: pair[1] })
+    }
+}
+
+impl CurvePricing for PriceSchedule {
+    fn buy_tokens(&self, curve: &BondingCurve, sol_in: u64) -> Option<u64> {
+        let mut budget = sol_in as u128;
+        let mut tokens_out: u128 = 0;
+
+        // Tokens sold back below the launch supply go first, at the opening price
+        let below_schedule = curve.real_token_reserves.saturating_sub(self.supply) as u128;
+        if below_schedule > 0 {
+            let cost = div_round(below_schedule.checked_mul(self.opening_price())?, CURVE_PRICE_SCALE, true)?;
+            if budget < cost {
+                let tokens = budget.checked_mul(CURVE_PRICE_SCALE)?.checked_div(self.opening_price())?;
+                return u64::try_from(tokens).ok();
+            }
+            budget -= cost;
+            tokens_out = below_schedule;
+        }
+
+        // Then up the schedule, taking whole segments while the budget lasts
+        let position = self.position(curve);
+        for segment in self.segments() {
+            if segment.end.position <= position {
+                continue;
+            }
+            let from = segment.start.position.max(position);
+            let cost = segment.cost(from, segment.end.position, true)?;
+            if budget < cost {
+                tokens_out = tokens_out.checked_add(segment.tokens_for(from, budget)? as u128)?;
+                break;
+            }
+            budget -= cost;
+            tokens_out = tokens_out.checked_add((segment.end.position - from) as u128)?;
+        }
+
+        u64::try_from(tokens_out).ok()
+    }
+
+    fn sell_sol(&self, curve: &BondingCurve, tokens_in: u64) -> Option<u64> {
+        let position = self.position(curve);
+        let on_schedule = tokens_in.min(position);
+        let below_schedule = (tokens_in - on_schedule) as u128;
+
+        let mut sol_out = div_round(below_schedule.checked_mul(self.opening_price())?, CURVE_PRICE_SCALE, false)?;
+
+        // Walk back down the schedule from the current position
+        let from = position - on_schedule;
+        for segment in self.segments() {
+            if segment.end.position <= from || segment.start.position >= position {
+                continue;
+            }
+            let cost = segment.cost(from.max(segment.start.position), position.min(segment.end.position), false)?;
+            sol_out = sol_out.checked_add(cost)?;
+        }
+
+        u64::try_from(sol_out).ok()
+    }
+
+    fn price(&self, curve: &BondingCurve) -> Option<u64> {
+        let position = self.position(curve);
+        let price = match self.segments().find(|segment| position < segment.end.position) {
+            Some(segment) => segment.price_at(position.max(segment.start.position), false)?,
+            None => self.points[self.len - 1].price,
+        };
+        // Scaled lamports per base unit to lamports per whole token, as constant product reports it
+        u64::try_from(price / (CURVE_PRICE_SCALE / 1_000_000)).ok()
+    }
+}
+
+impl Segment {
+    fn width(&self) -> u128 {
+        (self.end.position - self.start.position) as u128
+    }
+
+    fn rise(&self) -> Option<u128> {
+        self.end.price.checked_sub(self.start.price)
+    }
+
+    /// Scaled price at `position` within the segment
+    fn price_at(&self, position: u64, round_up: bool) -> Option<u128> {
+        let offset = (position - self.start.position) as u128;
+        let increase = div_round(self.rise()?.checked_mul(offset)?, self.width(), round_up)?;
+        self.start.price.checked_add(increase)
+    }
+
+    /// Lamports for the tokens between `from` and `to` within the segment
+    fn cost(&self, from: u64, to: u64, round_up: bool) -> Option<u128> {
+        // Price is linear here, so the cost is the amount times the average of the end prices
+        let price_sum = self.price_at(from, round_up)?.checked_add(self.price_at(to, round_up)?)?;
+        let scaled_cost = ((to - from) as u128).checked_mul(price_sum)?;
+        div_round(scaled_cost, 2 * CURVE_PRICE_SCALE, round_up)
+    }
+
+    /// Most tokens `budget` lamports buy from `from`, short of the segment end
+    fn tokens_for(&self, from: u64, budget: u128) -> Option<u64> {
+        // Solve (rise / width) * t^2 / 2 + price * t = budget * scale for t, as
+        // t = 2 * budget * scale / (price + sqrt(price^2 + 2 * (rise / width) * budget * scale)),
+        // rounding every term so t comes out no larger than the exact solution
+        let price = self.price_at(from, true)?;
+        let scaled_budget = budget.checked_mul(CURVE_PRICE_SCALE)?;
+        // 2 * rise * budget * scale / width, dividing before scaling so large buys don't overflow
+        let slope_numerator = budget.checked_mul(2)?.checked_mul(self.rise()?)?;
+        let slope_term = (slope_numerator / self.width())
+            .checked_mul(CURVE_PRICE_SCALE)?
+            .checked_add(div_round((slope_numerator % self.width()) * CURVE_PRICE_SCALE, self.width(), true)?)?;
+        let root = ceil_sqrt(price.checked_mul(price)?.checked_add(slope_term)?);
+        let tokens = scaled_budget.checked_mul(2)?.checked_div(price.checked_add(root)?)?;
+
+        Some(tokens.min((self.end.position - from) as u128) as u64)
+    }
+}
+
+fn div_round(numerator: u128, denominator: u128, round_up: bool) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    Some(if round_up { numerator.div_ceil(denominator) } else { numerator / denominator })
+}
+
+fn ceil_sqrt(value: u128) -> u128 {
+    let root = value.isqrt();
+    if root * root < value { root + 1 } else { root }
+}
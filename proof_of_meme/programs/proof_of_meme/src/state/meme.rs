@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{token, token_2022};
 use crate::constants::*;
+use crate::state::CurveParams;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum MemeStatus {
     /// In proving grounds, accepting backers
+    #[default]
     Proving,
     /// Goal met, token launched
     Launched,
@@ -11,11 +14,36 @@ pub enum MemeStatus {
     Failed,
     /// Migrated to Raydium
     Migrated,
+    /// Withdrawn by the creator during proving, refunds available
+    Cancelled,
 }
 
-impl Default for MemeStatus {
-    fn default() -> Self {
-        MemeStatus::Proving
+/// Token program the meme's mint is created under at launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TokenProgram {
+    /// Legacy SPL Token, with Metaplex metadata
+    #[default]
+    Spl,
+    /// Token-2022, with metadata in the mint's own metadata extension
+    Token2022,
+}
+
+/// Where a meme's liquidity goes once its curve completes, fixed at submission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MigrationTarget {
+    /// Raydium CPMM pool
+    #[default]
+    Raydium,
+    /// The program's native constant-product pool
+    NativePool,
+}
+
+impl TokenProgram {
+    pub fn id(&self) -> Pubkey {
+        match self {
+            TokenProgram::Spl => token::ID,
+            TokenProgram::Token2022 => token_2022::ID,
+        }
     }
 }
 
@@ -61,6 +89,28 @@ pub struct Meme {
     pub bump: u8,
     /// Vault bump seed
     pub vault_bump: u8,
+    /// Emergency freeze set by the platform authority
+    pub frozen: bool,
+    /// Escrowed in the vault for whoever finalizes or fails the meme
+    pub crank_reward: u64,
+    /// Launch as soon as the goal is reached instead of waiting for proving_ends_at
+    pub launch_on_goal: bool,
+    /// Earliest launch for launch_on_goal memes (submission + platform launch window)
+    pub early_launch_at: i64,
+    /// Most SOL the launch accepts (0 = uncapped); backing beyond it is refunded pro rata
+    pub sol_cap: u64,
+    /// Backing above the cap at launch, left in the vault for refunds
+    pub sol_oversubscribed: u64,
+    /// Times the creator changed uri or description after submission
+    pub metadata_updates: u16,
+    /// Timestamp of the last metadata change (0 = never changed)
+    pub metadata_updated_at: i64,
+    /// Set once backing reaches METADATA_LOCK_BPS of the goal; stays set if backing later drops
+    pub metadata_locked: bool,
+    /// Token program for the mint and every token account of this meme
+    pub token_program: TokenProgram,
+    /// Bonding curve parameters applied at launch, with defaults filled in
+    pub curve_params: CurveParams,
 }
 
 impl Meme {
@@ -83,7 +133,17 @@ impl Meme {
         8 + // index
         1 + // bump
         1 + // vault_bump
-        64; // padding
+        1 + // frozen
+        8 + // crank_reward
+        1 + // launch_on_goal
+        8 + // early_launch_at
+        8 + // sol_cap
+        8 + // sol_oversubscribed
+        2 + // metadata_updates
+        8 + // metadata_updated_at
+        1 + // metadata_locked
+        1 + // token_program
+        CurveParams::SIZE; // curve_params
 
     pub fn get_name(&self) -> String {
         String::from_utf8_lossy(&self.name[..self.name_length as usize]).to_string()
@@ -97,6 +157,10 @@ impl Meme {
         String::from_utf8_lossy(&self.uri[..self.uri_length as usize]).to_string()
     }
 
+    pub fn get_description(&self) -> String {
+        String::from_utf8_lossy(&self.description[..self.description_length as usize]).to_string()
+    }
+
     pub fn set_name(&mut self, name: &str) {
         let bytes = name.as_bytes();
         let len = bytes.len().min(MAX_NAME_LENGTH);
@@ -112,6 +176,8 @@ impl Meme {
     }
 
     pub fn set_uri(&mut self, uri: &str) {
+        // Clear the old value so a shorter one leaves no trailing bytes
+        self.uri = [0; MAX_URI_LENGTH];
         let bytes = uri.as_bytes();
         let len = bytes.len().min(MAX_URI_LENGTH);
         self.uri[..len].copy_from_slice(&bytes[..len]);
@@ -119,6 +185,7 @@ impl Meme {
     }
 
     pub fn set_description(&mut self, description: &str) {
+        self.description = [0; MAX_DESCRIPTION_LENGTH];
         let bytes = description.as_bytes();
         let len = bytes.len().min(MAX_DESCRIPTION_LENGTH);
         self.description[..len].copy_from_slice(&bytes[..len]);
@@ -133,7 +200,42 @@ impl Meme {
         self.status == MemeStatus::Launched
     }
 
+    /// Failed and cancelled memes return backing through withdraw_backing
+    pub fn is_refundable(&self) -> bool {
+        matches!(self.status, MemeStatus::Failed | MemeStatus::Cancelled)
+    }
+
     pub fn goal_reached(&self) -> bool {
         self.sol_backed >= self.sol_goal && self.backer_count >= self.min_backers
     }
+
+    /// Backing at which the creator can no longer edit the pitch
+    pub fn metadata_lock_threshold(&self) -> Option<u64> {
+        self.sol_goal
+            .checked_mul(METADATA_LOCK_BPS)?
+            .checked_div(BPS_DENOMINATOR)
+    }
+
+    /// Backing that went into the curve at launch
+    pub fn sol_accepted(&self) -> u64 {
+        self.sol_backed.saturating_sub(self.sol_oversubscribed)
+    }
+
+    /// Refund owed to a backer of `amount` when the launch was oversubscribed.
+    /// Rounds down, so the refunds never exceed what was left in the vault.
+    pub fn oversubscription_refund(&self, amount: u64) -> Option<u64> {
+        if self.sol_backed == 0 {
+            return Some(0);
+        }
+        let refund = (amount as u128)
+            .checked_mul(self.sol_oversubscribed as u128)?
+            .checked_div(self.sol_backed as u128)?;
+        u64::try_from(refund).ok()
+    }
+
+    /// Proving is over at `proving_ends_at`, or at `early_launch_at` for launch_on_goal memes
+    /// whose goal is already reached
+    pub fn can_finalize(&self, now: i64) -> bool {
+        now >= self.proving_ends_at || (self.launch_on_goal && self.goal_reached() && now >= self.early_launch_at)
+    }
 }
@@ -2,8 +2,12 @@ pub mod platform;
 pub mod meme;
 pub mod backing;
 pub mod curve;
+pub mod curve_kind;
+pub mod pool;
 
 pub use platform::*;
 pub use meme::*;
 pub use backing::*;
 pub use curve::*;
+pub use curve_kind::*;
+pub use pool::*;
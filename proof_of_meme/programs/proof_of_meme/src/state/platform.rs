@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::state::CurveBounds;
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_CONFIG_TIMELOCK, MAX_CRANK_REWARD, MAX_VESTING_DURATION, MIN_CRANK_REWARD, MIN_LAUNCH_WINDOW,
+    MIN_PROVING_DURATION, MAX_UNBACK_PENALTY_BPS, PAUSE_ALL,
+};
 
 #[account]
 #[derive(Default)]
@@ -21,6 +26,46 @@ pub struct PlatformConfig {
     pub total_platform_fees: u64,
     /// Bump seed
     pub bump: u8,
+    /// Default cliff before genesis tokens start unlocking (seconds after launch)
+    pub vesting_cliff_seconds: i64,
+    /// Default duration over which genesis tokens unlock linearly (seconds after launch)
+    pub vesting_duration_seconds: i64,
+    /// Notice period before fee changes take effect (0 = immediate)
+    pub config_timelock_seconds: i64,
+    /// Pending submission fee
+    pub pending_submission_fee: u64,
+    /// Pending platform share of trading fees (basis points)
+    pub pending_platform_fee_bps: u16,
+    /// Pending genesis share of trading fees (basis points)
+    pub pending_genesis_fee_bps: u16,
+    /// Pending burn share of trading fees (basis points)
+    pub pending_burn_fee_bps: u16,
+    /// Pending notice period
+    pub pending_config_timelock_seconds: i64,
+    /// When the pending config can be applied (0 = nothing pending)
+    pub pending_config_activates_at: i64,
+    /// Proposed new authority, must accept to take over (default = none)
+    pub pending_authority: Pubkey,
+    /// Receives platform trading, submission and migration fees
+    pub fee_recipient: Pubkey,
+    /// Emergency pause bitmask (see PAUSE_* constants)
+    pub paused: u8,
+    /// Paid to whoever finalizes, fails or migrates a meme (0 = disabled)
+    pub crank_reward: u64,
+    /// Pending crank reward
+    pub pending_crank_reward: u64,
+    /// Minimum proving time before a launch_on_goal meme can launch early
+    pub launch_window_seconds: i64,
+    /// Pending launch window
+    pub pending_launch_window_seconds: i64,
+    /// Share of unbacked SOL kept as a platform fee (basis points)
+    pub unback_penalty_bps: u16,
+    /// Pending unback penalty
+    pub pending_unback_penalty_bps: u16,
+    /// Curve parameters creators may pick at submission
+    pub curve_bounds: CurveBounds,
+    /// Pending curve parameter bounds
+    pub pending_curve_bounds: CurveBounds,
 }
 
 impl PlatformConfig {
@@ -34,5 +79,100 @@ impl PlatformConfig {
         8 + // total_memes_launched
         8 + // total_platform_fees
         1 + // bump
+        8 + // vesting_cliff_seconds
+        8 + // vesting_duration_seconds
+        8 + // config_timelock_seconds
+        8 + // pending_submission_fee
+        2 + // pending_platform_fee_bps
+        2 + // pending_genesis_fee_bps
+        2 + // pending_burn_fee_bps
+        8 + // pending_config_timelock_seconds
+        8 + // pending_config_activates_at
+        32 + // pending_authority
+        32 + // fee_recipient
+        1 + // paused
+        8 + // crank_reward
+        8 + // pending_crank_reward
+        8 + // launch_window_seconds
+        8 + // pending_launch_window_seconds
+        2 + // unback_penalty_bps
+        2 + // pending_unback_penalty_bps
+        CurveBounds::SIZE + // curve_bounds
+        CurveBounds::SIZE + // pending_curve_bounds
         64; // padding for future use
+
+    /// Size of the original layout (authority through bump, then 64 bytes of padding),
+    /// which migrate_platform_config grows to `SIZE`
+    pub const LEGACY_SIZE: usize = 8 + 32 + 8 + 2 + 2 + 2 + 8 + 8 + 8 + 1 + 64;
+
+    /// Fee shares must sum to 10000 bps = 100%
+    pub fn is_valid_fee_split(platform_fee_bps: u16, genesis_fee_bps: u16, burn_fee_bps: u16) -> bool {
+        let total_bps = platform_fee_bps as u64 + genesis_fee_bps as u64 + burn_fee_bps as u64;
+        total_bps == BPS_DENOMINATOR
+    }
+
+    /// A config timelock is valid if it is between zero and the maximum notice period
+    pub fn is_valid_config_timelock(timelock_seconds: i64) -> bool {
+        (0..=MAX_CONFIG_TIMELOCK).contains(&timelock_seconds)
+    }
+
+    /// A crank reward is valid if it is zero, or within bounds and covered by the
+    /// submission fee it is escrowed from
+    pub fn is_valid_crank_reward(crank_reward: u64, submission_fee: u64) -> bool {
+        crank_reward == 0
+            || ((MIN_CRANK_REWARD..=MAX_CRANK_REWARD).contains(&crank_reward) && crank_reward <= submission_fee)
+    }
+
+    /// A launch window is valid if it is long enough to stop a first-block launch and
+    /// no longer than the shortest proving period
+    pub fn is_valid_launch_window(window_seconds: i64) -> bool {
+        (MIN_LAUNCH_WINDOW..=MIN_PROVING_DURATION).contains(&window_seconds)
+    }
+
+    /// An unback penalty is valid if it is between zero and the maximum exit penalty
+    pub fn is_valid_unback_penalty(penalty_bps: u16) -> bool {
+        penalty_bps <= MAX_UNBACK_PENALTY_BPS
+    }
+
+    /// Platform fee kept from `amount` of unbacked SOL
+    pub fn unback_penalty(&self, amount: u64) -> Option<u64> {
+        amount
+            .checked_mul(self.unback_penalty_bps as u64)?
+            .checked_div(BPS_DENOMINATOR)
+    }
+
+    /// A pause mask is valid if it only sets known PAUSE_* bits
+    pub fn is_valid_pause_mask(paused: u8) -> bool {
+        paused & !PAUSE_ALL == 0
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
+    pub fn has_pending_config(&self) -> bool {
+        self.pending_config_activates_at != 0
+    }
+
+    /// Split a trading fee into (genesis, platform, burn) shares; burn takes the rounding remainder
+    pub fn split_trading_fee(&self, trading_fee: u64) -> Option<(u64, u64, u64)> {
+        let genesis_fee = trading_fee
+            .checked_mul(self.genesis_fee_bps as u64)?
+            .checked_div(BPS_DENOMINATOR)?;
+        let platform_fee = trading_fee
+            .checked_mul(self.platform_fee_bps as u64)?
+            .checked_div(BPS_DENOMINATOR)?;
+        let burn_fee = trading_fee
+            .checked_sub(genesis_fee)?
+            .checked_sub(platform_fee)?;
+        Some((genesis_fee, platform_fee, burn_fee))
+    }
+
+    /// A vesting schedule is valid if both values are within bounds and the cliff
+    /// falls inside the unlock duration (a zero duration unlocks everything at the cliff)
+    pub fn is_valid_vesting_schedule(cliff_seconds: i64, duration_seconds: i64) -> bool {
+        (0..=MAX_VESTING_DURATION).contains(&cliff_seconds)
+            && (0..=MAX_VESTING_DURATION).contains(&duration_seconds)
+            && (duration_seconds == 0 || cliff_seconds <= duration_seconds)
+    }
 }
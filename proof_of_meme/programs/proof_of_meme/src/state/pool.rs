@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SwapDirection {
+    /// Buy tokens with SOL
+    SolToToken,
+    /// Sell tokens for SOL
+    TokenToSol,
+}
+
+#[account]
+#[derive(Default)]
+pub struct Pool {
+    /// The meme this pool belongs to
+    pub meme: Pubkey,
+    /// Token mint
+    pub mint: Pubkey,
+    /// LP token mint
+    pub lp_mint: Pubkey,
+    /// SOL reserves available for swaps (excludes accumulated burn fees)
+    pub sol_reserves: u64,
+    /// Token reserves available for swaps
+    pub token_reserves: u64,
+    /// Total LP outstanding, including locked liquidity
+    pub lp_supply: u64,
+    /// LP seeded from the bonding curve at migration (never withdrawable)
+    pub locked_lp: u64,
+    /// Total trading volume (SOL)
+    pub total_volume: u64,
+    /// Accumulated fees for genesis backers
+    pub genesis_fees_accumulated: u64,
+    /// Accumulated fees for platform
+    pub platform_fees_accumulated: u64,
+    /// Accumulated fees for burn
+    pub burn_fees_accumulated: u64,
+    /// Timestamp the pool was opened
+    pub created_at: i64,
+    /// Bump seed
+    pub bump: u8,
+    /// Vault bump
+    pub vault_bump: u8,
+    /// Burn fees already spent on buyback
+    pub burn_fees_spent: u64,
+    /// Tokens bought back with burn fees and burned
+    pub tokens_burned: u64,
+}
+
+impl Pool {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // meme
+        32 + // mint
+        32 + // lp_mint
+        8 + // sol_reserves
+        8 + // token_reserves
+        8 + // lp_supply
+        8 + // locked_lp
+        8 + // total_volume
+        8 + // genesis_fees_accumulated
+        8 + // platform_fees_accumulated
+        8 + // burn_fees_accumulated
+        8 + // created_at
+        1 + // bump
+        1 + // vault_bump
+        8 + // burn_fees_spent
+        8 + // tokens_burned
+        48; // padding
+
+    /// Initial LP for seeded liquidity: sqrt(sol * tokens)
+    pub fn calculate_initial_lp(sol_amount: u64, token_amount: u64) -> Option<u64> {
+        let product = (sol_amount as u128).checked_mul(token_amount as u128)?;
+        u64::try_from(isqrt(product)).ok()
+    }
+
+    /// Constant product output for a given input: out = (in * reserve_out) / (reserve_in + in)
+    pub fn calculate_swap_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Option<u64> {
+        let numerator = (amount_in as u128).checked_mul(reserve_out as u128)?;
+        let denominator = (reserve_in as u128).checked_add(amount_in as u128)?;
+        let amount_out = numerator.checked_div(denominator)?;
+        u64::try_from(amount_out).ok()
+    }
+
+    /// Calculate tokens out for a given SOL input
+    pub fn calculate_buy_tokens(&self, sol_in: u64) -> Option<u64> {
+        Self::calculate_swap_out(sol_in, self.sol_reserves, self.token_reserves)
+    }
+
+    /// Calculate SOL out for a given token input
+    pub fn calculate_sell_sol(&self, tokens_in: u64) -> Option<u64> {
+        Self::calculate_swap_out(tokens_in, self.token_reserves, self.sol_reserves)
+    }
+
+    /// Current token price in lamports per whole token, as the bonding curve reports it
+    pub fn get_current_price(&self) -> Option<u64> {
+        let price = (self.sol_reserves as u128)
+            .checked_mul(1_000_000)? // 6 decimal precision
+            .checked_div(self.token_reserves as u128)?;
+        u64::try_from(price).ok()
+    }
+
+    /// Burn fees collected but not yet spent on buyback
+    pub fn pending_burn_fees(&self) -> u64 {
+        self.burn_fees_accumulated.saturating_sub(self.burn_fees_spent)
+    }
+
+    /// Tokens required (rounded up) and LP minted for a SOL deposit at the current ratio
+    pub fn calculate_deposit(&self, sol_amount: u64) -> Option<(u64, u64)> {
+        if self.sol_reserves == 0 {
+            return None;
+        }
+
+        let sol_reserves = self.sol_reserves as u128;
+        let tokens_required = (sol_amount as u128)
+            .checked_mul(self.token_reserves as u128)?
+            .div_ceil(sol_reserves);
+        let lp_out = (sol_amount as u128)
+            .checked_mul(self.lp_supply as u128)?
+            .checked_div(sol_reserves)?;
+
+        Some((u64::try_from(tokens_required).ok()?, u64::try_from(lp_out).ok()?))
+    }
+
+    /// SOL and tokens returned for burning LP
+    pub fn calculate_withdraw(&self, lp_amount: u64) -> Option<(u64, u64)> {
+        if self.lp_supply == 0 {
+            return None;
+        }
+
+        let sol_out = (lp_amount as u128)
+            .checked_mul(self.sol_reserves as u128)?
+            .checked_div(self.lp_supply as u128)?;
+        let tokens_out = (lp_amount as u128)
+            .checked_mul(self.token_reserves as u128)?
+            .checked_div(self.lp_supply as u128)?;
+
+        Some((u64::try_from(sol_out).ok()?, u64::try_from(tokens_out).ok()?))
+    }
+}
+
+/// Integer square root (floor) via Newton's method
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,94 @@
+//! Buyback and burn: burn fees from curve trades buy tokens off the curve and burn them
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::CurveStatus;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+#[tokio::test]
+async fn burn_fees_buy_back_and_burn_curve_tokens() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = env.launch_meme(0).await;
+    let mint = mint_pda(&meme);
+    let curve_vault = curve_vault_pda(&meme);
+    let curve_token_account = get_associated_token_address(&curve_pda(&meme), &mint);
+    let cranker = env.cranker.insecure_clone();
+
+    // Nothing to spend before anyone trades
+    let result = env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoBurnFees);
+
+    // --- trades accrue burn fees ---------------------------------------------
+    let trader = env.trader.insecure_clone();
+    env.create_ata(&trader.pubkey(), &mint).await;
+    let buy_amount = 10 * LAMPORTS_PER_SOL;
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, 0)], &[&trader])
+        .await
+        .unwrap();
+
+    let (_, _, burn_fee) = split_fee(trading_fee(buy_amount));
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.burn_fees_accumulated, burn_fee);
+    assert_eq!(curve.pending_burn_fees(), burn_fee);
+
+    // --- execute_buyback_burn ------------------------------------------------
+    let tokens_burned = expected_buy_tokens(&curve, burn_fee);
+    assert!(tokens_burned > 0);
+    let curve_tokens_before = env.token_balance(&curve_token_account).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+    assert_eq!(env.mint_supply(&mint).await, TOTAL_SUPPLY);
+
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    // Tokens leave the curve's own account and the supply, while the fee lamports stay put
+    assert_eq!(env.token_balance(&curve_token_account).await, curve_tokens_before - tokens_burned);
+    assert_eq!(env.mint_supply(&mint).await, TOTAL_SUPPLY - tokens_burned);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before);
+
+    let curve_after = env.curve(&meme).await;
+    assert_eq!(curve_after.burn_fees_spent, burn_fee);
+    assert_eq!(curve_after.tokens_burned, tokens_burned);
+    assert_eq!(curve_after.pending_burn_fees(), 0);
+    // The fees are now curve reserves, priced like any other buy
+    assert_eq!(curve_after.real_sol_reserves, curve.real_sol_reserves + burn_fee);
+    assert_eq!(curve_after.real_token_reserves, curve.real_token_reserves - tokens_burned);
+    assert_eq!(curve_after.virtual_sol_reserves, curve.virtual_sol_reserves + burn_fee);
+    assert_eq!(curve_after.virtual_token_reserves, curve.virtual_token_reserves - tokens_burned);
+    assert_eq!(curve_after.status, CurveStatus::Active);
+
+    // Fees are spent once
+    let result = env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoBurnFees);
+
+    // --- later fees add to the running totals --------------------------------
+    let sell_amount = env.token_balance(&get_associated_token_address(&trader.pubkey(), &mint)).await / 2;
+    let sol_out_gross = expected_sell_sol(&curve_after, sell_amount);
+    let (_, _, sell_burn_fee) = split_fee(trading_fee(sol_out_gross));
+    env.send(&[ix::sell_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, sell_amount, 0)], &[&trader])
+        .await
+        .unwrap();
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.pending_burn_fees(), sell_burn_fee);
+    let more_burned = expected_buy_tokens(&curve, sell_burn_fee);
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.burn_fees_spent, burn_fee + sell_burn_fee);
+    assert_eq!(curve.tokens_burned, tokens_burned + more_burned);
+    assert_eq!(env.mint_supply(&mint).await, TOTAL_SUPPLY - tokens_burned - more_burned);
+}
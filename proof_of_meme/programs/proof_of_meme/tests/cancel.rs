@@ -0,0 +1,88 @@
+//! Creator cancellation: a proving meme is withdrawn by its creator and backers
+//! refund through withdraw_backing
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = 2;
+const BACKING_AMOUNT: u64 = LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn creator_cancels_proving_meme() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let vault = vault_pda(&meme);
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    }
+    let total_backed = BACKING_AMOUNT * BACKER_COUNT as u64;
+
+    // Only the creator can cancel
+    let backer = env.backers[0].insecure_clone();
+    let result = env.send(&[ix::cancel_meme(&backer.pubkey(), &meme)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::NotMemeCreator);
+
+    let creator_before = env.lamports(&creator.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    env.send(&[ix::cancel_meme(&creator.pubkey(), &meme)], &[&creator]).await.unwrap();
+
+    // The escrowed crank reward comes back; the rest of the submission fee stays with the platform
+    assert_eq!(env.lamports(&creator.pubkey()).await, creator_before + CRANK_REWARD);
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before);
+    assert_eq!(env.lamports(&vault).await, total_backed);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Cancelled);
+    assert_eq!(meme_state.crank_reward, 0);
+
+    // Proving is over for good
+    let result = env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::AlreadyLaunched);
+    let result = env.send(&[ix::cancel_meme(&creator.pubkey(), &meme)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::AlreadyLaunched);
+
+    // Backers refund through the failed-meme path, in full
+    let mut vault_balance = total_backed;
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        let before = env.lamports(&backer.pubkey()).await;
+        env.send(&[ix::withdraw_backing(&backer.pubkey(), &meme)], &[&backer]).await.unwrap();
+        vault_balance -= BACKING_AMOUNT;
+
+        assert_eq!(env.lamports(&backer.pubkey()).await, before + BACKING_AMOUNT);
+        assert_eq!(env.lamports(&vault).await, vault_balance);
+    }
+}
+
+#[tokio::test]
+async fn cancel_closes_with_proving() {
+    let mut env = TestEnv::new(0).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+
+    // Once the meme can be finalized or failed, the creator no longer decides its outcome
+    let meme_state: Meme = env.account(&meme).await;
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+    let result = env.send(&[ix::cancel_meme(&creator.pubkey(), &meme)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingEnded);
+}
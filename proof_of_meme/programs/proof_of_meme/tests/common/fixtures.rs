@@ -0,0 +1,52 @@
+//! Mainnet programs and accounts dumped by `scripts/fetch-fixtures.sh` into `tests/fixtures`,
+//! the same files `Anchor.toml` loads into the local validator. None of them are checked in.
+
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use solana_program_test::ProgramTest;
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Load a dumped program binary at `program_id`
+pub fn add_program(program_test: &mut ProgramTest, program_id: Pubkey, file: &str) {
+    // Loaded as a deployed BPF program; `add_program` only runs shared objects under test-sbf
+    let elf = read(&path(file));
+    program_test.add_account(program_id, Account {
+        lamports: Rent::default().minimum_balance(elf.len()),
+        data: elf,
+        owner: bpf_loader::ID,
+        executable: true,
+        rent_epoch: 0,
+    });
+}
+
+/// Load an account dumped with `solana account --output json` at `address`
+pub fn add_account(program_test: &mut ProgramTest, address: Pubkey, file: &str) {
+    let path = path(file);
+    let json: serde_json::Value = serde_json::from_slice(&read(&path)).unwrap();
+    let account = &json["account"];
+
+    assert_eq!(account["data"][1], "base64", "{}: expected base64 account data", path.display());
+    program_test.add_account(address, Account {
+        lamports: account["lamports"].as_u64().unwrap(),
+        data: base64::engine::general_purpose::STANDARD
+            .decode(account["data"][0].as_str().unwrap())
+            .unwrap(),
+        owner: account["owner"].as_str().unwrap().parse().unwrap(),
+        executable: account["executable"].as_bool().unwrap(),
+        rent_epoch: account["rentEpoch"].as_u64().unwrap(),
+    });
+}
+
+fn path(file: &str) -> PathBuf {
+    Path::new(FIXTURES).join(file)
+}
+
+fn read(path: &Path) -> Vec<u8> {
+    std::fs::read(path).unwrap_or_else(|err| panic!("{}: {err} (run scripts/fetch-fixtures.sh)", path.display()))
+}
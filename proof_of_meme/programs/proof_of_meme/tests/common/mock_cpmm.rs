@@ -0,0 +1,235 @@
+//! Stand-in for Raydium CPMM `initialize`, registered at the real program id.
+//!
+//! Keeps the default suite free of the mainnet fixtures; the real program is loaded
+//! by `TestEnv::with_real_programs` in the ignored `raydium_migration` test. The pool
+//! accounts CPMM would create (LP mint, vaults, creator LP account) are pre-created
+//! by `add_fixture`. The mock then does what `migrate_to_raydium` depends on: charges
+//! the AMM config's pool creation fee, moves the creator's deposit into the vaults
+//! through each side's token program and mints LP tokens to the creator.
+
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use proof_of_meme::raydium::{self, AMM_CONFIG_DISCRIMINATOR, CPMM_INITIALIZE_DISCRIMINATOR, RAYDIUM_CPMM_PROGRAM_ID};
+use proof_of_meme_client::raydium::RaydiumPoolAccounts;
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+
+/// create_pool_fee in the mock AMM config, charged to the creator like CPMM
+pub const CREATE_POOL_FEE: u64 = 150_000_000;
+/// LP kept back from the creator on initialize, like CPMM
+pub const LOCKED_LP: u64 = 100;
+
+/// AmmConfig account size: discriminator, bump, disable_create_pool, index, four u64
+/// fees, protocol and fund owners, then 16 u64 of padding
+const AMM_CONFIG_SIZE: usize = 8 + 1 + 1 + 2 + 8 * 4 + 32 * 2 + 8 * 16;
+
+const AUTH_SEED: &[u8] = b"vault_and_lp_mint_auth_seed";
+
+pub struct CpmmFixture {
+    pub amm_config: Pubkey,
+    pub pool_state: Pubkey,
+    pub observation_state: Pubkey,
+    pub create_pool_fee: Pubkey,
+    pub lp_mint: Pubkey,
+    pub creator_lp_account: Pubkey,
+    pub token_vault: Pubkey,
+    pub wsol_vault: Pubkey,
+}
+
+impl CpmmFixture {
+    pub fn pool_accounts(&self) -> RaydiumPoolAccounts {
+        RaydiumPoolAccounts {
+            amm_config: self.amm_config,
+            authority: authority(),
+            pool_state: self.pool_state,
+            lp_mint: self.lp_mint,
+            vault_lp_account: self.creator_lp_account,
+            pool_token_vault: self.token_vault,
+            pool_wsol_vault: self.wsol_vault,
+            create_pool_fee: self.create_pool_fee,
+            observation_state: self.observation_state,
+        }
+    }
+}
+
+/// CPMM vault and LP mint authority
+pub fn authority() -> Pubkey {
+    Pubkey::find_program_address(&[AUTH_SEED], &RAYDIUM_CPMM_PROGRAM_ID).0
+}
+
+/// LP minted to the creator for an initial deposit
+pub fn expected_lp(amount_0: u64, amount_1: u64) -> u64 {
+    isqrt(amount_0 as u128 * amount_1 as u128) as u64 - LOCKED_LP
+}
+
+/// Register the mock program and the pool accounts for a migration of `mint`, owned by
+/// `mint_token_program`, by `creator`
+pub fn add_fixture(program_test: &mut ProgramTest, mint: Pubkey, mint_token_program: &Pubkey, creator: Pubkey) -> CpmmFixture {
+    program_test.add_program("raydium_cpmm_mock", RAYDIUM_CPMM_PROGRAM_ID, processor!(process_instruction));
+
+    let rent = Rent::default();
+    let amm_config = Pubkey::new_unique();
+    let lp_mint = Pubkey::new_unique();
+    let creator_lp_account = get_associated_token_address(&creator, &lp_mint);
+    let token_vault = Pubkey::new_unique();
+    let wsol_vault = Pubkey::new_unique();
+
+    // migrate_to_raydium and the mock only read the create fee, after the fee rates
+    let mut data = vec![0; AMM_CONFIG_SIZE];
+    data[..8].copy_from_slice(&AMM_CONFIG_DISCRIMINATOR);
+    data[36..44].copy_from_slice(&CREATE_POOL_FEE.to_le_bytes());
+    program_test.add_account(amm_config, Account {
+        lamports: rent.minimum_balance(AMM_CONFIG_SIZE),
+        data,
+        owner: RAYDIUM_CPMM_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    });
+
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(authority()),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    program_test.add_account(lp_mint, token_program_account(data, &spl_token::ID, &rent));
+
+    program_test.add_account(creator_lp_account, token_account(lp_mint, creator, COption::None, &spl_token::ID, &rent));
+    program_test.add_account(token_vault, token_account(mint, authority(), COption::None, mint_token_program, &rent));
+    let native_reserve = rent.minimum_balance(spl_token::state::Account::LEN);
+    program_test.add_account(
+        wsol_vault,
+        token_account(spl_token::native_mint::ID, authority(), COption::Some(native_reserve), &spl_token::ID, &rent),
+    );
+
+    CpmmFixture {
+        amm_config,
+        pool_state: Pubkey::new_unique(),
+        observation_state: Pubkey::new_unique(),
+        create_pool_fee: Pubkey::new_unique(),
+        lp_mint,
+        creator_lp_account,
+        token_vault,
+        wsol_vault,
+    }
+}
+
+fn token_program_account(data: Vec<u8>, owner: &Pubkey, rent: &Rent) -> Account {
+    Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: *owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// A plain token account; the base layout is the same under both token programs
+fn token_account(mint: Pubkey, owner: Pubkey, is_native: COption<u64>, token_program: &Pubkey, rent: &Rent) -> Account {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount: 0,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    token_program_account(data, token_program, rent)
+}
+
+/// Account order matches `raydium::cpmm_initialize`
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (discriminator, args) = data.split_at(8);
+    assert_eq!(discriminator, CPMM_INITIALIZE_DISCRIMINATOR);
+    let amount_0 = u64::from_le_bytes(args[0..8].try_into().unwrap());
+    let amount_1 = u64::from_le_bytes(args[8..16].try_into().unwrap());
+
+    let creator = &accounts[0];
+    let amm_config = &accounts[1];
+    let authority = &accounts[2];
+    let token_0_mint = &accounts[4];
+    let token_1_mint = &accounts[5];
+    let lp_mint = &accounts[6];
+    let creator_token_0 = &accounts[7];
+    let creator_token_1 = &accounts[8];
+    let creator_lp_token = &accounts[9];
+    let token_0_vault = &accounts[10];
+    let token_1_vault = &accounts[11];
+    let create_pool_fee = &accounts[12];
+    let token_program = &accounts[14];
+    let token_0_program = &accounts[15];
+    let token_1_program = &accounts[16];
+    let system_program = &accounts[18];
+
+    let fee = raydium::amm_config_create_pool_fee(&amm_config.data.borrow()).unwrap();
+    invoke(
+        &system_instruction::transfer(creator.key, create_pool_fee.key, fee),
+        &[creator.clone(), create_pool_fee.clone(), system_program.clone()],
+    )?;
+
+    for (mint, from, to, program, amount) in [
+        (token_0_mint, creator_token_0, token_0_vault, token_0_program, amount_0),
+        (token_1_mint, creator_token_1, token_1_vault, token_1_program, amount_1),
+    ] {
+        let decimals = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint.data.borrow())?.base.decimals;
+        invoke(
+            &spl_token_2022::instruction::transfer_checked(
+                program.key,
+                from.key,
+                mint.key,
+                to.key,
+                creator.key,
+                &[],
+                amount,
+                decimals,
+            )?,
+            &[from.clone(), mint.clone(), to.clone(), creator.clone(), program.clone()],
+        )?;
+    }
+
+    let (_, bump) = Pubkey::find_program_address(&[AUTH_SEED], program_id);
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            token_program.key,
+            lp_mint.key,
+            creator_lp_token.key,
+            authority.key,
+            &[],
+            expected_lp(amount_0, amount_1),
+        )?,
+        &[lp_mint.clone(), creator_lp_token.clone(), authority.clone(), token_program.clone()],
+        &[&[AUTH_SEED, &[bump]]],
+    )?;
+
+    Ok(())
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,101 @@
+//! Stand-in for Metaplex Token Metadata `CreateMetadataAccountV3`, registered at the
+//! real program id.
+//!
+//! Keeps the default suite self-contained: the real program is a mainnet dump that
+//! `scripts/fetch-fixtures.sh` fetches and `TestEnv::with_real_programs` loads, which
+//! only the ignored `metaplex_metadata` test uses. The mock checks what the real one
+//! checks for a fungible launch (metadata PDA, mint authority and update authority
+//! signatures), has the payer fund a metadata account of the real size, and writes
+//! the same `Metadata` the real program would, minus the real program's null padding.
+
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::rent::Rent;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::solana_program::sysvar::Sysvar;
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
+use anchor_spl::metadata::mpl_token_metadata::instructions::CreateMetadataAccountV3InstructionArgs;
+use anchor_spl::metadata::mpl_token_metadata::types::{Key, TokenStandard};
+use anchor_spl::metadata::mpl_token_metadata::ID as TOKEN_METADATA_PROGRAM_ID;
+use anchor_spl::token::spl_token;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::pubkey::Pubkey;
+
+/// Size the real program allocates for every metadata account
+pub const METADATA_SIZE: usize = 679;
+
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+pub fn add_program(program_test: &mut ProgramTest) {
+    program_test.add_program("token_metadata_mock", TOKEN_METADATA_PROGRAM_ID, processor!(process_instruction));
+}
+
+/// Decode the metadata account the mock wrote
+pub fn decode(data: &[u8]) -> Metadata {
+    Metadata::from_bytes(data).unwrap()
+}
+
+/// Account order matches `CreateMetadataAccountV3`
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (&discriminator, args) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+    assert_eq!(discriminator, CREATE_METADATA_ACCOUNT_V3);
+    let args = CreateMetadataAccountV3InstructionArgs::deserialize(&mut &args[..])?;
+
+    let metadata = &accounts[0];
+    let mint = &accounts[1];
+    let mint_authority = &accounts[2];
+    let payer = &accounts[3];
+    let update_authority = &accounts[4];
+    let system_program = &accounts[5];
+
+    let (expected, bump) = Metadata::find_pda(mint.key);
+    if *metadata.key != expected || !metadata.data_is_empty() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let mint_state = spl_token::state::Mint::unpack(&mint.data.borrow())?;
+    if mint_state.mint_authority != COption::Some(*mint_authority.key) || !mint_authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !update_authority.is_signer || !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            metadata.key,
+            Rent::get()?.minimum_balance(METADATA_SIZE),
+            METADATA_SIZE as u64,
+            program_id,
+        ),
+        &[payer.clone(), metadata.clone(), system_program.clone()],
+        &[&[Metadata::PREFIX, program_id.as_ref(), mint.key.as_ref(), &[bump]]],
+    )?;
+
+    let state = Metadata {
+        key: Key::MetadataV1,
+        update_authority: *update_authority.key,
+        mint: *mint.key,
+        name: args.data.name,
+        symbol: args.data.symbol,
+        uri: args.data.uri,
+        seller_fee_basis_points: args.data.seller_fee_basis_points,
+        creators: args.data.creators,
+        primary_sale_happened: false,
+        is_mutable: args.is_mutable,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::Fungible),
+        collection: args.data.collection,
+        uses: args.data.uses,
+        collection_details: args.collection_details,
+        programmable_config: None,
+    };
+    state.serialize(&mut &mut metadata.data.borrow_mut()[..])?;
+
+    Ok(())
+}
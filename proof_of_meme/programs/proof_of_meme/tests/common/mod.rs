@@ -0,0 +1,517 @@
+//! In-process test harness: runs the program natively inside `solana-program-test`
+//! so the lifecycle can be exercised without a validator or an SBF build.
+#![allow(dead_code)]
+
+pub mod fixtures;
+pub mod mock_cpmm;
+pub mod mock_token_metadata;
+pub mod raydium_cpmm;
+
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Event};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, spl_associated_token_account};
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{PlatformConfig, BondingCurve, CurveBounds, CurveParams, Meme, TokenProgram};
+use proof_of_meme_client::pda;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use anchor_lang::system_program;
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Token account size (SPL token 165 bytes)
+pub const TOKEN_ACCOUNT_SIZE: usize = spl_token::state::Account::LEN;
+/// Mint account size (SPL token 82 bytes)
+pub const MINT_SIZE: usize = spl_token::state::Mint::LEN;
+
+// Fee schedule used by every test
+pub const SUBMISSION_FEE: u64 = LAMPORTS_PER_SOL / 10;
+pub const PLATFORM_FEE_BPS: u16 = 2_000;
+pub const GENESIS_FEE_BPS: u16 = 7_000;
+pub const BURN_FEE_BPS: u16 = 1_000;
+/// Escrowed from the submission fee and paid to whoever cranks each transition
+pub const CRANK_REWARD: u64 = LAMPORTS_PER_SOL / 100;
+/// Exit penalty kept by the platform when backers unback during proving
+pub const UNBACK_PENALTY_BPS: u16 = 200;
+
+/// Anchor's entrypoint ties the slice and account lifetimes together;
+/// leak the slice so it fits the `processor!` signature.
+fn process_instruction<'a, 'b, 'c, 'd>(
+    program_id: &'a Pubkey,
+    accounts: &'b [AccountInfo<'c>],
+    data: &'d [u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    proof_of_meme::entry(program_id, accounts, data)
+}
+
+pub struct TestEnv {
+    pub ctx: ProgramTestContext,
+    pub rent: Rent,
+    pub authority: Keypair,
+    pub fee_recipient: Keypair,
+    pub creator: Keypair,
+    pub backers: Vec<Keypair>,
+    pub trader: Keypair,
+    pub cranker: Keypair,
+    pub cpmm: mock_cpmm::CpmmFixture,
+    /// Token program the CPMM fixture for meme #0 expects its mint under
+    pub token_program: TokenProgram,
+    nonce: u32,
+}
+
+impl TestEnv {
+    /// Boot a fresh bank with funded actors, the mock token metadata program and the
+    /// mock Raydium CPMM for meme #0
+    pub async fn new(backer_count: usize) -> Self {
+        Self::with_token_program(backer_count, TokenProgram::Spl).await
+    }
+
+    /// Like `new`, with meme #0's CPMM fixture set up for a launch under `token_program`
+    pub async fn with_token_program(backer_count: usize, token_program: TokenProgram) -> Self {
+        Self::build(backer_count, token_program, false).await
+    }
+
+    /// Like `new`, with the real Metaplex Token Metadata and Raydium CPMM programs and
+    /// CPMM's mainnet accounts loaded from `tests/fixtures` in place of the mocks
+    pub async fn with_real_programs(backer_count: usize) -> Self {
+        Self::build(backer_count, TokenProgram::Spl, true).await
+    }
+
+    async fn build(backer_count: usize, token_program: TokenProgram, real_programs: bool) -> Self {
+        let mut program_test = ProgramTest::new(
+            "proof_of_meme",
+            proof_of_meme::ID,
+            processor!(process_instruction),
+        );
+
+        let authority = Keypair::new();
+        let fee_recipient = Keypair::new();
+        let creator = Keypair::new();
+        let backers: Vec<Keypair> = (0..backer_count).map(|_| Keypair::new()).collect();
+        let trader = Keypair::new();
+        let cranker = Keypair::new();
+
+        for (keypair, sol) in [
+            (&authority, 10),
+            (&fee_recipient, 1),
+            (&creator, 10),
+            (&trader, 200),
+            (&cranker, 10),
+        ] {
+            fund(&mut program_test, &keypair.pubkey(), sol * LAMPORTS_PER_SOL);
+        }
+        for backer in &backers {
+            fund(&mut program_test, &backer.pubkey(), 10 * LAMPORTS_PER_SOL);
+        }
+
+        add_native_mint(&mut program_test);
+
+        let meme = meme_pda(0);
+        let cpmm = if real_programs {
+            fixtures::add_program(&mut program_test, anchor_spl::metadata::ID, "mpl_token_metadata.so");
+            raydium_cpmm::add_program(&mut program_test, mint_pda(&meme), curve_vault_pda(&meme))
+        } else {
+            mock_token_metadata::add_program(&mut program_test);
+            mock_cpmm::add_fixture(&mut program_test, mint_pda(&meme), &token_program.id(), curve_vault_pda(&meme))
+        };
+
+        let ctx = program_test.start_with_context().await;
+        let rent = ctx.banks_client.get_rent().await.unwrap();
+
+        Self {
+            ctx,
+            rent,
+            authority,
+            fee_recipient,
+            creator,
+            backers,
+            trader,
+            cranker,
+            cpmm,
+            token_program,
+            nonce: 0,
+        }
+    }
+
+    /// Send a transaction paid by the context payer so actor balances only move by what the program does
+    pub async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let tx = self.transaction(ixs, signers).await;
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// `send`, returning the `E` events the program emitted through `emit_cpi!`.
+    /// Banks only reports inner instructions for simulations, so the transaction is simulated first
+    pub async fn send_with_events<E: Event + AnchorDeserialize>(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Vec<E> {
+        let tx = self.transaction(ixs, signers).await;
+        let simulation = self.ctx.banks_client.simulate_transaction(tx.clone()).await.unwrap();
+        let inner_instructions = simulation.simulation_details.unwrap().inner_instructions.unwrap();
+        self.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+        inner_instructions
+            .iter()
+            .flatten()
+            .filter_map(|inner| inner.instruction.data.strip_prefix(EVENT_IX_TAG_LE))
+            .filter_map(|data| data.strip_prefix(E::DISCRIMINATOR))
+            .map(|mut data| E::deserialize(&mut data).unwrap())
+            .collect()
+    }
+
+    async fn transaction(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Transaction {
+        // Vary the compute limit so otherwise identical transactions get distinct signatures
+        self.nonce += 1;
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000 - self.nonce)];
+        instructions.extend_from_slice(ixs);
+
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let mut all_signers: Vec<&Keypair> = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+
+        Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        )
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap()
+            .unwrap_or_else(|| panic!("account {address} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// SPL Token and Token-2022 accounts alike
+    pub async fn token_account(&mut self, address: &Pubkey) -> spl_token_2022::state::Account {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap()
+            .unwrap_or_else(|| panic!("token account {address} not found"));
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data).unwrap().base
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        self.token_account(address).await.amount
+    }
+
+    /// SPL Token and Token-2022 mints alike
+    pub async fn mint(&mut self, address: &Pubkey) -> spl_token_2022::state::Mint {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data).unwrap().base
+    }
+
+    pub async fn mint_supply(&mut self, address: &Pubkey) -> u64 {
+        self.mint(address).await.supply
+    }
+
+    /// Metaplex metadata created for `mint` at launch
+    pub async fn token_metadata(&mut self, mint: &Pubkey) -> Metadata {
+        let address = pda::find_metadata_address(mint).0;
+        let account = self.ctx.banks_client.get_account(address).await.unwrap()
+            .unwrap_or_else(|| panic!("metadata {address} not found"));
+        assert_eq!(account.owner, anchor_spl::metadata::ID);
+        mock_token_metadata::decode(&account.data)
+    }
+
+    /// Pool creation fee in the CPMM AMM config migrations use
+    pub async fn cpmm_create_pool_fee(&mut self) -> u64 {
+        let account = self.ctx.banks_client.get_account(self.cpmm.amm_config).await.unwrap()
+            .expect("AMM config not found");
+        proof_of_meme::raydium::amm_config_create_pool_fee(&account.data).unwrap()
+    }
+
+    pub async fn platform(&mut self) -> PlatformConfig {
+        self.account(&platform_pda()).await
+    }
+
+    pub async fn curve(&mut self, meme: &Pubkey) -> BondingCurve {
+        self.account(&curve_pda(meme)).await
+    }
+
+    pub fn rent_for(&self, space: usize) -> u64 {
+        self.rent.minimum_balance(space)
+    }
+
+    /// Current cluster time
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    /// Move the clock to `unix_timestamp`
+    pub async fn warp_to_timestamp(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    /// Create `owner`'s associated token account for `mint` under the mint's token program,
+    /// paid by the context payer
+    pub async fn create_ata(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let token_program = self.ctx.banks_client.get_account(*mint).await.unwrap()
+            .unwrap_or_else(|| panic!("mint {mint} not found"))
+            .owner;
+        let ix = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            &self.ctx.payer.pubkey(),
+            owner,
+            mint,
+            &token_program,
+        );
+        self.send(&[ix], &[]).await.unwrap();
+        get_associated_token_address_with_program_id(owner, mint, &token_program)
+    }
+
+    /// Initialize the platform, enable crank rewards and route fees to the dedicated fee recipient
+    pub async fn initialize_platform(&mut self) {
+        self.initialize_platform_with_vesting(0, 0).await;
+    }
+
+    /// `initialize_platform` with a genesis vesting schedule
+    pub async fn initialize_platform_with_vesting(&mut self, cliff_seconds: i64, duration_seconds: i64) {
+        let authority = self.authority.insecure_clone();
+        let init = initialize_platform_ix(&authority.pubkey(), cliff_seconds, duration_seconds);
+        self.send(&[init], &[&authority]).await.unwrap();
+        let update = ix::update_platform_config(
+            &authority.pubkey(),
+            SUBMISSION_FEE,
+            PLATFORM_FEE_BPS,
+            GENESIS_FEE_BPS,
+            BURN_FEE_BPS,
+            CRANK_REWARD,
+            DEFAULT_LAUNCH_WINDOW,
+            UNBACK_PENALTY_BPS,
+            0,
+            CurveBounds::DEFAULT,
+        );
+        self.send(&[update], &[&authority]).await.unwrap();
+        self.send(&[ix::set_fee_recipient(&authority.pubkey(), &self.fee_recipient.pubkey())], &[&authority])
+            .await
+            .unwrap();
+    }
+
+    /// Submit meme #`index` at the minimum goal, back it with the first `MIN_BACKERS`
+    /// backers at 1 SOL each and launch it under SPL Token
+    pub async fn launch_meme(&mut self, index: u64) -> Pubkey {
+        self.launch_meme_with_params(index, CurveParams::default()).await
+    }
+
+    /// `launch_meme` with the given curve parameters
+    pub async fn launch_meme_with_params(&mut self, index: u64, curve_params: CurveParams) -> Pubkey {
+        let creator = self.creator.insecure_clone();
+        let fee_recipient = self.fee_recipient.pubkey();
+        let args = ix::SubmitMeme { curve_params, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+        self.send(&[ix::submit_meme(&creator.pubkey(), index, &fee_recipient, args)], &[&creator])
+            .await
+            .unwrap();
+
+        let meme = meme_pda(index);
+        for i in 0..MIN_BACKERS as usize {
+            let backer = self.backers[i].insecure_clone();
+            self.send(&[ix::back_meme(&backer.pubkey(), &meme, LAMPORTS_PER_SOL)], &[&backer]).await.unwrap();
+        }
+
+        let meme_state: Meme = self.account(&meme).await;
+        self.warp_to_timestamp(meme_state.proving_ends_at).await;
+        let cranker = self.cranker.insecure_clone();
+        self.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+        meme
+    }
+
+    /// Buy `meme`'s curve to completion as the trader with the smallest completing buy
+    pub async fn complete_curve(&mut self, meme: &Pubkey) {
+        let trader = self.trader.insecure_clone();
+        let fee_recipient = self.fee_recipient.pubkey();
+        self.create_ata(&trader.pubkey(), &mint_pda(meme)).await;
+
+        let curve = self.curve(meme).await;
+        let remaining = curve.completion_threshold - curve.real_sol_reserves;
+        let completing_buy = (remaining * BPS_DENOMINATOR).div_ceil(BPS_DENOMINATOR - TRADING_FEE_BPS) + 1;
+        self.send(&[ix::buy_tokens(&trader.pubkey(), meme, &spl_token::ID, &fee_recipient, completing_buy, 0)], &[&trader])
+            .await
+            .unwrap();
+    }
+}
+
+fn fund(program_test: &mut ProgramTest, address: &Pubkey, lamports: u64) {
+    program_test.add_account(*address, Account::new(lamports, 0, &system_program::ID));
+}
+
+/// solana-program-test ships the token programs but not the wSOL mint account
+fn add_native_mint(program_test: &mut ProgramTest) {
+    let mut data = vec![0u8; MINT_SIZE];
+    spl_token::state::Mint {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+
+    program_test.add_account(
+        spl_token::native_mint::ID,
+        Account {
+            lamports: Rent::default().minimum_balance(MINT_SIZE),
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Assert a transaction failed with the given program error
+pub fn assert_program_error(result: Result<(), BanksClientError>, expected: ProofOfMemeError) {
+    let expected_code = u32::from(expected);
+    match result.expect_err("transaction should have failed").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected_code, "unexpected program error code");
+        }
+        other => panic!("expected custom program error {expected_code}, got {other:?}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Independent fee and curve math, used to compute expected balances
+// ---------------------------------------------------------------------------
+
+pub fn trading_fee(sol_amount: u64) -> u64 {
+    sol_amount * TRADING_FEE_BPS / BPS_DENOMINATOR
+}
+
+/// (genesis, platform, burn) shares of a trading fee; burn takes the remainder
+pub fn split_fee(fee: u64) -> (u64, u64, u64) {
+    let genesis = fee * GENESIS_FEE_BPS as u64 / BPS_DENOMINATOR;
+    let platform = fee * PLATFORM_FEE_BPS as u64 / BPS_DENOMINATOR;
+    (genesis, platform, fee - genesis - platform)
+}
+
+pub fn expected_buy_tokens(curve: &BondingCurve, sol_in: u64) -> u64 {
+    let out = sol_in as u128 * curve.virtual_token_reserves as u128
+        / (curve.virtual_sol_reserves as u128 + sol_in as u128);
+    out.min(curve.real_token_reserves as u128) as u64
+}
+
+pub fn expected_sell_sol(curve: &BondingCurve, tokens_in: u64) -> u64 {
+    let out = tokens_in as u128 * curve.virtual_sol_reserves as u128
+        / (curve.virtual_token_reserves as u128 + tokens_in as u128);
+    out.min(curve.real_sol_reserves as u128) as u64
+}
+
+// ---------------------------------------------------------------------------
+// PDAs and instruction builders, from the client crate
+// ---------------------------------------------------------------------------
+
+pub use proof_of_meme_client::instructions as ix;
+
+pub fn platform_pda() -> Pubkey {
+    pda::find_platform_address().0
+}
+
+pub fn meme_pda(index: u64) -> Pubkey {
+    pda::find_meme_address(index).0
+}
+
+pub fn vault_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_vault_address(meme).0
+}
+
+pub fn backing_pda(meme: &Pubkey, backer: &Pubkey) -> Pubkey {
+    pda::find_backing_address(meme, backer).0
+}
+
+pub fn mint_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_mint_address(meme).0
+}
+
+pub fn curve_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_curve_address(meme).0
+}
+
+pub fn curve_vault_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_curve_vault_address(meme).0
+}
+
+pub fn genesis_pool_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_genesis_pool_address(meme).0
+}
+
+pub fn pool_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_pool_address(meme).0
+}
+
+pub fn pool_vault_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_pool_vault_address(meme).0
+}
+
+pub fn lp_mint_pda(meme: &Pubkey) -> Pubkey {
+    pda::find_lp_mint_address(meme).0
+}
+
+/// initialize_platform with the test fee schedule and the given vesting schedule
+pub fn initialize_platform_ix(authority: &Pubkey, cliff_seconds: i64, duration_seconds: i64) -> Instruction {
+    ix::initialize_platform(
+        authority,
+        SUBMISSION_FEE,
+        PLATFORM_FEE_BPS,
+        GENESIS_FEE_BPS,
+        BURN_FEE_BPS,
+        cliff_seconds,
+        duration_seconds,
+    )
+}
+
+/// update_platform_config with the test fee schedule, no timelock and the given curve bounds
+pub fn update_curve_bounds_ix(authority: &Pubkey, curve_bounds: CurveBounds) -> Instruction {
+    ix::update_platform_config(
+        authority,
+        SUBMISSION_FEE,
+        PLATFORM_FEE_BPS,
+        GENESIS_FEE_BPS,
+        BURN_FEE_BPS,
+        CRANK_REWARD,
+        DEFAULT_LAUNCH_WINDOW,
+        UNBACK_PENALTY_BPS,
+        0,
+        curve_bounds,
+    )
+}
+
+/// submit_meme arguments with fixed metadata and the shortest proving period
+pub fn submit_meme_args(sol_goal: u64, min_backers: u32) -> ix::SubmitMeme {
+    ix::SubmitMeme {
+        name: "Proof Cat".to_string(),
+        symbol: "PCAT".to_string(),
+        uri: "https://example.com/pcat.json".to_string(),
+        description: "Integration test meme".to_string(),
+        sol_goal,
+        min_backers,
+        duration_seconds: MIN_PROVING_DURATION,
+        launch_on_goal: false,
+        sol_cap: 0,
+        token_program: TokenProgram::Spl,
+        curve_params: CurveParams::default(),
+    }
+}
+
+pub fn submit_meme_ix(creator: &Pubkey, index: u64, fee_recipient: &Pubkey, sol_goal: u64, min_backers: u32) -> Instruction {
+    ix::submit_meme(creator, index, fee_recipient, submit_meme_args(sol_goal, min_backers))
+}
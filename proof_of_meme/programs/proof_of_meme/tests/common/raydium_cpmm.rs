@@ -0,0 +1,30 @@
+//! The real Raydium CPMM program, for checking `migrate_to_raydium`'s hand-built
+//! `initialize` CPI (discriminator, argument layout, account order) against it.
+
+use proof_of_meme::raydium::RAYDIUM_CPMM_PROGRAM_ID;
+use proof_of_meme_client::raydium::{RaydiumPoolAccounts, AMM_CONFIG, CREATE_POOL_FEE_RECEIVER};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use super::fixtures;
+use super::mock_cpmm::CpmmFixture;
+
+/// Load the CPMM program and the accounts `initialize` reads; the pool for `mint`,
+/// created by `creator`, is derived rather than pre-created
+pub fn add_program(program_test: &mut ProgramTest, mint: Pubkey, creator: Pubkey) -> CpmmFixture {
+    fixtures::add_program(program_test, RAYDIUM_CPMM_PROGRAM_ID, "raydium_cpmm.so");
+    fixtures::add_account(program_test, AMM_CONFIG, "amm_config.json");
+    fixtures::add_account(program_test, CREATE_POOL_FEE_RECEIVER, "create_pool_fee.json");
+
+    let pool = RaydiumPoolAccounts::derive_default(&mint, &creator);
+    CpmmFixture {
+        amm_config: pool.amm_config,
+        pool_state: pool.pool_state,
+        observation_state: pool.observation_state,
+        create_pool_fee: pool.create_pool_fee,
+        lp_mint: pool.lp_mint,
+        creator_lp_account: pool.vault_lp_account,
+        token_vault: pool.pool_token_vault,
+        wsol_vault: pool.pool_wsol_vault,
+    }
+}
@@ -0,0 +1,141 @@
+//! Curve shapes: a creator picks one at submission and the launched curve trades,
+//! completes and migrates along it
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::events::TradeExecuted;
+use proof_of_meme::state::{CurveKind, CurveParams, CurveStatus, Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+/// Price triples from the opening price to the end of the supply
+const LINEAR: CurveKind = CurveKind::Linear { end_price_bps: 30_000 };
+
+fn submit_with_kind(env: &TestEnv, index: u64, kind: CurveKind) -> solana_sdk::instruction::Instruction {
+    let args = ix::SubmitMeme {
+        curve_params: CurveParams { kind, ..CurveParams::default() },
+        ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS)
+    };
+    ix::submit_meme(&env.creator.pubkey(), index, &env.fee_recipient.pubkey(), args)
+}
+
+#[tokio::test]
+async fn linear_curve_trades_completes_and_migrates() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let creator = env.creator.insecure_clone();
+
+    // Prices can't fall as the supply sells
+    let falling = CurveKind::Linear { end_price_bps: BPS_DENOMINATOR as u32 - 1 };
+    let result = env.send(&[submit_with_kind(&env, 0, falling)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidCurveShape);
+
+    // A flat price at the goal raises less than completion needs, so the supply would run out first
+    let flat = CurveKind::Linear { end_price_bps: BPS_DENOMINATOR as u32 };
+    let result = env.send(&[submit_with_kind(&env, 0, flat)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::CurveSellsOut);
+
+    let meme = meme_pda(0);
+    env.send(&[submit_with_kind(&env, 0, LINEAR)], &[&creator]).await.unwrap();
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.curve_params.kind, LINEAR);
+
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, LAMPORTS_PER_SOL)], &[&backer]).await.unwrap();
+    }
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    let cranker = env.cranker.insecure_clone();
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    // Same opening price as a constant-product launch: what genesis backers paid per token
+    let sol_backed = LAMPORTS_PER_SOL * BACKER_COUNT as u64;
+    let genesis_tokens = TOTAL_SUPPLY * GENESIS_ALLOCATION_BPS / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.kind, LINEAR);
+    assert_eq!(curve.curve_supply, curve_tokens);
+    assert_eq!(curve.opening_price as u128, sol_backed as u128 * CURVE_PRICE_SCALE / genesis_tokens as u128);
+    let opening_price = curve.get_current_price().unwrap();
+    assert_eq!(opening_price, sol_backed * 1_000_000 / genesis_tokens);
+
+    // --- buy and sell along the line ----------------------------------------
+    let trader = env.trader.insecure_clone();
+    let mint = mint_pda(&meme);
+    let trader_token_account = env.create_ata(&trader.pubkey(), &mint).await;
+
+    let buy_amount = 2 * LAMPORTS_PER_SOL;
+    let sol_in = buy_amount - trading_fee(buy_amount);
+    let tokens_out = curve.calculate_buy_tokens(sol_in).unwrap();
+    // Every token costs at least the opening price
+    assert!((tokens_out as u128) < sol_in as u128 * CURVE_PRICE_SCALE / curve.opening_price as u128);
+
+    let buy_ix = ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, tokens_out);
+    let events = env.send_with_events::<TradeExecuted>(&[buy_ix], &[&trader]).await;
+    assert_eq!(env.token_balance(&trader_token_account).await, tokens_out);
+
+    let launch_curve = curve;
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.real_token_reserves, curve_tokens - tokens_out);
+    assert!(curve.get_current_price().unwrap() > opening_price);
+    // The schedule prices off real reserves; the virtual ones stay at their launch values
+    assert_eq!(curve.virtual_sol_reserves, launch_curve.virtual_sol_reserves);
+    assert_eq!(curve.virtual_token_reserves, launch_curve.virtual_token_reserves);
+
+    // So the trade event reports the real reserves and the price they give
+    let [event] = events.as_slice() else { panic!("expected one trade event, got {}", events.len()) };
+    assert_eq!(event.pre_sol_reserves, launch_curve.real_sol_reserves);
+    assert_eq!(event.pre_token_reserves, curve_tokens);
+    assert_eq!(event.post_sol_reserves, curve.real_sol_reserves);
+    assert_eq!(event.post_token_reserves, curve.real_token_reserves);
+    assert_eq!(event.post_price, curve.get_current_price().unwrap());
+
+    let sell_amount = tokens_out / 2;
+    let sol_out_gross = curve.calculate_sell_sol(sell_amount).unwrap();
+    let sol_out_net = sol_out_gross - trading_fee(sol_out_gross);
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    env.send(&[ix::sell_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, sell_amount, sol_out_net)], &[&trader])
+        .await
+        .unwrap();
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before + sol_out_net);
+
+    // The later half was bought higher up the line, so it sells back for more than half
+    assert!(sol_out_gross > sol_in / 2 && sol_out_gross < sol_in);
+
+    // --- complete with supply to spare, then migrate --------------------------
+    let curve = env.curve(&meme).await;
+    let remaining = curve.completion_threshold - curve.real_sol_reserves;
+    let completing_buy = (remaining * BPS_DENOMINATOR).div_ceil(BPS_DENOMINATOR - TRADING_FEE_BPS) + 1;
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, completing_buy, 0)], &[&trader])
+        .await
+        .unwrap();
+
+    // Unspent burn fees block migration, so buy back and burn first
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Complete);
+    let curve_token_account = get_associated_token_address(&curve_pda(&meme), &mint);
+    let pool_tokens = env.token_balance(&curve_token_account).await;
+    assert!(pool_tokens > 0);
+
+    let migrate_ix =
+        ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    env.send(&[migrate_ix], &[&cranker]).await.unwrap();
+
+    let cpmm_token_vault = env.cpmm.token_vault;
+    assert_eq!(env.token_balance(&cpmm_token_vault).await, pool_tokens);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Migrated);
+}
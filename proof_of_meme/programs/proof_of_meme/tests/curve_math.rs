@@ -0,0 +1,312 @@
+//! Property tests for the `BondingCurve` pricing and reserve accounting.
+//!
+//! Random buy/sell/buyback/claim sequences are replayed against a model of the
+//! curve vault that moves lamports the way the handlers do, for every curve shape.
+
+use anchor_lang::prelude::Pubkey;
+use proof_of_meme::constants::*;
+use proof_of_meme::state::{BondingCurve, CurveBreakpoint, CurveKind, CurveStatus};
+use proptest::prelude::*;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+// Fee split used by the model; only the platform share leaves the vault
+const PLATFORM_FEE_BPS: u64 = 2_000;
+const GENESIS_FEE_BPS: u64 = 7_000;
+
+/// Constant-product curve as finalize_proving leaves it for `sol_backed`
+fn launched_curve(sol_backed: u64) -> BondingCurve {
+    launched_curve_with(sol_backed, CurveKind::ConstantProduct)
+}
+
+/// Curve of `kind` as finalize_proving leaves it for `sol_backed`
+fn launched_curve_with(sol_backed: u64, kind: CurveKind) -> BondingCurve {
+    let genesis_tokens = TOTAL_SUPPLY * GENESIS_ALLOCATION_BPS / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+    let virtual_sol_reserves = (sol_backed as u128 * curve_tokens as u128 / genesis_tokens as u128) as u64;
+
+    BondingCurve {
+        meme: Pubkey::default(),
+        mint: Pubkey::default(),
+        virtual_sol_reserves,
+        virtual_token_reserves: curve_tokens,
+        real_sol_reserves: sol_backed,
+        real_token_reserves: curve_tokens,
+        tokens_sold: 0,
+        total_volume: 0,
+        genesis_fees_accumulated: 0,
+        genesis_fees_distributed: 0,
+        platform_fees_accumulated: 0,
+        burn_fees_accumulated: 0,
+        status: CurveStatus::Active,
+        completion_threshold: sol_backed + CURVE_COMPLETION_SOL,
+        bump: 0,
+        vault_bump: 0,
+        migration_pool: Pubkey::default(),
+        burn_fees_spent: 0,
+        tokens_burned: 0,
+        opening_price: (virtual_sol_reserves as u128 * CURVE_PRICE_SCALE / curve_tokens as u128) as u64,
+        curve_supply: curve_tokens,
+        kind,
+    }
+}
+
+/// Any shape submit_meme accepts
+fn curve_kind() -> impl Strategy<Value = CurveKind> {
+    prop_oneof![Just(CurveKind::ConstantProduct), price_schedule_kind()]
+}
+
+/// Any accepted shape that prices along a schedule
+fn price_schedule_kind() -> impl Strategy<Value = CurveKind> {
+    let piecewise = prop::collection::vec((1..=BPS_DENOMINATOR as u16, BPS_DENOMINATOR as u32..=100_000), 1..=MAX_CURVE_BREAKPOINTS)
+        .prop_map(|points| {
+            let mut supplies: Vec<u16> = points.iter().map(|&(supply_bps, _)| supply_bps).collect();
+            supplies.sort_unstable();
+            supplies.dedup();
+            *supplies.last_mut().unwrap() = BPS_DENOMINATOR as u16;
+            let mut prices: Vec<u32> = points.iter().map(|&(_, price_bps)| price_bps).take(supplies.len()).collect();
+            prices.sort_unstable();
+
+            let mut breakpoints = [CurveBreakpoint::default(); MAX_CURVE_BREAKPOINTS];
+            for (breakpoint, (supply_bps, price_bps)) in breakpoints.iter_mut().zip(supplies.into_iter().zip(prices)) {
+                *breakpoint = CurveBreakpoint { supply_bps, price_bps };
+            }
+            CurveKind::PiecewiseLinear { breakpoints, count: points.len().min(MAX_CURVE_BREAKPOINTS) as u8 }
+        });
+
+    prop_oneof![
+        (BPS_DENOMINATOR as u32..=MAX_CURVE_PRICE_MULTIPLE_BPS).prop_map(|end_price_bps| CurveKind::Linear { end_price_bps }),
+        (1..=5_000u16).prop_map(|growth_bps| CurveKind::Exponential { growth_bps }),
+        piecewise,
+    ]
+    .prop_filter("submit_meme rejects invalid shapes", CurveKind::is_valid)
+}
+
+fn k(curve: &BondingCurve) -> u128 {
+    curve.virtual_sol_reserves as u128 * curve.virtual_token_reserves as u128
+}
+
+/// (trading fee, platform share, genesis share, burn share)
+fn fees(amount: u64) -> (u64, u64, u64, u64) {
+    let fee = amount * TRADING_FEE_BPS / BPS_DENOMINATOR;
+    let platform = fee * PLATFORM_FEE_BPS / BPS_DENOMINATOR;
+    let genesis = fee * GENESIS_FEE_BPS / BPS_DENOMINATOR;
+    (fee, platform, genesis, fee - platform - genesis)
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Buy(u64),
+    /// Sell this fraction (bps) of the trader's tokens
+    Sell(u64),
+    Buyback,
+    ClaimGenesisFees,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => (1..=500 * LAMPORTS_PER_SOL).prop_map(Op::Buy),
+        4 => (1..=BPS_DENOMINATOR).prop_map(Op::Sell),
+        1 => Just(Op::Buyback),
+        1 => Just(Op::ClaimGenesisFees),
+    ]
+}
+
+/// Curve plus the lamports its vault would hold
+struct Model {
+    curve: BondingCurve,
+    vault: u64,
+    trader_tokens: u64,
+}
+
+impl Model {
+    fn new(sol_backed: u64, kind: CurveKind) -> Self {
+        Self { curve: launched_curve_with(sol_backed, kind), vault: sol_backed, trader_tokens: 0 }
+    }
+
+    /// Mirrors buy_tokens; returns false where the handler would reject
+    fn buy(&mut self, sol_amount: u64) -> bool {
+        let (fee, platform, genesis, burn) = fees(sol_amount);
+        let sol_after_fee = sol_amount - fee;
+        let Some(tokens_out) = self.curve.calculate_buy_tokens(sol_after_fee) else {
+            return false;
+        };
+        if tokens_out == 0 {
+            return false;
+        }
+
+        self.vault += sol_amount - platform;
+        self.curve.apply_buy(sol_after_fee, tokens_out);
+        self.curve.genesis_fees_accumulated += genesis;
+        self.curve.burn_fees_accumulated += burn;
+        self.trader_tokens += tokens_out;
+        true
+    }
+
+    /// Mirrors sell_tokens; returns false where the handler would reject
+    fn sell(&mut self, token_amount: u64) -> bool {
+        let Some(sol_out_gross) = self.curve.calculate_sell_sol(token_amount) else {
+            return false;
+        };
+        if sol_out_gross == 0 || sol_out_gross > self.vault || sol_out_gross > self.curve.real_sol_reserves {
+            return false;
+        }
+        let (fee, platform, genesis, burn) = fees(sol_out_gross);
+
+        self.vault -= sol_out_gross - fee + platform;
+        self.curve.apply_sell(token_amount, sol_out_gross);
+        self.curve.genesis_fees_accumulated += genesis;
+        self.curve.burn_fees_accumulated += burn;
+        self.trader_tokens -= token_amount;
+        true
+    }
+
+    /// Mirrors execute_buyback_burn: the spent fees never leave the vault
+    fn buyback(&mut self) {
+        let spend = self.curve.pending_burn_fees();
+        let Some(tokens_out) = self.curve.calculate_buy_tokens(spend) else {
+            return;
+        };
+        if spend == 0 || tokens_out == 0 {
+            return;
+        }
+        self.curve.apply_buy(spend, tokens_out);
+        self.curve.burn_fees_spent += spend;
+        self.curve.tokens_burned += tokens_out;
+    }
+
+    /// Pay out every undistributed genesis fee
+    fn claim_genesis_fees(&mut self) {
+        let pending = self.curve.genesis_fees_accumulated - self.curve.genesis_fees_distributed;
+        self.vault -= pending;
+        self.curve.genesis_fees_distributed += pending;
+    }
+}
+
+proptest! {
+    #[test]
+    fn trade_sequences_preserve_invariants(
+        sol_backed in MIN_SOL_GOAL..=MAX_SOL_GOAL,
+        kind in curve_kind(),
+        ops in prop::collection::vec(op(), 1..64),
+    ) {
+        let mut model = Model::new(sol_backed, kind);
+
+        for op in ops {
+            let k_before = k(&model.curve);
+            let price_before = model.curve.get_current_price().unwrap();
+            match op {
+                Op::Buy(sol) => {
+                    model.buy(sol);
+                }
+                Op::Sell(bps) => {
+                    let tokens = (model.trader_tokens as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64;
+                    if tokens > 0 {
+                        model.sell(tokens);
+                    }
+                }
+                Op::Buyback => model.buyback(),
+                Op::ClaimGenesisFees => model.claim_genesis_fees(),
+            }
+
+            let price = model.curve.get_current_price().unwrap();
+            match op {
+                Op::Buy(_) | Op::Buyback => prop_assert!(price >= price_before, "price fell after {:?}", op),
+                Op::Sell(_) => prop_assert!(price <= price_before, "price rose after {:?}", op),
+                Op::ClaimGenesisFees => prop_assert_eq!(price, price_before),
+            }
+            if kind == CurveKind::ConstantProduct {
+                prop_assert!(k(&model.curve) >= k_before, "k decreased after {:?}", op);
+            }
+            prop_assert!(
+                model.curve.real_sol_reserves <= model.vault,
+                "real_sol_reserves {} exceeds vault {} after {:?}",
+                model.curve.real_sol_reserves,
+                model.vault,
+                op
+            );
+        }
+    }
+
+    #[test]
+    fn buy_then_sell_never_profits(
+        sol_backed in MIN_SOL_GOAL..=MAX_SOL_GOAL,
+        kind in curve_kind(),
+        warmup in prop::collection::vec(1..=200 * LAMPORTS_PER_SOL, 0..8),
+        sol_in in 1..=1_000 * LAMPORTS_PER_SOL,
+    ) {
+        let mut curve = launched_curve_with(sol_backed, kind);
+        for sol in warmup {
+            if let Some(tokens) = curve.calculate_buy_tokens(sol) {
+                curve.apply_buy(sol, tokens);
+            }
+        }
+
+        let tokens = curve.calculate_buy_tokens(sol_in).unwrap();
+        curve.apply_buy(sol_in, tokens);
+        let sol_out = curve.calculate_sell_sol(tokens).unwrap();
+
+        prop_assert!(sol_out <= sol_in, "bought with {} and sold back for {}", sol_in, sol_out);
+    }
+
+    #[test]
+    fn price_schedules_charge_their_integral(
+        sol_backed in MIN_SOL_GOAL..=MAX_SOL_GOAL,
+        kind in price_schedule_kind(),
+        splits in prop::collection::vec(1..=BPS_DENOMINATOR, 1..8),
+    ) {
+        let curve = launched_curve_with(sol_backed, kind);
+
+        // Selling the whole supply back from a sold-out curve pays the average price for it
+        let mut sold_out = curve.clone();
+        sold_out.real_token_reserves = 0;
+        sold_out.real_sol_reserves = u64::MAX / 2;
+        let full_cost = sold_out.calculate_sell_sol(curve.curve_supply).unwrap();
+        let expected = curve.opening_price as u128 * curve.curve_supply as u128 / CURVE_PRICE_SCALE
+            * kind.average_price_bps().unwrap() as u128 / BPS_DENOMINATOR as u128;
+        let tolerance = full_cost as u128 / 1_000 + EXPONENTIAL_CURVE_STEPS as u128;
+        prop_assert!(full_cost as u128 + tolerance >= expected && full_cost as u128 <= expected + tolerance,
+            "whole supply sells for {} against {}", full_cost, expected);
+
+        // Buying up the schedule in pieces and selling it all back never profits
+        let mut pieces = curve.clone();
+        let mut spent = 0u64;
+        let mut tokens = 0u64;
+        for bps in splits {
+            let sol = full_cost / 8 * bps / BPS_DENOMINATOR;
+            let bought = pieces.calculate_buy_tokens(sol).unwrap();
+            pieces.apply_buy(sol, bought);
+            spent += sol;
+            tokens += bought;
+        }
+        let sol_back = pieces.calculate_sell_sol(tokens).unwrap();
+        prop_assert!(sol_back <= spent, "spent {} and sold back for {}", spent, sol_back);
+    }
+
+    #[test]
+    fn k_never_decreases_for_any_reserves(
+        virtual_sol in 1..=u64::MAX,
+        virtual_tokens in 1..=u64::MAX,
+        real_sol in 0..=u64::MAX,
+        real_token_bps in 0..=BPS_DENOMINATOR,
+        amount in 1..=u64::MAX,
+        is_buy in any::<bool>(),
+    ) {
+        let mut curve = launched_curve(MIN_SOL_GOAL);
+        curve.virtual_sol_reserves = virtual_sol;
+        curve.virtual_token_reserves = virtual_tokens;
+        curve.real_sol_reserves = real_sol.min(virtual_sol);
+        curve.real_token_reserves = (virtual_tokens as u128 * real_token_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+        let k_before = k(&curve);
+        if is_buy {
+            if let Some(tokens_out) = curve.calculate_buy_tokens(amount) {
+                curve.apply_buy(amount, tokens_out);
+            }
+        } else if let Some(sol_out) = curve.calculate_sell_sol(amount) {
+            curve.apply_sell(amount, sol_out);
+        }
+
+        prop_assert!(k(&curve) >= k_before);
+    }
+}
@@ -0,0 +1,134 @@
+//! Per-meme curve parameters: creators pick them within bounds set by the platform
+//! authority, and finalize_proving builds the curve from them
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{CurveBounds, CurveKind, CurveParams, GenesisPool, Meme, MigrationTarget};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+const BOUNDS: CurveBounds = CurveBounds {
+    min_initial_virtual_sol: 10 * LAMPORTS_PER_SOL,
+    max_initial_virtual_sol: 100 * LAMPORTS_PER_SOL,
+    min_completion_sol: 40 * LAMPORTS_PER_SOL,
+    max_completion_sol: 200 * LAMPORTS_PER_SOL,
+    min_genesis_allocation_bps: 1_000,
+    max_genesis_allocation_bps: 3_000,
+    allow_native_pool: false,
+};
+
+const PARAMS: CurveParams = CurveParams {
+    initial_virtual_sol: 50 * LAMPORTS_PER_SOL,
+    completion_sol: 120 * LAMPORTS_PER_SOL,
+    genesis_allocation_bps: 3_000,
+    kind: CurveKind::ConstantProduct,
+    migration_target: MigrationTarget::Raydium,
+};
+
+fn submit_with_params_ix(creator: &Pubkey, index: u64, fee_recipient: &Pubkey, curve_params: CurveParams) -> Instruction {
+    let args = ix::SubmitMeme { curve_params, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    ix::submit_meme(creator, index, fee_recipient, args)
+}
+
+#[tokio::test]
+async fn custom_curve_params_within_bounds() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let authority = env.authority.insecure_clone();
+    let creator = env.creator.insecure_clone();
+
+    // New platforms only allow the default curve
+    assert_eq!(env.platform().await.curve_bounds, CurveBounds::DEFAULT);
+    let result = env.send(&[submit_with_params_ix(&creator.pubkey(), 0, &fee_recipient, PARAMS)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidCurveParams);
+
+    // Bounds must keep the defaults reachable and the genesis share capped
+    for bounds in [
+        CurveBounds { min_completion_sol: CURVE_COMPLETION_SOL + 1, ..BOUNDS },
+        CurveBounds { max_genesis_allocation_bps: MAX_GENESIS_ALLOCATION_BPS as u16 + 1, ..BOUNDS },
+        CurveBounds { min_initial_virtual_sol: 0, ..BOUNDS },
+        CurveBounds { max_initial_virtual_sol: BOUNDS.min_initial_virtual_sol - 1, ..BOUNDS },
+    ] {
+        let result = env.send(&[update_curve_bounds_ix(&authority.pubkey(), bounds)], &[&authority]).await;
+        assert_program_error(result, ProofOfMemeError::InvalidCurveBounds);
+    }
+
+    env.send(&[update_curve_bounds_ix(&authority.pubkey(), BOUNDS)], &[&authority]).await.unwrap();
+    assert_eq!(env.platform().await.curve_bounds, BOUNDS);
+
+    // Each parameter is checked against its own range
+    for params in [
+        CurveParams { initial_virtual_sol: BOUNDS.max_initial_virtual_sol + 1, ..PARAMS },
+        CurveParams { completion_sol: BOUNDS.min_completion_sol - 1, ..PARAMS },
+        CurveParams { genesis_allocation_bps: BOUNDS.max_genesis_allocation_bps + 1, ..PARAMS },
+        CurveParams { migration_target: MigrationTarget::NativePool, ..PARAMS },
+    ] {
+        let result = env.send(&[submit_with_params_ix(&creator.pubkey(), 0, &fee_recipient, params)], &[&creator]).await;
+        assert_program_error(result, ProofOfMemeError::InvalidCurveParams);
+    }
+
+    let meme = meme_pda(0);
+    env.send(&[submit_with_params_ix(&creator.pubkey(), 0, &fee_recipient, PARAMS)], &[&creator])
+        .await
+        .unwrap();
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.curve_params, PARAMS);
+
+    // Unset parameters are recorded with the defaults filled in
+    let partial = CurveParams { completion_sol: 60 * LAMPORTS_PER_SOL, ..CurveParams::default() };
+    env.send(&[submit_with_params_ix(&creator.pubkey(), 1, &fee_recipient, partial)], &[&creator])
+        .await
+        .unwrap();
+    let defaulted: Meme = env.account(&meme_pda(1)).await;
+    assert_eq!(
+        defaulted.curve_params,
+        CurveParams {
+            initial_virtual_sol: 0,
+            completion_sol: 60 * LAMPORTS_PER_SOL,
+            genesis_allocation_bps: GENESIS_ALLOCATION_BPS as u16,
+            kind: CurveKind::ConstantProduct,
+            migration_target: MigrationTarget::Raydium,
+        }
+    );
+
+    // --- launch meme #0 with its parameters ----------------------------------
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, LAMPORTS_PER_SOL)], &[&backer]).await.unwrap();
+    }
+    let sol_backed = LAMPORTS_PER_SOL * BACKER_COUNT as u64;
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    let cranker = env.cranker.insecure_clone();
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    let genesis_tokens = TOTAL_SUPPLY * PARAMS.genesis_allocation_bps as u64 / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.virtual_sol_reserves, PARAMS.initial_virtual_sol);
+    assert_eq!(curve.virtual_token_reserves, curve_tokens);
+    assert_eq!(curve.real_sol_reserves, sol_backed);
+    assert_eq!(curve.real_token_reserves, curve_tokens);
+    assert_eq!(curve.completion_threshold, sol_backed + PARAMS.completion_sol);
+
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_pda(&meme)).await;
+    assert_eq!(genesis_pool.total_tokens, genesis_tokens);
+
+    let mint = mint_pda(&meme);
+    assert_eq!(env.token_balance(&get_associated_token_address(&curve_pda(&meme), &mint)).await, curve_tokens);
+    assert_eq!(
+        env.token_balance(&get_associated_token_address(&genesis_pool_pda(&meme), &mint)).await,
+        genesis_tokens
+    );
+}
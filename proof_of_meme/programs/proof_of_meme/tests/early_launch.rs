@@ -0,0 +1,113 @@
+//! launch_on_goal: a meme that reaches its goal launches once the platform launch window
+//! has passed, without waiting for proving_ends_at
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{CurveBounds, Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKING_AMOUNT: u64 = 750_000_000;
+
+#[tokio::test]
+async fn launch_on_goal_launches_after_window() {
+    let mut env = TestEnv::new(MIN_BACKERS as usize).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    // The window must stop a first-block launch
+    let authority = env.authority.insecure_clone();
+    let update = ix::update_platform_config(
+        &authority.pubkey(),
+        SUBMISSION_FEE,
+        PLATFORM_FEE_BPS,
+        GENESIS_FEE_BPS,
+        BURN_FEE_BPS,
+        CRANK_REWARD,
+        MIN_LAUNCH_WINDOW - 1,
+        UNBACK_PENALTY_BPS,
+        0,
+        CurveBounds::DEFAULT,
+    );
+    let result = env.send(&[update], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidLaunchWindow);
+
+    let meme = meme_pda(0);
+    let creator = env.creator.insecure_clone();
+    let args = ix::SubmitMeme { launch_on_goal: true, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    env.send(&[ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, args)], &[&creator])
+        .await
+        .unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert!(meme_state.launch_on_goal);
+    assert_eq!(meme_state.early_launch_at, meme_state.created_at + DEFAULT_LAUNCH_WINDOW);
+
+    // Back with all but one wallet: goal amount met, backer threshold not yet
+    for i in 0..MIN_BACKERS as usize - 1 {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    }
+
+    // Past the window but short of the goal: neither launch nor fail early
+    env.warp_to_timestamp(meme_state.early_launch_at).await;
+    let cranker = env.cranker.insecure_clone();
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+    let result = env.send(&[ix::mark_meme_failed(&cranker.pubkey(), &meme)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    let last = env.backers[MIN_BACKERS as usize - 1].insecure_clone();
+    env.send(&[ix::back_meme(&last.pubkey(), &meme, BACKING_AMOUNT)], &[&last]).await.unwrap();
+    let meme_state: Meme = env.account(&meme).await;
+    assert!(meme_state.goal_reached());
+
+    // An early launch is now possible, so the backing that made it possible is locked in
+    let result = env.send(&[ix::unback_meme(&last.pubkey(), &meme, &fee_recipient, BACKING_AMOUNT)], &[&last]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingEnded);
+
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+    assert!(meme_state.launched_at < meme_state.proving_ends_at);
+    assert_eq!(env.curve(&meme).await.real_sol_reserves, BACKING_AMOUNT * MIN_BACKERS as u64);
+}
+
+#[tokio::test]
+async fn goal_reached_inside_window_waits() {
+    let mut env = TestEnv::new(MIN_BACKERS as usize).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let creator = env.creator.insecure_clone();
+    let args = ix::SubmitMeme { launch_on_goal: true, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    env.send(&[ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, args)], &[&creator])
+        .await
+        .unwrap();
+
+    for i in 0..MIN_BACKERS as usize {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    }
+
+    // Goal reached in the first block, but the window has not passed
+    let cranker = env.cranker.insecure_clone();
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    let meme_state: Meme = env.account(&meme).await;
+    env.warp_to_timestamp(meme_state.early_launch_at - 1).await;
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    env.warp_to_timestamp(meme_state.early_launch_at).await;
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+}
@@ -0,0 +1,94 @@
+//! Failure path: proving ends short of the goal → mark_meme_failed → backers withdraw
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Backing, Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = 5;
+const BACKING_AMOUNT: u64 = 2 * LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn failed_meme_refunds_backers() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let vault = vault_pda(&meme);
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+
+    // Five backers at the per-wallet cap: 10 SOL of a 20 SOL goal
+    let mut backer_balances = Vec::with_capacity(BACKER_COUNT);
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        let before = env.lamports(&backer.pubkey()).await;
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+
+        let after = env.lamports(&backer.pubkey()).await;
+        assert_eq!(after, before - BACKING_AMOUNT - env.rent_for(Backing::SIZE));
+        backer_balances.push(after);
+    }
+    let total_backed = BACKING_AMOUNT * BACKER_COUNT as u64;
+    assert_eq!(env.lamports(&vault).await, total_backed + CRANK_REWARD);
+
+    // Backing above 10% of the goal is rejected
+    let whale = env.backers[0].insecure_clone();
+    let result = env.send(&[ix::back_meme(&whale.pubkey(), &meme, 1)], &[&whale]).await;
+    assert_program_error(result, ProofOfMemeError::BackingExceedsMaximum);
+
+    // Refunds are locked while proving is active
+    let result = env.send(&[ix::withdraw_backing(&whale.pubkey(), &meme)], &[&whale]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    // Cannot be failed early
+    let cranker = env.cranker.insecure_clone();
+    let result = env.send(&[ix::mark_meme_failed(&cranker.pubkey(), &meme)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    let meme_state: Meme = env.account(&meme).await;
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    // Goal missed, so it cannot launch
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::GoalNotReached);
+
+    let cranker_before = env.lamports(&cranker.pubkey()).await;
+    env.send(&[ix::mark_meme_failed(&cranker.pubkey(), &meme)], &[&cranker]).await.unwrap();
+
+    // The cranker collects the escrowed reward, leaving exactly the backing for refunds
+    assert_eq!(env.lamports(&cranker.pubkey()).await, cranker_before + CRANK_REWARD);
+    assert_eq!(env.lamports(&vault).await, total_backed);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Failed);
+    assert_eq!(meme_state.crank_reward, 0);
+
+    // Each backer gets exactly their backing back
+    let mut vault_balance = total_backed;
+    for (i, balance) in backer_balances.iter().enumerate() {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::withdraw_backing(&backer.pubkey(), &meme)], &[&backer]).await.unwrap();
+        vault_balance -= BACKING_AMOUNT;
+
+        assert_eq!(env.lamports(&backer.pubkey()).await, balance + BACKING_AMOUNT);
+        assert_eq!(env.lamports(&vault).await, vault_balance);
+
+        let backing: Backing = env.account(&backing_pda(&meme, &backer.pubkey())).await;
+        assert!(backing.withdrawn);
+        assert_eq!(backing.amount, 0);
+    }
+    assert_eq!(env.lamports(&vault).await, 0);
+
+    // No double refunds
+    let first = env.backers[0].insecure_clone();
+    let result = env.send(&[ix::withdraw_backing(&first.pubkey(), &meme)], &[&first]).await;
+    assert_program_error(result, ProofOfMemeError::BackingAlreadyWithdrawn);
+    assert_eq!(env.lamports(&first.pubkey()).await, backer_balances[0] + BACKING_AMOUNT);
+}
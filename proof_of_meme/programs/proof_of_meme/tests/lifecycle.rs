@@ -0,0 +1,403 @@
+//! Happy path: initialize → submit → back → launch → trade → claim fees → migrate to Raydium
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Backing, BondingCurve, CurveStatus, GenesisPool, Meme, MemeStatus, PlatformConfig};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = 32;
+
+/// 0.5, 0.75, 1.0, 1.25 SOL repeating - all qualify for fees, none exceed 10% of a 20 SOL goal
+fn backing_amount(i: usize) -> u64 {
+    500_000_000 + (i as u64 % 4) * 250_000_000
+}
+
+#[tokio::test]
+async fn full_lifecycle_to_raydium() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+
+    // --- initialize_platform -------------------------------------------------
+    let authority_before = env.lamports(&env.authority.pubkey()).await;
+    env.initialize_platform().await;
+
+    assert_eq!(
+        env.lamports(&env.authority.pubkey()).await,
+        authority_before - env.rent_for(PlatformConfig::SIZE)
+    );
+    let platform = env.platform().await;
+    assert_eq!(platform.authority, env.authority.pubkey());
+    assert_eq!(platform.fee_recipient, fee_recipient);
+    assert_eq!(platform.submission_fee, SUBMISSION_FEE);
+    assert_eq!(platform.crank_reward, CRANK_REWARD);
+    assert_eq!(platform.total_memes_submitted, 0);
+
+    // --- submit_meme ---------------------------------------------------------
+    let meme = meme_pda(0);
+    let creator = env.creator.pubkey();
+    let creator_before = env.lamports(&creator).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+
+    let creator_kp = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator, 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator_kp])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        env.lamports(&creator).await,
+        creator_before - SUBMISSION_FEE - env.rent_for(Meme::SIZE)
+    );
+    // The crank reward is escrowed in the vault out of the submission fee
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + SUBMISSION_FEE - CRANK_REWARD);
+    assert_eq!(env.lamports(&vault_pda(&meme)).await, CRANK_REWARD);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Proving);
+    assert_eq!(meme_state.crank_reward, CRANK_REWARD);
+    assert_eq!(meme_state.creator, creator);
+    assert_eq!(meme_state.sol_goal, MIN_SOL_GOAL);
+    assert_eq!(env.platform().await.total_memes_submitted, 1);
+
+    // --- back_meme x32 -------------------------------------------------------
+    let vault = vault_pda(&meme);
+    let mut total_backed = 0;
+
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        let amount = backing_amount(i);
+        let backer_before = env.lamports(&backer.pubkey()).await;
+
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, amount)], &[&backer]).await.unwrap();
+        total_backed += amount;
+
+        assert_eq!(
+            env.lamports(&backer.pubkey()).await,
+            backer_before - amount - env.rent_for(Backing::SIZE)
+        );
+        assert_eq!(env.lamports(&vault).await, total_backed + CRANK_REWARD);
+
+        let backing: Backing = env.account(&backing_pda(&meme, &backer.pubkey())).await;
+        assert_eq!(backing.amount, amount);
+        assert!(backing.qualifies_for_fees);
+    }
+
+    // Top-up from an existing backer: no new rent, backer count unchanged
+    let top_up_backer = env.backers[0].insecure_clone();
+    let top_up = 500_000_000;
+    let backer_before = env.lamports(&top_up_backer.pubkey()).await;
+    env.send(&[ix::back_meme(&top_up_backer.pubkey(), &meme, top_up)], &[&top_up_backer])
+        .await
+        .unwrap();
+    total_backed += top_up;
+
+    assert_eq!(env.lamports(&top_up_backer.pubkey()).await, backer_before - top_up);
+    assert_eq!(env.lamports(&vault).await, total_backed + CRANK_REWARD);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.sol_backed, total_backed);
+    assert_eq!(meme_state.backer_count, BACKER_COUNT as u32);
+
+    // --- finalize_proving ----------------------------------------------------
+    let cranker = env.cranker.insecure_clone();
+
+    // Too early
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    let cranker_before = env.lamports(&cranker.pubkey()).await;
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    let launch_rent = env.rent_for(MINT_SIZE)
+        + env.rent_for(BondingCurve::SIZE)
+        + env.rent_for(GenesisPool::SIZE)
+        + 2 * env.rent_for(TOKEN_ACCOUNT_SIZE)
+        + env.rent_for(mock_token_metadata::METADATA_SIZE);
+    assert_eq!(env.lamports(&cranker.pubkey()).await, cranker_before + CRANK_REWARD - launch_rent);
+
+    let mint = mint_pda(&meme);
+    let curve_key = curve_pda(&meme);
+    let curve_vault = curve_vault_pda(&meme);
+    let genesis_pool_key = genesis_pool_pda(&meme);
+    let curve_token_account = get_associated_token_address(&curve_key, &mint);
+    let genesis_token_account = get_associated_token_address(&genesis_pool_key, &mint);
+
+    let genesis_tokens = TOTAL_SUPPLY * GENESIS_ALLOCATION_BPS / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+
+    // Backing SOL swept from the proving vault into the curve vault
+    assert_eq!(env.lamports(&vault).await, 0);
+    assert_eq!(env.lamports(&curve_vault).await, total_backed);
+    assert_eq!(env.mint_supply(&mint).await, TOTAL_SUPPLY);
+    // Nobody can mint more or freeze holders once launched
+    let mint_state = env.mint(&mint).await;
+    assert!(mint_state.mint_authority.is_none());
+    assert!(mint_state.freeze_authority.is_none());
+    assert!(proof_of_meme_client::accounts::is_fixed_supply(&mint_state));
+    assert_eq!(env.token_balance(&curve_token_account).await, curve_tokens);
+    assert_eq!(env.token_balance(&genesis_token_account).await, genesis_tokens);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+    assert_eq!(meme_state.mint, mint);
+
+    // Token metadata carries the meme's pitch and can never be changed
+    let metadata = env.token_metadata(&mint).await;
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(metadata.name, meme_state.get_name());
+    assert_eq!(metadata.symbol, meme_state.get_symbol());
+    assert_eq!(metadata.uri, meme_state.get_uri());
+    assert_eq!(metadata.update_authority, curve_key);
+    assert!(!metadata.is_mutable);
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Active);
+    assert_eq!(
+        curve.virtual_sol_reserves,
+        (total_backed as u128 * curve_tokens as u128 / genesis_tokens as u128) as u64
+    );
+    assert_eq!(curve.virtual_token_reserves, curve_tokens);
+    assert_eq!(curve.real_sol_reserves, total_backed);
+    assert_eq!(curve.real_token_reserves, curve_tokens);
+    assert_eq!(curve.completion_threshold, total_backed + CURVE_COMPLETION_SOL);
+
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_key).await;
+    assert_eq!(genesis_pool.total_qualified_backing, total_backed);
+    assert_eq!(genesis_pool.total_tokens, genesis_tokens);
+
+    // Uncapped launches have no oversubscription to refund
+    let backer = env.backers[0].insecure_clone();
+    let result = env.send(&[ix::claim_backing_refund(&backer.pubkey(), &meme)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::NoRefundAvailable);
+
+    // --- buy_tokens ----------------------------------------------------------
+    let trader = env.trader.insecure_clone();
+    let trader_token_account = env.create_ata(&trader.pubkey(), &mint).await;
+    let mut expected_genesis_fees = 0;
+
+    let buy_amount = 2 * LAMPORTS_PER_SOL;
+    let fee = trading_fee(buy_amount);
+    let (genesis_fee, platform_fee, burn_fee) = split_fee(fee);
+    let tokens_out = expected_buy_tokens(&curve, buy_amount - fee);
+    expected_genesis_fees += genesis_fee;
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+
+    // Slippage guard rejects an unreachable minimum
+    let result = env
+        .send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, tokens_out + 1)], &[&trader])
+        .await;
+    assert_program_error(result, ProofOfMemeError::SlippageExceeded);
+
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, tokens_out)], &[&trader])
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before - buy_amount);
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + platform_fee);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before + buy_amount - platform_fee);
+    assert_eq!(env.token_balance(&trader_token_account).await, tokens_out);
+    assert_eq!(env.token_balance(&curve_token_account).await, curve_tokens - tokens_out);
+
+    let curve_after_buy = env.curve(&meme).await;
+    assert_eq!(curve_after_buy.virtual_sol_reserves, curve.virtual_sol_reserves + buy_amount - fee);
+    assert_eq!(curve_after_buy.virtual_token_reserves, curve.virtual_token_reserves - tokens_out);
+    assert_eq!(curve_after_buy.real_sol_reserves, curve.real_sol_reserves + buy_amount - fee);
+    assert_eq!(curve_after_buy.real_token_reserves, curve.real_token_reserves - tokens_out);
+    assert_eq!(curve_after_buy.genesis_fees_accumulated, genesis_fee);
+    assert_eq!(curve_after_buy.platform_fees_accumulated, platform_fee);
+    assert_eq!(curve_after_buy.burn_fees_accumulated, burn_fee);
+
+    // --- sell_tokens ---------------------------------------------------------
+    let curve = curve_after_buy;
+    let sell_amount = tokens_out / 2;
+    let sol_out_gross = expected_sell_sol(&curve, sell_amount);
+    let fee = trading_fee(sol_out_gross);
+    let (genesis_fee, platform_fee, _) = split_fee(fee);
+    let sol_out_net = sol_out_gross - fee;
+    expected_genesis_fees += genesis_fee;
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+
+    env.send(&[ix::sell_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, sell_amount, sol_out_net)], &[&trader])
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before + sol_out_net);
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + platform_fee);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before - sol_out_net - platform_fee);
+    assert_eq!(env.token_balance(&trader_token_account).await, tokens_out - sell_amount);
+    assert_eq!(
+        env.token_balance(&curve_token_account).await,
+        curve_tokens - tokens_out + sell_amount
+    );
+
+    let curve_after_sell = env.curve(&meme).await;
+    assert_eq!(curve_after_sell.real_sol_reserves, curve.real_sol_reserves - sol_out_gross);
+    assert_eq!(curve_after_sell.real_token_reserves, curve.real_token_reserves + sell_amount);
+    assert_eq!(curve_after_sell.genesis_fees_accumulated, expected_genesis_fees);
+
+    // --- claim_genesis_fees --------------------------------------------------
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_key).await;
+    assert_eq!(genesis_pool.total_fees, expected_genesis_fees);
+
+    let claimer = env.backers[3].insecure_clone();
+    let claimer_backing = backing_amount(3);
+    let claimable = (claimer_backing as u128 * expected_genesis_fees as u128 / total_backed as u128) as u64;
+    assert!(claimable > 0);
+
+    let claimer_before = env.lamports(&claimer.pubkey()).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+
+    env.send(&[ix::claim_genesis_fees(&claimer.pubkey(), &meme)], &[&claimer]).await.unwrap();
+
+    assert_eq!(env.lamports(&claimer.pubkey()).await, claimer_before + claimable);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before - claimable);
+
+    let backing: Backing = env.account(&backing_pda(&meme, &claimer.pubkey())).await;
+    assert_eq!(backing.fees_claimed, claimable);
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_key).await;
+    assert_eq!(genesis_pool.total_claimed, claimable);
+
+    // Nothing left until more fees accrue
+    let result = env.send(&[ix::claim_genesis_fees(&claimer.pubkey(), &meme)], &[&claimer]).await;
+    assert_program_error(result, ProofOfMemeError::NoFeesToClaim);
+
+    // --- buy through to curve completion -------------------------------------
+    let curve = env.curve(&meme).await;
+    let remaining = curve.completion_threshold - curve.real_sol_reserves;
+    // Smallest buy whose post-fee amount reaches the threshold
+    let completing_buy = (remaining * BPS_DENOMINATOR).div_ceil(BPS_DENOMINATOR - TRADING_FEE_BPS) + 1;
+    let fee = trading_fee(completing_buy);
+    let (genesis_fee, platform_fee, _) = split_fee(fee);
+    let tokens_out = expected_buy_tokens(&curve, completing_buy - fee);
+    expected_genesis_fees += genesis_fee;
+
+    let trader_tokens_before = env.token_balance(&trader_token_account).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, completing_buy, 0)], &[&trader])
+        .await
+        .unwrap();
+
+    assert_eq!(env.token_balance(&trader_token_account).await, trader_tokens_before + tokens_out);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before + completing_buy - platform_fee);
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Complete);
+    assert!(curve.real_sol_reserves >= curve.completion_threshold);
+
+    // Curve is closed to further trading
+    let result = env
+        .send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, LAMPORTS_PER_SOL, 0)], &[&trader])
+        .await;
+    assert_program_error(result, ProofOfMemeError::CurveCompleted);
+
+    // --- execute_buyback_burn on the completed curve -------------------------
+    // Burn fees, including the completing buy's, must be spent before migrating
+    let migrate_ix = ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    let result = env.send(&[migrate_ix], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::BurnFeesPending);
+
+    let burn_fees = curve.pending_burn_fees();
+    assert!(burn_fees > 0);
+    let tokens_burned = expected_buy_tokens(&curve, burn_fees);
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    assert_eq!(env.mint_supply(&mint).await, TOTAL_SUPPLY - tokens_burned);
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Complete);
+    assert_eq!(curve.pending_burn_fees(), 0);
+
+    // --- migrate_to_raydium --------------------------------------------------
+    // The meme was submitted for Raydium, so the native pool is off limits
+    let result = env
+        .send(&[ix::migrate_to_pool(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient)], &[&cranker])
+        .await;
+    assert_program_error(result, ProofOfMemeError::WrongMigrationTarget);
+
+    let pool_sol = curve.real_sol_reserves - MIGRATION_FEE - mock_cpmm::CREATE_POOL_FEE - RAYDIUM_POOL_RENT;
+    let pool_tokens = env.token_balance(&curve_token_account).await;
+    assert_eq!(pool_tokens, curve.real_token_reserves);
+
+    let cpmm_lp_mint = env.cpmm.lp_mint;
+    let cpmm_lp_account = env.cpmm.creator_lp_account;
+    let cpmm_token_vault = env.cpmm.token_vault;
+    let cpmm_wsol_vault = env.cpmm.wsol_vault;
+    let cpmm_fee_account = env.cpmm.create_pool_fee;
+    let cpmm_pool_state = env.cpmm.pool_state;
+
+    let cranker_before = env.lamports(&cranker.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+    let wsol_vault_before = env.lamports(&cpmm_wsol_vault).await;
+    let platform_fees_before = env.platform().await.total_platform_fees;
+
+    let migrate_ix = ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    env.send(&[migrate_ix], &[&cranker]).await.unwrap();
+
+    // Migrator pays rent for the curve vault's token and wSOL accounts and
+    // collects the crank reward out of the migration fee
+    assert_eq!(
+        env.lamports(&cranker.pubkey()).await,
+        cranker_before + CRANK_REWARD - 2 * env.rent_for(TOKEN_ACCOUNT_SIZE)
+    );
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + MIGRATION_FEE - CRANK_REWARD);
+    assert_eq!(
+        env.lamports(&curve_vault).await,
+        curve_vault_before - MIGRATION_FEE - pool_sol - mock_cpmm::CREATE_POOL_FEE
+    );
+    assert_eq!(env.lamports(&cpmm_fee_account).await, mock_cpmm::CREATE_POOL_FEE);
+
+    // Liquidity landed in the pool vaults
+    assert_eq!(env.token_balance(&curve_token_account).await, 0);
+    assert_eq!(env.token_balance(&cpmm_token_vault).await, pool_tokens);
+    assert_eq!(env.token_balance(&cpmm_wsol_vault).await, pool_sol);
+    assert_eq!(env.lamports(&cpmm_wsol_vault).await, wsol_vault_before + pool_sol);
+    assert_eq!(
+        env.token_balance(&get_associated_token_address(&curve_vault, &mint)).await,
+        0
+    );
+    assert_eq!(
+        env.token_balance(&get_associated_token_address(&curve_vault, &spl_token::native_mint::ID)).await,
+        0
+    );
+
+    // Every LP token handed to the creator was burned
+    assert_eq!(env.token_balance(&cpmm_lp_account).await, 0);
+    assert_eq!(env.mint_supply(&cpmm_lp_mint).await, 0);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Migrated);
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Migrated);
+    assert_eq!(curve.real_sol_reserves, 0);
+    assert_eq!(curve.real_token_reserves, 0);
+    assert_eq!(curve.migration_pool, cpmm_pool_state);
+    assert_eq!(
+        env.platform().await.total_platform_fees,
+        platform_fees_before + MIGRATION_FEE - CRANK_REWARD
+    );
+
+    // Genesis backers can still claim after migration
+    let late_claimer = env.backers[4].insecure_clone();
+    let late_claimable =
+        (backing_amount(4) as u128 * expected_genesis_fees as u128 / total_backed as u128) as u64;
+    let late_before = env.lamports(&late_claimer.pubkey()).await;
+    env.send(&[ix::claim_genesis_fees(&late_claimer.pubkey(), &meme)], &[&late_claimer])
+        .await
+        .unwrap();
+    assert_eq!(env.lamports(&late_claimer.pubkey()).await, late_before + late_claimable);
+}
@@ -0,0 +1,83 @@
+//! Creator metadata edits during proving: change tracking and the backing lock
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::Meme;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = 3;
+const BACKING_AMOUNT: u64 = 2 * LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn creator_edits_until_locked() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.metadata_updates, 0);
+    assert_eq!(meme_state.metadata_updated_at, 0);
+    assert_eq!(meme_state.metadata_lock_threshold(), Some(MIN_SOL_GOAL / 4));
+
+    let backer = env.backers[0].insecure_clone();
+    let update = ix::update_meme_metadata(&backer.pubkey(), &meme, "https://x.io".to_string(), String::new());
+    let result = env.send(&[update], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::NotMemeCreator);
+
+    let update = ix::update_meme_metadata(&creator.pubkey(), &meme, "x".repeat(MAX_URI_LENGTH + 1), String::new());
+    let result = env.send(&[update], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::UriTooLong);
+
+    // A shorter value replaces the old one completely
+    let edited_at = meme_state.created_at + 60;
+    env.warp_to_timestamp(edited_at).await;
+    let update = ix::update_meme_metadata(&creator.pubkey(), &meme, "https://x.io".to_string(), "Fixed".to_string());
+    env.send(&[update], &[&creator]).await.unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.get_uri(), "https://x.io");
+    assert_eq!(meme_state.get_description(), "Fixed");
+    assert!(meme_state.uri[meme_state.uri_length as usize..].iter().all(|&b| b == 0));
+    assert!(meme_state.description[meme_state.description_length as usize..].iter().all(|&b| b == 0));
+    assert_eq!(meme_state.metadata_updates, 1);
+    assert_eq!(meme_state.metadata_updated_at, edited_at);
+
+    // Below the threshold the creator can still edit
+    for i in 0..BACKER_COUNT - 1 {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    }
+    assert!(!env.account::<Meme>(&meme).await.metadata_locked);
+    let update = ix::update_meme_metadata(&creator.pubkey(), &meme, "https://y.io".to_string(), "Fixed".to_string());
+    env.send(&[update], &[&creator]).await.unwrap();
+    assert_eq!(env.account::<Meme>(&meme).await.metadata_updates, 2);
+
+    // Crossing 25% of the goal locks the pitch
+    let last = env.backers[BACKER_COUNT - 1].insecure_clone();
+    env.send(&[ix::back_meme(&last.pubkey(), &meme, BACKING_AMOUNT)], &[&last]).await.unwrap();
+    assert!(env.account::<Meme>(&meme).await.metadata_locked);
+
+    let update = ix::update_meme_metadata(&creator.pubkey(), &meme, "https://z.io".to_string(), String::new());
+    let result = env.send(&[update], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::MetadataLocked);
+
+    // Backing falling back under the threshold doesn't reopen edits
+    env.send(&[ix::unback_meme(&last.pubkey(), &meme, &fee_recipient, BACKING_AMOUNT)], &[&last]).await.unwrap();
+    let meme_state: Meme = env.account(&meme).await;
+    assert!(meme_state.sol_backed < MIN_SOL_GOAL / 4);
+    assert!(meme_state.metadata_locked);
+
+    let update = ix::update_meme_metadata(&creator.pubkey(), &meme, "https://z.io".to_string(), String::new());
+    let result = env.send(&[update], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::MetadataLocked);
+    assert_eq!(env.account::<Meme>(&meme).await.get_uri(), "https://y.io");
+}
@@ -0,0 +1,44 @@
+//! SPL Token launch against the real Metaplex Token Metadata program rather than the
+//! mock, so a wrong `CreateMetadataAccountsV3` account list or argument layout fails
+//! here. Needs the mainnet fixtures:
+//!
+//!     scripts/fetch-fixtures.sh && cargo test --test metaplex_metadata -- --ignored
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::state::{Meme, MemeStatus};
+use proof_of_meme_client::pda;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+#[tokio::test]
+#[ignore = "needs tests/fixtures from scripts/fetch-fixtures.sh"]
+async fn finalize_creates_real_metadata() {
+    let mut env = TestEnv::with_real_programs(BACKER_COUNT).await;
+    env.initialize_platform().await;
+
+    let meme = env.launch_meme(0).await;
+    let mint = mint_pda(&meme);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+
+    // The real program created the metadata account at its PDA, at the size the mock assumes
+    let address = pda::find_metadata_address(&mint).0;
+    let account = env.ctx.banks_client.get_account(address).await.unwrap().expect("metadata not created");
+    assert_eq!(account.owner, anchor_spl::metadata::ID);
+    assert_eq!(account.data.len(), mock_token_metadata::METADATA_SIZE);
+
+    // Same fields the mock writes; the real program pads the strings with nulls
+    let metadata = env.token_metadata(&mint).await;
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(metadata.name.trim_end_matches('\0'), meme_state.get_name());
+    assert_eq!(metadata.symbol.trim_end_matches('\0'), meme_state.get_symbol());
+    assert_eq!(metadata.uri.trim_end_matches('\0'), meme_state.get_uri());
+    assert_eq!(metadata.update_authority, curve_pda(&meme));
+    assert!(!metadata.is_mutable);
+
+    // Creating metadata needs the mint authority, which finalize revokes right after
+    assert!(env.mint(&mint).await.mint_authority.is_none());
+}
@@ -0,0 +1,279 @@
+//! Native pool: a completed curve migrates into the constant-product pool, which then
+//! swaps both ways, takes and returns liquidity and burns its burn fees
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{
+    CurveBounds, CurveParams, CurveStatus, GenesisPool, Meme, MemeStatus, MigrationTarget, Pool, SwapDirection,
+};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+fn k(pool: &Pool) -> u128 {
+    pool.sol_reserves as u128 * pool.token_reserves as u128
+}
+
+fn swap_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    (amount_in as u128 * reserve_out as u128 / (reserve_in as u128 + amount_in as u128)) as u64
+}
+
+#[tokio::test]
+async fn pool_migrates_swaps_and_takes_liquidity() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    let authority = env.authority.insecure_clone();
+    let creator = env.creator.insecure_clone();
+    env.initialize_platform().await;
+
+    // --- native pool opt-in --------------------------------------------------
+    // Creators may only pick the native pool once the platform allows it
+    let params = CurveParams { migration_target: MigrationTarget::NativePool, ..CurveParams::default() };
+    let submit_ix = ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, ix::SubmitMeme {
+        curve_params: params,
+        ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS)
+    });
+    let result = env.send(&[submit_ix], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidCurveParams);
+
+    let bounds = CurveBounds { allow_native_pool: true, ..CurveBounds::DEFAULT };
+    env.send(&[update_curve_bounds_ix(&authority.pubkey(), bounds)], &[&authority]).await.unwrap();
+
+    let meme = env.launch_meme_with_params(0, params).await;
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.curve_params.migration_target, MigrationTarget::NativePool);
+    env.complete_curve(&meme).await;
+    let cranker = env.cranker.insecure_clone();
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    let mint = mint_pda(&meme);
+    let pool_key = pool_pda(&meme);
+    let pool_vault = pool_vault_pda(&meme);
+    let lp_mint = lp_mint_pda(&meme);
+    let curve_vault = curve_vault_pda(&meme);
+    let curve_token_account = get_associated_token_address(&curve_pda(&meme), &mint);
+    let pool_token_account = get_associated_token_address(&pool_key, &mint);
+
+    // --- migrate_to_pool -----------------------------------------------------
+    // The meme was submitted for the native pool, so Raydium is off limits
+    let migrate_ix = ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    let result = env.send(&[migrate_ix], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::WrongMigrationTarget);
+
+    let curve = env.curve(&meme).await;
+    let pool_sol = curve.real_sol_reserves - MIGRATION_FEE;
+    let pool_tokens = env.token_balance(&curve_token_account).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+
+    env.send(&[ix::migrate_to_pool(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient)], &[&cranker])
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + MIGRATION_FEE - CRANK_REWARD);
+    assert_eq!(env.lamports(&pool_vault).await, pool_sol);
+    assert_eq!(env.token_balance(&pool_token_account).await, pool_tokens);
+    assert_eq!(env.token_balance(&curve_token_account).await, 0);
+
+    // Seeded liquidity is locked: it counts towards the LP supply but no LP tokens exist for it
+    let initial_lp = (pool_sol as u128 * pool_tokens as u128).isqrt() as u64;
+    let pool: Pool = env.account(&pool_key).await;
+    assert_eq!(pool.sol_reserves, pool_sol);
+    assert_eq!(pool.token_reserves, pool_tokens);
+    assert_eq!(pool.lp_supply, initial_lp);
+    assert_eq!(pool.locked_lp, initial_lp);
+    assert_eq!(env.mint_supply(&lp_mint).await, 0);
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Migrated);
+    assert_eq!(curve.migration_pool, pool_key);
+    assert_eq!(curve.real_sol_reserves, 0);
+    assert_eq!(curve.real_token_reserves, 0);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Migrated);
+
+    // --- swap SOL -> tokens --------------------------------------------------
+    let trader = env.trader.insecure_clone();
+    let trader_token_account = get_associated_token_address(&trader.pubkey(), &mint);
+
+    let buy_amount = LAMPORTS_PER_SOL;
+    let fee = trading_fee(buy_amount);
+    let (genesis_fee, platform_fee, buy_burn_fee) = split_fee(fee);
+    let tokens_out = swap_out(buy_amount - fee, pool.sol_reserves, pool.token_reserves);
+
+    let result = env
+        .send(
+            &[ix::swap(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, tokens_out + 1, SwapDirection::SolToToken)],
+            &[&trader],
+        )
+        .await;
+    assert_program_error(result, ProofOfMemeError::SlippageExceeded);
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let trader_tokens_before = env.token_balance(&trader_token_account).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+    let genesis_fees_before = env.curve(&meme).await.genesis_fees_accumulated;
+
+    env.send(
+        &[ix::swap(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, buy_amount, tokens_out, SwapDirection::SolToToken)],
+        &[&trader],
+    )
+    .await
+    .unwrap();
+
+    // Fees split exactly like the curve; the burn share waits in the pool vault
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before - buy_amount);
+    assert_eq!(env.token_balance(&trader_token_account).await, trader_tokens_before + tokens_out);
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + platform_fee);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before + genesis_fee);
+    assert_eq!(env.lamports(&pool_vault).await, pool_sol + buy_amount - fee + buy_burn_fee);
+
+    let pool_after_buy: Pool = env.account(&pool_key).await;
+    assert_eq!(pool_after_buy.sol_reserves, pool.sol_reserves + buy_amount - fee);
+    assert_eq!(pool_after_buy.token_reserves, pool.token_reserves - tokens_out);
+    assert!(k(&pool_after_buy) >= k(&pool));
+    assert_eq!(pool_after_buy.genesis_fees_accumulated, genesis_fee);
+    assert_eq!(pool_after_buy.platform_fees_accumulated, platform_fee);
+    assert_eq!(pool_after_buy.burn_fees_accumulated, buy_burn_fee);
+
+    // Genesis backers keep earning after graduation
+    assert_eq!(env.curve(&meme).await.genesis_fees_accumulated, genesis_fees_before + genesis_fee);
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_pda(&meme)).await;
+    assert_eq!(genesis_pool.total_fees, genesis_fees_before + genesis_fee);
+
+    // --- swap tokens -> SOL --------------------------------------------------
+    let pool = pool_after_buy;
+    let sell_amount = tokens_out / 2;
+    let sol_out_gross = swap_out(sell_amount, pool.token_reserves, pool.sol_reserves);
+    let fee = trading_fee(sol_out_gross);
+    let (genesis_fee, platform_fee, sell_burn_fee) = split_fee(fee);
+    let sol_out_net = sol_out_gross - fee;
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let curve_vault_before = env.lamports(&curve_vault).await;
+    let pool_vault_before = env.lamports(&pool_vault).await;
+
+    env.send(
+        &[ix::swap(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, sell_amount, sol_out_net, SwapDirection::TokenToSol)],
+        &[&trader],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before + sol_out_net);
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + platform_fee);
+    assert_eq!(env.lamports(&curve_vault).await, curve_vault_before + genesis_fee);
+    assert_eq!(env.lamports(&pool_vault).await, pool_vault_before - sol_out_net - genesis_fee - platform_fee);
+
+    let pool_after_sell: Pool = env.account(&pool_key).await;
+    assert_eq!(pool_after_sell.sol_reserves, pool.sol_reserves - sol_out_gross);
+    assert_eq!(pool_after_sell.token_reserves, pool.token_reserves + sell_amount);
+    assert!(k(&pool_after_sell) >= k(&pool));
+    assert_eq!(pool_after_sell.burn_fees_accumulated, buy_burn_fee + sell_burn_fee);
+
+    // --- execute_pool_buyback_burn -------------------------------------------
+    let pool = pool_after_sell;
+    let burn_fees = buy_burn_fee + sell_burn_fee;
+    let tokens_burned = swap_out(burn_fees, pool.sol_reserves, pool.token_reserves);
+    let supply_before = env.mint_supply(&mint).await;
+    let pool_vault_before = env.lamports(&pool_vault).await;
+
+    env.send(&[ix::execute_pool_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    // The burn fees join the reserves and the tokens they bought are gone
+    assert_eq!(env.mint_supply(&mint).await, supply_before - tokens_burned);
+    assert_eq!(env.token_balance(&pool_token_account).await, pool.token_reserves - tokens_burned);
+    assert_eq!(env.lamports(&pool_vault).await, pool_vault_before);
+
+    let pool_after_burn: Pool = env.account(&pool_key).await;
+    assert_eq!(pool_after_burn.sol_reserves, pool.sol_reserves + burn_fees);
+    assert_eq!(pool_after_burn.token_reserves, pool.token_reserves - tokens_burned);
+    assert_eq!(pool_after_burn.burn_fees_spent, burn_fees);
+    assert_eq!(pool_after_burn.tokens_burned, tokens_burned);
+    assert_eq!(pool_after_burn.pending_burn_fees(), 0);
+    assert_eq!(env.lamports(&pool_vault).await, pool_after_burn.sol_reserves);
+
+    let result = env.send(&[ix::execute_pool_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoBurnFees);
+
+    // --- add_liquidity -------------------------------------------------------
+    let pool = pool_after_burn;
+    let trader_lp_account = get_associated_token_address(&trader.pubkey(), &lp_mint);
+    let deposit_sol = LAMPORTS_PER_SOL / 10;
+    let deposit_tokens = (deposit_sol as u128 * pool.token_reserves as u128).div_ceil(pool.sol_reserves as u128) as u64;
+    let lp_out = (deposit_sol as u128 * pool.lp_supply as u128 / pool.sol_reserves as u128) as u64;
+
+    let result = env
+        .send(&[ix::add_liquidity(&trader.pubkey(), &meme, &spl_token::ID, deposit_sol, deposit_tokens - 1, 0)], &[&trader])
+        .await;
+    assert_program_error(result, ProofOfMemeError::SlippageExceeded);
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let trader_tokens_before = env.token_balance(&trader_token_account).await;
+
+    env.send(&[ix::add_liquidity(&trader.pubkey(), &meme, &spl_token::ID, deposit_sol, deposit_tokens, lp_out)], &[&trader])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        env.lamports(&trader.pubkey()).await,
+        trader_before - deposit_sol - env.rent_for(TOKEN_ACCOUNT_SIZE)
+    );
+    assert_eq!(env.token_balance(&trader_token_account).await, trader_tokens_before - deposit_tokens);
+    assert_eq!(env.token_balance(&trader_lp_account).await, lp_out);
+    assert_eq!(env.mint_supply(&lp_mint).await, lp_out);
+
+    // Deposits keep the reserve ratio and mint LP in proportion
+    let pool_after_add: Pool = env.account(&pool_key).await;
+    assert_eq!(pool_after_add.sol_reserves, pool.sol_reserves + deposit_sol);
+    assert_eq!(pool_after_add.token_reserves, pool.token_reserves + deposit_tokens);
+    assert_eq!(pool_after_add.lp_supply, pool.lp_supply + lp_out);
+    assert!(
+        pool_after_add.token_reserves as u128 * pool.sol_reserves as u128
+            >= pool.token_reserves as u128 * pool_after_add.sol_reserves as u128
+    );
+
+    // --- remove_liquidity ----------------------------------------------------
+    let pool = pool_after_add;
+    let sol_out = (lp_out as u128 * pool.sol_reserves as u128 / pool.lp_supply as u128) as u64;
+    let tokens_back = (lp_out as u128 * pool.token_reserves as u128 / pool.lp_supply as u128) as u64;
+    // Rounding never pays a provider more than they put in
+    assert!(sol_out <= deposit_sol && tokens_back <= deposit_tokens);
+
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    let trader_tokens_before = env.token_balance(&trader_token_account).await;
+
+    env.send(&[ix::remove_liquidity(&trader.pubkey(), &meme, &spl_token::ID, lp_out, sol_out, tokens_back)], &[&trader])
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before + sol_out);
+    assert_eq!(env.token_balance(&trader_token_account).await, trader_tokens_before + tokens_back);
+    assert_eq!(env.token_balance(&trader_lp_account).await, 0);
+
+    let pool_after_remove: Pool = env.account(&pool_key).await;
+    assert_eq!(pool_after_remove.sol_reserves, pool.sol_reserves - sol_out);
+    assert_eq!(pool_after_remove.token_reserves, pool.token_reserves - tokens_back);
+    assert_eq!(pool_after_remove.lp_supply, pool.lp_supply - lp_out);
+
+    // --- locked liquidity stays put ------------------------------------------
+    // Only the locked LP is left and no account holds it, so nothing more can be withdrawn
+    assert_eq!(pool_after_remove.lp_supply, pool_after_remove.locked_lp);
+    assert_eq!(env.mint_supply(&lp_mint).await, 0);
+
+    let result = env
+        .send(&[ix::remove_liquidity(&trader.pubkey(), &meme, &spl_token::ID, 1, 0, 0)], &[&trader])
+        .await;
+    assert_program_error(result, ProofOfMemeError::InsufficientTokens);
+    assert!(pool_after_remove.sol_reserves > 0 && pool_after_remove.token_reserves > 0);
+}
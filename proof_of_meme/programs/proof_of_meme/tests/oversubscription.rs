@@ -0,0 +1,108 @@
+//! sol_cap: an oversubscribed launch takes only the cap into the curve and refunds the
+//! excess to every backer pro rata
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Backing, BondingCurve, GenesisPool, Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = 30;
+const BACKING_AMOUNT: u64 = LAMPORTS_PER_SOL;
+const SOL_CAP: u64 = MIN_SOL_GOAL;
+
+#[tokio::test]
+async fn oversubscribed_launch_refunds_excess() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let vault = vault_pda(&meme);
+    let creator = env.creator.insecure_clone();
+
+    // The cap can't undercut the goal
+    let args = ix::SubmitMeme { sol_cap: MIN_SOL_GOAL - 1, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    let result = env.send(&[ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, args)], &[&creator]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidSolCap);
+
+    let args = ix::SubmitMeme { sol_cap: SOL_CAP, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    env.send(&[ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, args)], &[&creator])
+        .await
+        .unwrap();
+
+    // 30 SOL backed against a 20 SOL cap
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    }
+    let total_backed = BACKING_AMOUNT * BACKER_COUNT as u64;
+    let oversubscribed = total_backed - SOL_CAP;
+    assert_eq!(env.lamports(&vault).await, total_backed + CRANK_REWARD);
+
+    // Nothing to refund before launch
+    let first = env.backers[0].insecure_clone();
+    let result = env.send(&[ix::claim_backing_refund(&first.pubkey(), &meme)], &[&first]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingStillActive);
+
+    let meme_state: Meme = env.account(&meme).await;
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    let cranker = env.cranker.insecure_clone();
+    let cranker_before = env.lamports(&cranker.pubkey()).await;
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await.unwrap();
+
+    // The finalizer also funds the refund vault's rent exemption
+    let vault_rent = env.rent_for(0);
+    let launch_rent = env.rent_for(MINT_SIZE)
+        + env.rent_for(BondingCurve::SIZE)
+        + env.rent_for(GenesisPool::SIZE)
+        + 2 * env.rent_for(TOKEN_ACCOUNT_SIZE)
+        + env.rent_for(mock_token_metadata::METADATA_SIZE)
+        + vault_rent;
+    assert_eq!(env.lamports(&cranker.pubkey()).await, cranker_before + CRANK_REWARD - launch_rent);
+
+    // Only the cap reaches the curve, priced as if exactly the cap was backed
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+    assert_eq!(meme_state.sol_oversubscribed, oversubscribed);
+    assert_eq!(meme_state.sol_accepted(), SOL_CAP);
+    assert_eq!(env.lamports(&vault).await, oversubscribed + vault_rent);
+    assert_eq!(env.lamports(&curve_vault_pda(&meme)).await, SOL_CAP);
+
+    let genesis_tokens = TOTAL_SUPPLY * GENESIS_ALLOCATION_BPS / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.real_sol_reserves, SOL_CAP);
+    assert_eq!(
+        curve.virtual_sol_reserves,
+        (SOL_CAP as u128 * curve_tokens as u128 / genesis_tokens as u128) as u64
+    );
+    assert_eq!(curve.completion_threshold, SOL_CAP + CURVE_COMPLETION_SOL);
+
+    // Every backer reclaims the same share of their backing, once
+    let refund = BACKING_AMOUNT * oversubscribed / total_backed;
+    let mut vault_balance = oversubscribed + vault_rent;
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        let before = env.lamports(&backer.pubkey()).await;
+        env.send(&[ix::claim_backing_refund(&backer.pubkey(), &meme)], &[&backer]).await.unwrap();
+        vault_balance -= refund;
+
+        assert_eq!(env.lamports(&backer.pubkey()).await, before + refund);
+        assert_eq!(env.lamports(&vault).await, vault_balance);
+
+        let backing: Backing = env.account(&backing_pda(&meme, &backer.pubkey())).await;
+        assert_eq!(backing.refunded, refund);
+        assert_eq!(backing.amount, BACKING_AMOUNT);
+    }
+
+    // Rounding dust stays behind with the rent reserve
+    assert_eq!(vault_balance, vault_rent + oversubscribed - refund * BACKER_COUNT as u64);
+
+    let result = env.send(&[ix::claim_backing_refund(&first.pubkey(), &meme)], &[&first]).await;
+    assert_program_error(result, ProofOfMemeError::RefundAlreadyClaimed);
+}
@@ -0,0 +1,168 @@
+//! Pause flags and freezes: each flag stops only its own instruction group, and a
+//! frozen meme stops everything on that meme while the rest of the platform runs
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::Meme;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+const GROUPS: [u8; 5] = [PAUSE_SUBMISSIONS, PAUSE_BACKING, PAUSE_TRADING, PAUSE_CLAIMS, PAUSE_MIGRATION];
+
+/// Run one instruction from `group` against the launched meme #0 or the proving meme #1;
+/// `claimer` picks a backer of meme #0 who has not claimed fees yet
+async fn try_group(env: &mut TestEnv, group: u8, claimer: usize) -> Result<(), BanksClientError> {
+    let launched = meme_pda(0);
+    let proving = meme_pda(1);
+    let fee_recipient = env.fee_recipient.pubkey();
+
+    match group {
+        PAUSE_SUBMISSIONS => {
+            let creator = env.creator.insecure_clone();
+            let index = env.platform().await.total_memes_submitted;
+            let submit_ix = submit_meme_ix(&creator.pubkey(), index, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS);
+            env.send(&[submit_ix], &[&creator]).await
+        }
+        PAUSE_BACKING => {
+            let backer = env.backers[0].insecure_clone();
+            env.send(&[ix::back_meme(&backer.pubkey(), &proving, LAMPORTS_PER_SOL / 10)], &[&backer]).await
+        }
+        PAUSE_TRADING => {
+            let trader = env.trader.insecure_clone();
+            let buy_ix = ix::buy_tokens(&trader.pubkey(), &launched, &spl_token::ID, &fee_recipient, LAMPORTS_PER_SOL / 10, 0);
+            env.send(&[buy_ix], &[&trader]).await
+        }
+        PAUSE_CLAIMS => {
+            let backer = env.backers[claimer].insecure_clone();
+            env.send(&[ix::claim_genesis_fees(&backer.pubkey(), &launched)], &[&backer]).await
+        }
+        PAUSE_MIGRATION => {
+            let cranker = env.cranker.insecure_clone();
+            let pool_accounts = env.cpmm.pool_accounts();
+            env.send(&[ix::migrate_to_raydium(&cranker.pubkey(), &launched, &spl_token::ID, &fee_recipient, &pool_accounts)], &[&cranker])
+                .await
+        }
+        _ => unreachable!("not a pause group"),
+    }
+}
+
+/// Assert how `group`'s probe fared: `Paused` when blocked, otherwise it gets past the gate.
+/// Meme #0's curve is never completed, so an unblocked migration stops at `CurveNotComplete`
+fn assert_group(group: u8, blocked: bool, result: Result<(), BanksClientError>) {
+    if blocked {
+        assert_program_error(result, ProofOfMemeError::Paused);
+    } else if group == PAUSE_MIGRATION {
+        assert_program_error(result, ProofOfMemeError::CurveNotComplete);
+    } else {
+        result.unwrap_or_else(|err| panic!("group {group:#07b} should run: {err}"));
+    }
+}
+
+#[tokio::test]
+async fn pause_flags_and_frozen_memes() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let authority = env.authority.insecure_clone();
+    let outsider = env.cranker.insecure_clone();
+    let trader = env.trader.insecure_clone();
+
+    // Meme #0 trades with genesis fees to claim; meme #1 is still proving with one backer
+    let launched = env.launch_meme(0).await;
+    env.create_ata(&trader.pubkey(), &mint_pda(&launched)).await;
+    let buy_ix = ix::buy_tokens(&trader.pubkey(), &launched, &spl_token::ID, &fee_recipient, LAMPORTS_PER_SOL, 0);
+    env.send(&[buy_ix], &[&trader]).await.unwrap();
+
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 1, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+    let backer = env.backers[0].insecure_clone();
+    env.send(&[ix::back_meme(&backer.pubkey(), &meme_pda(1), MIN_BACKING_AMOUNT)], &[&backer])
+        .await
+        .unwrap();
+
+    let mut claimer = 0;
+
+    // --- set_platform_paused -------------------------------------------------
+    let result = env.send(&[ix::set_platform_paused(&outsider.pubkey(), PAUSE_ALL)], &[&outsider]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    let result = env.send(&[ix::set_platform_paused(&authority.pubkey(), PAUSE_ALL + 1)], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidPauseFlags);
+
+    // --- each flag stops its own group and nothing else ----------------------
+    for paused in GROUPS {
+        env.send(&[ix::set_platform_paused(&authority.pubkey(), paused)], &[&authority]).await.unwrap();
+        assert_eq!(env.platform().await.paused, paused);
+
+        for group in GROUPS {
+            let result = try_group(&mut env, group, claimer).await;
+            if group == PAUSE_CLAIMS && paused != PAUSE_CLAIMS {
+                claimer += 1;
+            }
+            assert_group(group, group == paused, result);
+        }
+    }
+
+    // --- PAUSE_ALL stops everything, clearing the mask resumes ---------------
+    env.send(&[ix::set_platform_paused(&authority.pubkey(), PAUSE_ALL)], &[&authority]).await.unwrap();
+    for group in GROUPS {
+        let result = try_group(&mut env, group, claimer).await;
+        assert_group(group, true, result);
+    }
+
+    env.send(&[ix::set_platform_paused(&authority.pubkey(), 0)], &[&authority]).await.unwrap();
+    assert_eq!(env.platform().await.paused, 0);
+    for group in GROUPS {
+        let result = try_group(&mut env, group, claimer).await;
+        assert_group(group, false, result);
+    }
+    claimer += 1;
+
+    // --- set_meme_frozen -----------------------------------------------------
+    let result = env.send(&[ix::set_meme_frozen(&outsider.pubkey(), &launched, true)], &[&outsider]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    env.send(&[ix::set_meme_frozen(&authority.pubkey(), &launched, true)], &[&authority]).await.unwrap();
+    let meme: Meme = env.account(&launched).await;
+    assert!(meme.frozen);
+
+    // Trading and claims on the frozen meme stop
+    let result = try_group(&mut env, PAUSE_TRADING, claimer).await;
+    assert_program_error(result, ProofOfMemeError::Paused);
+
+    let sell_ix = ix::sell_tokens(&trader.pubkey(), &launched, &spl_token::ID, &fee_recipient, 1_000, 0);
+    let result = env.send(&[sell_ix], &[&trader]).await;
+    assert_program_error(result, ProofOfMemeError::Paused);
+
+    let result = try_group(&mut env, PAUSE_CLAIMS, claimer).await;
+    assert_program_error(result, ProofOfMemeError::Paused);
+
+    let claimant = env.backers[claimer].insecure_clone();
+    let claim_tokens_ix = ix::claim_genesis_tokens(&claimant.pubkey(), &launched, &spl_token::ID);
+    let result = env.send(std::slice::from_ref(&claim_tokens_ix), &[&claimant]).await;
+    assert_program_error(result, ProofOfMemeError::Paused);
+
+    // Other memes carry on
+    let result = try_group(&mut env, PAUSE_BACKING, claimer).await;
+    assert_group(PAUSE_BACKING, false, result);
+
+    // --- unfreeze ------------------------------------------------------------
+    env.send(&[ix::set_meme_frozen(&authority.pubkey(), &launched, false)], &[&authority]).await.unwrap();
+    let meme: Meme = env.account(&launched).await;
+    assert!(!meme.frozen);
+
+    let result = try_group(&mut env, PAUSE_TRADING, claimer).await;
+    assert_group(PAUSE_TRADING, false, result);
+    let result = try_group(&mut env, PAUSE_CLAIMS, claimer).await;
+    assert_group(PAUSE_CLAIMS, false, result);
+    env.send(&[claim_tokens_ix], &[&claimant]).await.unwrap();
+}
@@ -0,0 +1,99 @@
+//! Authority handoff: a proposed authority takes over only once it accepts, and the
+//! authority can redirect platform fees to a new recipient
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn authority_handoff_and_fee_recipient() {
+    let mut env = TestEnv::new(0).await;
+    env.initialize_platform().await;
+
+    let old_authority = env.authority.insecure_clone();
+    let new_authority = Keypair::new();
+    let impostor = env.cranker.insecure_clone();
+
+    // --- propose -------------------------------------------------------------
+    let result = env.send(&[ix::accept_authority(&new_authority.pubkey())], &[&new_authority]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingAuthority);
+
+    let result = env.send(&[ix::propose_authority(&impostor.pubkey(), &impostor.pubkey())], &[&impostor]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    env.send(&[ix::propose_authority(&old_authority.pubkey(), &new_authority.pubkey())], &[&old_authority])
+        .await
+        .unwrap();
+    let platform = env.platform().await;
+    assert_eq!(platform.authority, old_authority.pubkey());
+    assert_eq!(platform.pending_authority, new_authority.pubkey());
+
+    // The old authority keeps control until the nominee accepts
+    let result = env.send(&[ix::set_platform_paused(&new_authority.pubkey(), PAUSE_ALL)], &[&new_authority]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+    let result = env
+        .send(&[ix::set_fee_recipient(&new_authority.pubkey(), &new_authority.pubkey())], &[&new_authority])
+        .await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    let interim_recipient = Pubkey::new_unique();
+    env.send(&[ix::set_fee_recipient(&old_authority.pubkey(), &interim_recipient)], &[&old_authority])
+        .await
+        .unwrap();
+    assert_eq!(env.platform().await.fee_recipient, interim_recipient);
+
+    // --- accept --------------------------------------------------------------
+    // Only the nominee can accept
+    let result = env.send(&[ix::accept_authority(&impostor.pubkey())], &[&impostor]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    // Proposing the default key withdraws the nomination
+    env.send(&[ix::propose_authority(&old_authority.pubkey(), &Pubkey::default())], &[&old_authority])
+        .await
+        .unwrap();
+    let result = env.send(&[ix::accept_authority(&new_authority.pubkey())], &[&new_authority]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingAuthority);
+
+    env.send(&[ix::propose_authority(&old_authority.pubkey(), &new_authority.pubkey())], &[&old_authority])
+        .await
+        .unwrap();
+    env.send(&[ix::accept_authority(&new_authority.pubkey())], &[&new_authority]).await.unwrap();
+
+    let platform = env.platform().await;
+    assert_eq!(platform.authority, new_authority.pubkey());
+    assert_eq!(platform.pending_authority, Pubkey::default());
+
+    // The old authority is locked out
+    let result = env
+        .send(&[ix::set_fee_recipient(&old_authority.pubkey(), &old_authority.pubkey())], &[&old_authority])
+        .await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+    let result = env
+        .send(&[ix::propose_authority(&old_authority.pubkey(), &old_authority.pubkey())], &[&old_authority])
+        .await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    // --- set_fee_recipient ---------------------------------------------------
+    let treasury = Pubkey::new_unique();
+    env.send(&[ix::set_fee_recipient(&new_authority.pubkey(), &treasury)], &[&new_authority])
+        .await
+        .unwrap();
+    assert_eq!(env.platform().await.fee_recipient, treasury);
+
+    // Fees can only be paid to the new recipient
+    let creator = env.creator.insecure_clone();
+    let result = env
+        .send(&[submit_meme_ix(&creator.pubkey(), 0, &interim_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await;
+    assert_program_error(result, ProofOfMemeError::InvalidFeeRecipient);
+
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &treasury, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.lamports(&treasury).await, SUBMISSION_FEE - CRANK_REWARD);
+    assert_eq!(env.lamports(&interim_recipient).await, 0);
+}
@@ -0,0 +1,125 @@
+//! Config timelock: with a timelock set, platform config changes queue until their ETA,
+//! apply once it passes and can be cancelled; without one they apply immediately
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::CurveBounds;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+const TIMELOCK: i64 = 24 * 60 * 60;
+
+/// The test fee schedule with a different submission fee and timelock
+fn update_config_ix(authority: &Pubkey, submission_fee: u64, config_timelock_seconds: i64) -> Instruction {
+    ix::update_platform_config(
+        authority,
+        submission_fee,
+        PLATFORM_FEE_BPS,
+        GENESIS_FEE_BPS,
+        BURN_FEE_BPS,
+        CRANK_REWARD,
+        DEFAULT_LAUNCH_WINDOW,
+        UNBACK_PENALTY_BPS,
+        config_timelock_seconds,
+        CurveBounds::DEFAULT,
+    )
+}
+
+#[tokio::test]
+async fn config_changes_wait_out_the_timelock() {
+    let mut env = TestEnv::new(0).await;
+    env.initialize_platform().await;
+
+    let authority = env.authority.insecure_clone();
+    let cranker = env.cranker.insecure_clone();
+
+    // --- no timelock: changes apply immediately ------------------------------
+    let platform = env.platform().await;
+    assert_eq!(platform.config_timelock_seconds, 0);
+
+    let result = env.send(&[update_config_ix(&cranker.pubkey(), 2 * SUBMISSION_FEE, 0)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    env.send(&[update_config_ix(&authority.pubkey(), 2 * SUBMISSION_FEE, 0)], &[&authority]).await.unwrap();
+    let platform = env.platform().await;
+    assert_eq!(platform.submission_fee, 2 * SUBMISSION_FEE);
+    assert!(!platform.has_pending_config());
+
+    let result = env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingConfig);
+
+    // Turning the timelock on is itself immediate while none is set
+    env.send(&[update_config_ix(&authority.pubkey(), 2 * SUBMISSION_FEE, TIMELOCK)], &[&authority]).await.unwrap();
+    assert_eq!(env.platform().await.config_timelock_seconds, TIMELOCK);
+
+    // --- queued change: rejected before its ETA, applied after ---------------
+    let queued_at = env.now().await;
+    env.send(&[update_config_ix(&authority.pubkey(), 3 * SUBMISSION_FEE, TIMELOCK)], &[&authority]).await.unwrap();
+
+    let platform = env.platform().await;
+    assert_eq!(platform.submission_fee, 2 * SUBMISSION_FEE);
+    assert_eq!(platform.pending_submission_fee, 3 * SUBMISSION_FEE);
+    assert_eq!(platform.pending_config_activates_at, queued_at + TIMELOCK);
+
+    let result = env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ConfigTimelockActive);
+
+    env.warp_to_timestamp(queued_at + TIMELOCK - 1).await;
+    let result = env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::ConfigTimelockActive);
+    assert_eq!(env.platform().await.submission_fee, 2 * SUBMISSION_FEE);
+
+    // Anyone can apply once the ETA passes
+    env.warp_to_timestamp(queued_at + TIMELOCK).await;
+    env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await.unwrap();
+
+    let platform = env.platform().await;
+    assert_eq!(platform.submission_fee, 3 * SUBMISSION_FEE);
+    assert!(!platform.has_pending_config());
+
+    let result = env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingConfig);
+
+    // --- cancel --------------------------------------------------------------
+    env.send(&[update_config_ix(&authority.pubkey(), 4 * SUBMISSION_FEE, TIMELOCK)], &[&authority]).await.unwrap();
+    assert!(env.platform().await.has_pending_config());
+
+    let result = env.send(&[ix::cancel_platform_config_update(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    env.send(&[ix::cancel_platform_config_update(&authority.pubkey())], &[&authority]).await.unwrap();
+    let platform = env.platform().await;
+    assert!(!platform.has_pending_config());
+    assert_eq!(platform.submission_fee, 3 * SUBMISSION_FEE);
+
+    // A cancelled change can't be applied later
+    let now = env.now().await;
+    env.warp_to_timestamp(now + TIMELOCK).await;
+    let result = env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingConfig);
+    assert_eq!(env.platform().await.submission_fee, 3 * SUBMISSION_FEE);
+
+    let result = env.send(&[ix::cancel_platform_config_update(&authority.pubkey())], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::NoPendingConfig);
+
+    // --- dropping the timelock back to zero ----------------------------------
+    // Removing the timelock waits out the current one, then later changes are immediate again
+    let queued_at = env.now().await;
+    env.send(&[update_config_ix(&authority.pubkey(), SUBMISSION_FEE, 0)], &[&authority]).await.unwrap();
+    assert_eq!(env.platform().await.config_timelock_seconds, TIMELOCK);
+
+    env.warp_to_timestamp(queued_at + TIMELOCK).await;
+    env.send(&[ix::apply_platform_config(&cranker.pubkey())], &[&cranker]).await.unwrap();
+    let platform = env.platform().await;
+    assert_eq!(platform.config_timelock_seconds, 0);
+    assert_eq!(platform.submission_fee, SUBMISSION_FEE);
+
+    env.send(&[update_config_ix(&authority.pubkey(), 2 * SUBMISSION_FEE, 0)], &[&authority]).await.unwrap();
+    let platform = env.platform().await;
+    assert_eq!(platform.submission_fee, 2 * SUBMISSION_FEE);
+    assert!(!platform.has_pending_config());
+}
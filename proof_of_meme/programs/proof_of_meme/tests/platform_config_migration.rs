@@ -0,0 +1,98 @@
+//! migrate_platform_config: a platform config on the original layout (fields through
+//! bump plus 64 bytes of padding) grows to the current one, keeping its values
+
+mod common;
+
+use anchor_lang::Discriminator;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{CurveBounds, Meme, MemeStatus, PlatformConfig};
+use proof_of_meme_client::pda;
+use solana_sdk::account::Account;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+const MEMES_SUBMITTED: u64 = 3;
+const MEMES_LAUNCHED: u64 = 1;
+const PLATFORM_FEES: u64 = 5 * LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn legacy_platform_config_migrates() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+    let authority = env.authority.insecure_clone();
+    let outsider = env.cranker.insecure_clone();
+    let (platform, bump) = pda::find_platform_address();
+
+    // --- legacy layout -------------------------------------------------------
+    let mut data = PlatformConfig::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&authority.pubkey().to_bytes());
+    data.extend_from_slice(&SUBMISSION_FEE.to_le_bytes());
+    data.extend_from_slice(&PLATFORM_FEE_BPS.to_le_bytes());
+    data.extend_from_slice(&GENESIS_FEE_BPS.to_le_bytes());
+    data.extend_from_slice(&BURN_FEE_BPS.to_le_bytes());
+    data.extend_from_slice(&MEMES_SUBMITTED.to_le_bytes());
+    data.extend_from_slice(&MEMES_LAUNCHED.to_le_bytes());
+    data.extend_from_slice(&PLATFORM_FEES.to_le_bytes());
+    data.push(bump);
+    data.resize(PlatformConfig::LEGACY_SIZE, 0);
+
+    env.ctx.set_account(&platform, &Account {
+        lamports: env.rent_for(PlatformConfig::LEGACY_SIZE),
+        data,
+        owner: proof_of_meme::ID,
+        executable: false,
+        rent_epoch: 0,
+    }.into());
+
+    // Too short for the current layout, so nothing reading the platform works
+    let result = env.send(&[update_curve_bounds_ix(&authority.pubkey(), CurveBounds::DEFAULT)], &[&authority]).await;
+    assert!(result.is_err());
+
+    // --- migrate_platform_config ---------------------------------------------
+    let result = env.send(&[ix::migrate_platform_config(&outsider.pubkey())], &[&outsider]).await;
+    assert_program_error(result, ProofOfMemeError::Unauthorized);
+
+    env.send(&[ix::migrate_platform_config(&authority.pubkey())], &[&authority]).await.unwrap();
+
+    let account = env.ctx.banks_client.get_account(platform).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), PlatformConfig::SIZE);
+    assert_eq!(account.lamports, env.rent_for(PlatformConfig::SIZE));
+
+    // Legacy values survive; the added fields get initialize_platform's defaults
+    let config = env.platform().await;
+    assert_eq!(config.authority, authority.pubkey());
+    assert_eq!(config.submission_fee, SUBMISSION_FEE);
+    assert_eq!(config.platform_fee_bps, PLATFORM_FEE_BPS);
+    assert_eq!(config.genesis_fee_bps, GENESIS_FEE_BPS);
+    assert_eq!(config.burn_fee_bps, BURN_FEE_BPS);
+    assert_eq!(config.total_memes_submitted, MEMES_SUBMITTED);
+    assert_eq!(config.total_memes_launched, MEMES_LAUNCHED);
+    assert_eq!(config.total_platform_fees, PLATFORM_FEES);
+    assert_eq!(config.bump, bump);
+    assert_eq!(config.fee_recipient, authority.pubkey());
+    assert_eq!(config.launch_window_seconds, DEFAULT_LAUNCH_WINDOW);
+    assert_eq!(config.curve_bounds, CurveBounds::DEFAULT);
+    assert_eq!(config.paused, 0);
+    assert_eq!(config.crank_reward, 0);
+    assert!(!config.has_pending_config());
+
+    // Only runs once
+    let result = env.send(&[ix::migrate_platform_config(&authority.pubkey())], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::PlatformConfigCurrent);
+
+    // --- after migration -----------------------------------------------------
+    // The migrated config takes the usual setup and runs a meme through launch
+    env.send(&[update_curve_bounds_ix(&authority.pubkey(), CurveBounds::DEFAULT)], &[&authority]).await.unwrap();
+    env.send(&[ix::set_fee_recipient(&authority.pubkey(), &env.fee_recipient.pubkey())], &[&authority])
+        .await
+        .unwrap();
+
+    let meme = env.launch_meme(MEMES_SUBMITTED).await;
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Launched);
+    let config = env.platform().await;
+    assert_eq!(config.total_memes_submitted, MEMES_SUBMITTED + 1);
+    assert_eq!(config.total_memes_launched, MEMES_LAUNCHED + 1);
+}
@@ -0,0 +1,67 @@
+//! Migration against the real Raydium CPMM program rather than the mock, so a wrong
+//! `initialize` discriminator or account order fails here. Needs the mainnet fixtures:
+//!
+//!     scripts/fetch-fixtures.sh && cargo test --test raydium_migration -- --ignored
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::raydium::RAYDIUM_CPMM_PROGRAM_ID;
+use proof_of_meme::state::{CurveStatus, Meme, MemeStatus};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+#[tokio::test]
+#[ignore = "needs tests/fixtures from scripts/fetch-fixtures.sh"]
+async fn migrates_into_real_cpmm_pool() {
+    let mut env = TestEnv::with_real_programs(BACKER_COUNT).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = env.launch_meme(0).await;
+    env.complete_curve(&meme).await;
+    let cranker = env.cranker.insecure_clone();
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    let mint = mint_pda(&meme);
+    let curve_token_account = get_associated_token_address(&curve_pda(&meme), &mint);
+    let curve = env.curve(&meme).await;
+    // The deposit is sized off the mainnet AMM config's create fee
+    let create_pool_fee = env.cpmm_create_pool_fee().await;
+    let pool_sol = curve.real_sol_reserves - MIGRATION_FEE - create_pool_fee - RAYDIUM_POOL_RENT;
+    let pool_tokens = env.token_balance(&curve_token_account).await;
+    let cpmm_fee_account = env.cpmm.create_pool_fee;
+    let fee_account_before = env.lamports(&cpmm_fee_account).await;
+
+    // --- migrate_to_raydium --------------------------------------------------
+    let migrate_ix = ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    env.send(&[migrate_ix], &[&cranker]).await.unwrap();
+
+    // CPMM created the pool and took the whole deposit into its vaults
+    assert_eq!(env.lamports(&cpmm_fee_account).await, fee_account_before + create_pool_fee);
+    let pool_state = env.cpmm.pool_state;
+    let pool_account = env.ctx.banks_client.get_account(pool_state).await.unwrap().expect("pool state not created");
+    assert_eq!(pool_account.owner, RAYDIUM_CPMM_PROGRAM_ID);
+
+    let cpmm_token_vault = env.cpmm.token_vault;
+    let cpmm_wsol_vault = env.cpmm.wsol_vault;
+    assert_eq!(env.token_balance(&cpmm_token_vault).await, pool_tokens);
+    assert_eq!(env.token_balance(&cpmm_wsol_vault).await, pool_sol);
+    assert_eq!(env.token_balance(&curve_token_account).await, 0);
+
+    // The LP handed to the curve vault was burned
+    let cpmm_lp_account = env.cpmm.creator_lp_account;
+    assert_eq!(env.token_balance(&cpmm_lp_account).await, 0);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Migrated);
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Migrated);
+    assert_eq!(curve.migration_pool, pool_state);
+}
@@ -0,0 +1,165 @@
+//! Token-2022 launches: native metadata on the mint, then trading, genesis claims and
+//! migration through the same code paths as SPL Token memes
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{CurveStatus, GenesisPool, Meme, MemeStatus, TokenProgram};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+#[tokio::test]
+async fn token_2022_launch_trades_and_migrates() {
+    let mut env = TestEnv::with_token_program(BACKER_COUNT, TokenProgram::Token2022).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    let meme = meme_pda(0);
+    let creator = env.creator.insecure_clone();
+    let args = ix::SubmitMeme { token_program: TokenProgram::Token2022, ..submit_meme_args(MIN_SOL_GOAL, MIN_BACKERS) };
+    env.send(&[ix::submit_meme(&creator.pubkey(), 0, &fee_recipient, args)], &[&creator])
+        .await
+        .unwrap();
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.token_program, TokenProgram::Token2022);
+
+    for i in 0..BACKER_COUNT {
+        let backer = env.backers[i].insecure_clone();
+        env.send(&[ix::back_meme(&backer.pubkey(), &meme, LAMPORTS_PER_SOL)], &[&backer]).await.unwrap();
+    }
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+
+    // The meme picked its token program at submission
+    let cranker = env.cranker.insecure_clone();
+    let result = env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token::ID)], &[&cranker]).await;
+    assert_program_error(result, ProofOfMemeError::AccountMismatch);
+
+    env.send(&[ix::finalize_proving(&cranker.pubkey(), &meme, &spl_token_2022::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    // --- mint and metadata ---------------------------------------------------
+    let mint = mint_pda(&meme);
+    let mint_account = env.ctx.banks_client.get_account(mint).await.unwrap().unwrap();
+    assert_eq!(mint_account.owner, spl_token_2022::ID);
+
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint_state.base.supply, TOTAL_SUPPLY);
+    assert_eq!(mint_state.base.decimals, TOKEN_DECIMALS);
+    assert!(proof_of_meme_client::accounts::is_fixed_supply(&mint_state.base));
+
+    // The mint points at itself for metadata, and nobody can repoint it
+    let pointer = mint_state.get_extension::<MetadataPointer>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(pointer.metadata_address), Some(mint));
+    assert_eq!(Option::<Pubkey>::from(pointer.authority), None);
+
+    // Same pitch as the meme, and with no update authority it can never change
+    let metadata = mint_state.get_variable_len_extension::<TokenMetadata>().unwrap();
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(metadata.name, meme_state.get_name());
+    assert_eq!(metadata.symbol, meme_state.get_symbol());
+    assert_eq!(metadata.uri, meme_state.get_uri());
+    assert_eq!(Option::<Pubkey>::from(metadata.update_authority), None);
+    assert!(metadata.additional_metadata.is_empty());
+
+    // No Metaplex account for Token-2022 memes
+    let (metaplex_metadata, _) = Metadata::find_pda(&mint);
+    assert!(env.ctx.banks_client.get_account(metaplex_metadata).await.unwrap().is_none());
+
+    let genesis_tokens = TOTAL_SUPPLY * GENESIS_ALLOCATION_BPS / BPS_DENOMINATOR;
+    let curve_tokens = TOTAL_SUPPLY - genesis_tokens;
+    let curve_token_account = get_associated_token_address_with_program_id(&curve_pda(&meme), &mint, &spl_token_2022::ID);
+    let genesis_token_account =
+        get_associated_token_address_with_program_id(&genesis_pool_pda(&meme), &mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&curve_token_account).await, curve_tokens);
+    assert_eq!(env.token_balance(&genesis_token_account).await, genesis_tokens);
+
+    // --- buy and sell --------------------------------------------------------
+    let trader = env.trader.insecure_clone();
+    let trader_token_account = env.create_ata(&trader.pubkey(), &mint).await;
+
+    // Token accounts must belong to the meme's token program
+    let result = env
+        .send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token::ID, &fee_recipient, LAMPORTS_PER_SOL, 0)], &[&trader])
+        .await;
+    assert!(result.is_err());
+
+    let curve = env.curve(&meme).await;
+    let buy_amount = 2 * LAMPORTS_PER_SOL;
+    let tokens_out = expected_buy_tokens(&curve, buy_amount - trading_fee(buy_amount));
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token_2022::ID, &fee_recipient, buy_amount, tokens_out)], &[&trader])
+        .await
+        .unwrap();
+    assert_eq!(env.token_balance(&trader_token_account).await, tokens_out);
+    assert_eq!(env.token_balance(&curve_token_account).await, curve_tokens - tokens_out);
+
+    let curve = env.curve(&meme).await;
+    let sell_amount = tokens_out / 2;
+    let sol_out_gross = expected_sell_sol(&curve, sell_amount);
+    let sol_out_net = sol_out_gross - trading_fee(sol_out_gross);
+    let trader_before = env.lamports(&trader.pubkey()).await;
+    env.send(&[ix::sell_tokens(&trader.pubkey(), &meme, &spl_token_2022::ID, &fee_recipient, sell_amount, sol_out_net)], &[&trader])
+        .await
+        .unwrap();
+    assert_eq!(env.lamports(&trader.pubkey()).await, trader_before + sol_out_net);
+    assert_eq!(env.token_balance(&trader_token_account).await, tokens_out - sell_amount);
+
+    // --- claim_genesis_tokens ------------------------------------------------
+    let backer = env.backers[0].insecure_clone();
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_pda(&meme)).await;
+    let share = genesis_pool.calculate_token_share(LAMPORTS_PER_SOL).unwrap();
+    env.send(&[ix::claim_genesis_tokens(&backer.pubkey(), &meme, &spl_token_2022::ID)], &[&backer])
+        .await
+        .unwrap();
+
+    let backer_token_account =
+        get_associated_token_address_with_program_id(&backer.pubkey(), &mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&backer_token_account).await, share);
+    assert_eq!(env.token_balance(&genesis_token_account).await, genesis_tokens - share);
+
+    // --- complete and migrate ------------------------------------------------
+    let curve = env.curve(&meme).await;
+    let remaining = curve.completion_threshold - curve.real_sol_reserves;
+    let completing_buy = (remaining * BPS_DENOMINATOR).div_ceil(BPS_DENOMINATOR - TRADING_FEE_BPS) + 1;
+    env.send(&[ix::buy_tokens(&trader.pubkey(), &meme, &spl_token_2022::ID, &fee_recipient, completing_buy, 0)], &[&trader])
+        .await
+        .unwrap();
+
+    // Unspent burn fees block migration, so buy back and burn first
+    env.send(&[ix::execute_buyback_burn(&cranker.pubkey(), &meme, &spl_token_2022::ID)], &[&cranker])
+        .await
+        .unwrap();
+
+    let curve = env.curve(&meme).await;
+    assert_eq!(curve.status, CurveStatus::Complete);
+    let pool_sol = curve.real_sol_reserves - MIGRATION_FEE - mock_cpmm::CREATE_POOL_FEE - RAYDIUM_POOL_RENT;
+    let pool_tokens = env.token_balance(&curve_token_account).await;
+
+    // The meme side moves under Token-2022; wSOL and the LP mint stay on SPL Token
+    let migrate_ix =
+        ix::migrate_to_raydium(&cranker.pubkey(), &meme, &spl_token_2022::ID, &fee_recipient, &env.cpmm.pool_accounts());
+    env.send(&[migrate_ix], &[&cranker]).await.unwrap();
+
+    let cpmm_token_vault = env.cpmm.token_vault;
+    let cpmm_wsol_vault = env.cpmm.wsol_vault;
+    let cpmm_lp_mint = env.cpmm.lp_mint;
+    assert_eq!(env.token_balance(&curve_token_account).await, 0);
+    assert_eq!(env.token_balance(&cpmm_token_vault).await, pool_tokens);
+    assert_eq!(env.token_balance(&cpmm_wsol_vault).await, pool_sol);
+    assert_eq!(env.mint_supply(&cpmm_lp_mint).await, 0);
+
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.status, MemeStatus::Migrated);
+}
@@ -0,0 +1,107 @@
+//! Unbacking during proving: partial and full exits, the platform's exit penalty,
+//! and the meme totals it unwinds
+
+mod common;
+
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Backing, CurveBounds, Meme};
+use solana_sdk::signature::Signer;
+
+const BACKING_AMOUNT: u64 = 2 * LAMPORTS_PER_SOL;
+
+fn penalty(amount: u64) -> u64 {
+    amount * UNBACK_PENALTY_BPS as u64 / BPS_DENOMINATOR
+}
+
+#[tokio::test]
+async fn backers_unback_during_proving() {
+    let mut env = TestEnv::new(2).await;
+    let fee_recipient = env.fee_recipient.pubkey();
+    env.initialize_platform().await;
+
+    // The penalty is capped
+    let authority = env.authority.insecure_clone();
+    let update = ix::update_platform_config(
+        &authority.pubkey(),
+        SUBMISSION_FEE,
+        PLATFORM_FEE_BPS,
+        GENESIS_FEE_BPS,
+        BURN_FEE_BPS,
+        CRANK_REWARD,
+        DEFAULT_LAUNCH_WINDOW,
+        MAX_UNBACK_PENALTY_BPS + 1,
+        0,
+        CurveBounds::DEFAULT,
+    );
+    let result = env.send(&[update], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidUnbackPenalty);
+
+    let meme = meme_pda(0);
+    let vault = vault_pda(&meme);
+    let creator = env.creator.insecure_clone();
+    env.send(&[submit_meme_ix(&creator.pubkey(), 0, &fee_recipient, MIN_SOL_GOAL, MIN_BACKERS)], &[&creator])
+        .await
+        .unwrap();
+
+    let backer = env.backers[0].insecure_clone();
+    for wallet in [&creator, &backer] {
+        env.send(&[ix::back_meme(&wallet.pubkey(), &meme, BACKING_AMOUNT)], &[wallet]).await.unwrap();
+    }
+    let total_backed = 2 * BACKING_AMOUNT;
+
+    let result = env.send(&[ix::unback_meme(&backer.pubkey(), &meme, &fee_recipient, 0)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::ZeroAmount);
+    let result = env.send(&[ix::unback_meme(&backer.pubkey(), &meme, &fee_recipient, BACKING_AMOUNT + 1)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::UnbackExceedsBacking);
+
+    // What stays behind must still meet the backing minimum
+    let too_much = BACKING_AMOUNT - MIN_BACKING_AMOUNT + 1;
+    let result = env.send(&[ix::unback_meme(&backer.pubkey(), &meme, &fee_recipient, too_much)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::BackingTooLow);
+
+    // Partial exit by the creator: backer keeps a reduced position, platform keeps the penalty
+    let partial = LAMPORTS_PER_SOL / 2;
+    let creator_before = env.lamports(&creator.pubkey()).await;
+    let fee_recipient_before = env.lamports(&fee_recipient).await;
+    let platform_fees_before = env.platform().await.total_platform_fees;
+    env.send(&[ix::unback_meme(&creator.pubkey(), &meme, &fee_recipient, partial)], &[&creator]).await.unwrap();
+
+    assert_eq!(env.lamports(&creator.pubkey()).await, creator_before + partial - penalty(partial));
+    assert_eq!(env.lamports(&fee_recipient).await, fee_recipient_before + penalty(partial));
+    assert_eq!(env.platform().await.total_platform_fees, platform_fees_before + penalty(partial));
+    assert_eq!(env.lamports(&vault).await, total_backed - partial + CRANK_REWARD);
+
+    let backing: Backing = env.account(&backing_pda(&meme, &creator.pubkey())).await;
+    assert_eq!(backing.amount, BACKING_AMOUNT - partial);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.sol_backed, total_backed - partial);
+    assert_eq!(meme_state.creator_backing, BACKING_AMOUNT - partial);
+    assert_eq!(meme_state.backer_count, 2);
+
+    // Full exit closes the backing record and returns its rent
+    let backer_before = env.lamports(&backer.pubkey()).await;
+    env.send(&[ix::unback_meme(&backer.pubkey(), &meme, &fee_recipient, BACKING_AMOUNT)], &[&backer]).await.unwrap();
+
+    assert_eq!(
+        env.lamports(&backer.pubkey()).await,
+        backer_before + BACKING_AMOUNT - penalty(BACKING_AMOUNT) + env.rent_for(Backing::SIZE)
+    );
+    assert_eq!(env.lamports(&backing_pda(&meme, &backer.pubkey())).await, 0);
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.sol_backed, BACKING_AMOUNT - partial);
+    assert_eq!(meme_state.backer_count, 1);
+    assert_eq!(env.lamports(&vault).await, BACKING_AMOUNT - partial + CRANK_REWARD);
+
+    // Backing again counts as a new backer
+    env.send(&[ix::back_meme(&backer.pubkey(), &meme, MIN_BACKING_AMOUNT)], &[&backer]).await.unwrap();
+    let meme_state: Meme = env.account(&meme).await;
+    assert_eq!(meme_state.backer_count, 2);
+    assert_eq!(meme_state.sol_backed, BACKING_AMOUNT - partial + MIN_BACKING_AMOUNT);
+
+    // Locked in once proving ends
+    env.warp_to_timestamp(meme_state.proving_ends_at).await;
+    let result = env.send(&[ix::unback_meme(&backer.pubkey(), &meme, &fee_recipient, MIN_BACKING_AMOUNT)], &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::ProvingEnded);
+}
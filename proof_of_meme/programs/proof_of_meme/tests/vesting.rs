@@ -0,0 +1,108 @@
+//! Genesis vesting: nothing unlocks before the cliff, then claim_vested releases the
+//! allocation linearly until the duration ends
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use proof_of_meme::constants::*;
+use proof_of_meme::errors::ProofOfMemeError;
+use proof_of_meme::state::{Backing, GenesisPool};
+use solana_sdk::signature::Signer;
+
+const BACKER_COUNT: usize = MIN_BACKERS as usize;
+
+const DAY: i64 = 24 * 60 * 60;
+const CLIFF: i64 = 7 * DAY;
+const DURATION: i64 = 30 * DAY;
+
+#[tokio::test]
+async fn genesis_tokens_vest_after_cliff() {
+    let mut env = TestEnv::new(BACKER_COUNT).await;
+
+    // A cliff past the unlock duration is rejected
+    let authority = env.authority.insecure_clone();
+    let result = env.send(&[initialize_platform_ix(&authority.pubkey(), DURATION + 1, DURATION)], &[&authority]).await;
+    assert_program_error(result, ProofOfMemeError::InvalidVestingSchedule);
+
+    env.initialize_platform_with_vesting(CLIFF, DURATION).await;
+    let meme = env.launch_meme(0).await;
+    let mint = mint_pda(&meme);
+    let genesis_pool_address = genesis_pool_pda(&meme);
+
+    // The platform schedule is snapshotted at launch
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_address).await;
+    let start = genesis_pool.vesting_start;
+    assert_eq!(start, env.now().await);
+    assert_eq!(genesis_pool.vesting_cliff_seconds, CLIFF);
+    assert_eq!(genesis_pool.vesting_duration_seconds, DURATION);
+
+    let backer = env.backers[0].insecure_clone();
+    let backing_address = backing_pda(&meme, &backer.pubkey());
+    let backer_tokens = env.create_ata(&backer.pubkey(), &mint).await;
+    let claim_vested_ix = ix::claim_vested(&backer.pubkey(), &meme, &spl_token::ID);
+
+    // Nothing to vest until the allocation is recorded
+    let result = env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::GenesisTokensNotAllocated);
+
+    // --- before the cliff ----------------------------------------------------
+    env.send(&[ix::claim_genesis_tokens(&backer.pubkey(), &meme, &spl_token::ID)], &[&backer])
+        .await
+        .unwrap();
+
+    let allocation = genesis_pool.total_tokens / BACKER_COUNT as u64;
+    let backing: Backing = env.account(&backing_address).await;
+    assert_eq!(backing.tokens_received, allocation);
+    assert_eq!(backing.tokens_claimed, 0);
+    assert_eq!(env.token_balance(&backer_tokens).await, 0);
+
+    env.warp_to_timestamp(start + CLIFF - 1).await;
+    let result = env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::NoTokensToClaim);
+
+    // --- linear unlock -------------------------------------------------------
+    let mut claimed = 0;
+    for elapsed in [CLIFF, DURATION / 2, DURATION - 1] {
+        env.warp_to_timestamp(start + elapsed).await;
+        env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await.unwrap();
+
+        let vested = (allocation as u128 * elapsed as u128 / DURATION as u128) as u64;
+        assert!(vested > claimed);
+        claimed = vested;
+        assert_eq!(env.token_balance(&backer_tokens).await, vested);
+        let backing: Backing = env.account(&backing_address).await;
+        assert_eq!(backing.tokens_claimed, vested);
+
+        // Each unlock is released once
+        let result = env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await;
+        assert_program_error(result, ProofOfMemeError::NoTokensToClaim);
+    }
+
+    // --- fully vested --------------------------------------------------------
+    env.warp_to_timestamp(start + DURATION).await;
+    env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await.unwrap();
+    assert_eq!(env.token_balance(&backer_tokens).await, allocation);
+    let backing: Backing = env.account(&backing_address).await;
+    assert_eq!(backing.tokens_claimed, allocation);
+
+    env.warp_to_timestamp(start + 2 * DURATION).await;
+    let result = env.send(std::slice::from_ref(&claim_vested_ix), &[&backer]).await;
+    assert_program_error(result, ProofOfMemeError::NoTokensToClaim);
+
+    // A backer allocating after the schedule ends gets everything at once
+    let late = env.backers[1].insecure_clone();
+    let late_tokens = env.create_ata(&late.pubkey(), &mint).await;
+    env.send(&[ix::claim_genesis_tokens(&late.pubkey(), &meme, &spl_token::ID)], &[&late])
+        .await
+        .unwrap();
+    assert_eq!(env.token_balance(&late_tokens).await, allocation);
+    let result = env.send(&[ix::claim_vested(&late.pubkey(), &meme, &spl_token::ID)], &[&late]).await;
+    assert_program_error(result, ProofOfMemeError::NoTokensToClaim);
+
+    let genesis_pool: GenesisPool = env.account(&genesis_pool_address).await;
+    assert_eq!(genesis_pool.tokens_distributed, 2 * allocation);
+    let genesis_tokens = get_associated_token_address(&genesis_pool_address, &mint);
+    assert_eq!(env.token_balance(&genesis_tokens).await, genesis_pool.total_tokens - 2 * allocation);
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+# Dump the mainnet programs and accounts the local tests load into
+# programs/proof_of_meme/tests/fixtures:
+#   - Raydium CPMM, for `anchor test` and the real-CPMM migration test
+#     (cargo test --test raydium_migration -- --ignored)
+#   - the CPMM AMM config (index 0) and pool creation fee receiver it reads
+#   - Metaplex Token Metadata, for `anchor test` and the real-program launch test
+#     (cargo test --test metaplex_metadata -- --ignored)
+#
+# Usage: scripts/fetch-fixtures.sh [rpc-url]   (defaults to mainnet-beta)
+# CI runs it before `cargo test --workspace -- --ignored` (.github/workflows/test.yml).
+set -euo pipefail
+
+RPC_URL="${1:-https://api.mainnet-beta.solana.com}"
+FIXTURES="$(cd "$(dirname "$0")/.." && pwd)/programs/proof_of_meme/tests/fixtures"
+
+RAYDIUM_CPMM=CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C
+AMM_CONFIG=D4FPEruKEHrG5TenZ2mpDGEfu1iUvTiqBxvpU8HLBvC2
+CREATE_POOL_FEE_RECEIVER=DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8
+TOKEN_METADATA=metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s
+
+mkdir -p "$FIXTURES"
+
+solana program dump -u "$RPC_URL" "$RAYDIUM_CPMM" "$FIXTURES/raydium_cpmm.so"
+solana account -u "$RPC_URL" "$AMM_CONFIG" --output json --output-file "$FIXTURES/amm_config.json"
+solana account -u "$RPC_URL" "$CREATE_POOL_FEE_RECEIVER" --output json --output-file "$FIXTURES/create_pool_fee.json"
+solana program dump -u "$RPC_URL" "$TOKEN_METADATA" "$FIXTURES/mpl_token_metadata.so"
+
+echo "Fixtures written to $FIXTURES"
@@ -27,6 +27,11 @@ export const TOTAL_SUPPLY = 1_000_000_000; // 1 billion tokens
 // Bonding curve
 export const CURVE_COMPLETION_SOL = 85; // ~85 SOL to complete curve
 export const GENESIS_ALLOCATION_BPS = 2_000; // 20% of supply to genesis backers
+export const MAX_GENESIS_ALLOCATION_BPS = 5_000; // creators can give backers at most half the supply
+export const MAX_CURVE_BREAKPOINTS = 8; // piecewise-linear curves
+export const EXPONENTIAL_CURVE_STEPS = 16; // exponential curves compound over this many equal slices of the supply
+export const MAX_CURVE_PRICE_MULTIPLE_BPS = 10_000_000; // non-constant-product curves end at most 1000x the opening price
+export const CURVE_PRICE_SCALE = 1_000_000_000_000; // their prices are lamports per token base unit * 1e12
 
 // Migration
 export const MIGRATION_FEE = 1.5; // 1.5 SOL
@@ -0,0 +1,164 @@
+import { BPS_DENOMINATOR, CURVE_PRICE_SCALE, EXPONENTIAL_CURVE_STEPS, MAX_CURVE_BREAKPOINTS } from './constants';
+import { CurveBreakpoint, CurveKind } from './types';
+
+// Off-chain copy of the program's price schedules (state/curve_kind.rs), used by the
+// quote methods for linear, exponential and piecewise-linear curves. Rounding follows
+// the program so quotes match what it executes.
+
+const BPS = BigInt(BPS_DENOMINATOR);
+const SCALE = BigInt(CURVE_PRICE_SCALE);
+
+interface PricePoint {
+  position: bigint; // curve supply sold
+  price: bigint; // lamports per token base unit, scaled by CURVE_PRICE_SCALE
+}
+
+/** Pads breakpoints to the fixed-size array the program stores */
+export function piecewiseLinearCurve(breakpoints: CurveBreakpoint[]): CurveKind {
+  const padded = breakpoints.slice();
+  while (padded.length < MAX_CURVE_BREAKPOINTS) padded.push({ supplyBps: 0, priceBps: 0 });
+  return { piecewiseLinear: { breakpoints: padded, count: breakpoints.length } };
+}
+
+/** (supply sold, price multiple) points in basis points, or null for constant product */
+function priceMultiples(kind: CurveKind): [bigint, bigint][] | null {
+  const points: [bigint, bigint][] = [[0n, BPS]];
+  if ('linear' in kind) {
+    points.push([BPS, BigInt(kind.linear.endPriceBps)]);
+  } else if ('exponential' in kind) {
+    const growth = BigInt(kind.exponential.growthBps);
+    for (let step = 1; step <= EXPONENTIAL_CURVE_STEPS; step++) {
+      const multiple = (points[step - 1][1] * (BPS + growth)) / BPS;
+      points.push([(BPS * BigInt(step)) / BigInt(EXPONENTIAL_CURVE_STEPS), multiple]);
+    }
+  } else if ('piecewiseLinear' in kind) {
+    const { breakpoints, count } = kind.piecewiseLinear;
+    for (const breakpoint of breakpoints.slice(0, count)) {
+      points.push([BigInt(breakpoint.supplyBps), BigInt(breakpoint.priceBps)]);
+    }
+  } else {
+    return null;
+  }
+  return points;
+}
+
+function divRound(numerator: bigint, denominator: bigint, roundUp: boolean): bigint {
+  return roundUp ? (numerator + denominator - 1n) / denominator : numerator / denominator;
+}
+
+function ceilSqrt(value: bigint): bigint {
+  if (value < 2n) return value;
+  // Newton's method for the floor root, then round up
+  let x = value;
+  let y = (x + 1n) / 2n;
+  while (y < x) {
+    x = y;
+    y = (x + value / x) / 2n;
+  }
+  return x * x < value ? x + 1n : x;
+}
+
+class Segment {
+  constructor(readonly start: PricePoint, readonly end: PricePoint) {}
+
+  get width(): bigint {
+    return this.end.position - this.start.position;
+  }
+
+  get rise(): bigint {
+    return this.end.price - this.start.price;
+  }
+
+  priceAt(position: bigint, roundUp: boolean): bigint {
+    return this.start.price + divRound(this.rise * (position - this.start.position), this.width, roundUp);
+  }
+
+  cost(from: bigint, to: bigint, roundUp: boolean): bigint {
+    const priceSum = this.priceAt(from, roundUp) + this.priceAt(to, roundUp);
+    return divRound((to - from) * priceSum, 2n * SCALE, roundUp);
+  }
+
+  tokensFor(from: bigint, budget: bigint): bigint {
+    const price = this.priceAt(from, true);
+    const scaledBudget = budget * SCALE;
+    const slopeNumerator = 2n * budget * this.rise;
+    const slopeTerm = (slopeNumerator / this.width) * SCALE + divRound((slopeNumerator % this.width) * SCALE, this.width, true);
+    const root = ceilSqrt(price * price + slopeTerm);
+    const tokens = (2n * scaledBudget) / (price + root);
+    const room = this.end.position - from;
+    return tokens < room ? tokens : room;
+  }
+}
+
+/** Price schedule for a non-constant-product curve */
+export class PriceSchedule {
+  private readonly segments: Segment[];
+  private readonly openingPrice: bigint;
+
+  private constructor(private readonly supply: bigint, points: PricePoint[]) {
+    this.openingPrice = points[0].price;
+    this.segments = [];
+    for (let i = 1; i < points.length; i++) {
+      if (points[i].position > points[i - 1].position) this.segments.push(new Segment(points[i - 1], points[i]));
+    }
+  }
+
+  /** Null for constant-product curves */
+  static fromCurve(kind: CurveKind, curveSupply: bigint, openingPrice: bigint): PriceSchedule | null {
+    const multiples = priceMultiples(kind);
+    if (!multiples) return null;
+    return new PriceSchedule(
+      curveSupply,
+      multiples.map(([supplyBps, priceBps]) => ({
+        position: (curveSupply * supplyBps) / BPS,
+        price: (openingPrice * priceBps) / BPS,
+      }))
+    );
+  }
+
+  /** Tokens out for `solIn` lamports, before the cap at real token reserves */
+  buyTokens(realTokenReserves: bigint, solIn: bigint): bigint {
+    let budget = solIn;
+    let tokensOut = 0n;
+
+    // Tokens sold back below the launch supply go first, at the opening price
+    const belowSchedule = realTokenReserves > this.supply ? realTokenReserves - this.supply : 0n;
+    if (belowSchedule > 0n) {
+      const cost = divRound(belowSchedule * this.openingPrice, SCALE, true);
+      if (budget < cost) return (budget * SCALE) / this.openingPrice;
+      budget -= cost;
+      tokensOut = belowSchedule;
+    }
+
+    const position = this.position(realTokenReserves);
+    for (const segment of this.segments) {
+      if (segment.end.position <= position) continue;
+      const from = segment.start.position > position ? segment.start.position : position;
+      const cost = segment.cost(from, segment.end.position, true);
+      if (budget < cost) return tokensOut + segment.tokensFor(from, budget);
+      budget -= cost;
+      tokensOut += segment.end.position - from;
+    }
+    return tokensOut;
+  }
+
+  /** Lamports out for `tokensIn`, before the cap at real SOL reserves */
+  sellSol(realTokenReserves: bigint, tokensIn: bigint): bigint {
+    const position = this.position(realTokenReserves);
+    const onSchedule = tokensIn < position ? tokensIn : position;
+    let solOut = ((tokensIn - onSchedule) * this.openingPrice) / SCALE;
+
+    const from = position - onSchedule;
+    for (const segment of this.segments) {
+      if (segment.end.position <= from || segment.start.position >= position) continue;
+      const start = from > segment.start.position ? from : segment.start.position;
+      const end = position < segment.end.position ? position : segment.end.position;
+      solOut += segment.cost(start, end, false);
+    }
+    return solOut;
+  }
+
+  private position(realTokenReserves: bigint): bigint {
+    return this.supply > realTokenReserves ? this.supply - realTokenReserves : 0n;
+  }
+}
@@ -1,12 +1,13 @@
 import { Program, AnchorProvider, BN, Idl } from '@coral-xyz/anchor';
-import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Connection, Keypair } from '@solana/web3.js';
-import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Connection } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress, getMint } from '@solana/spl-token';
 
 // Re-export types
 export * from './types';
 export * from './pda';
 export * from './constants';
 export * from './metadata';
+export * from './curve';
 
 import {
   getPlatformPDA,
@@ -18,8 +19,10 @@ import {
   getGenesisPoolPDA,
   getMintPDA
 } from './pda';
-import { PROGRAM_ID, LAMPORTS_PER_SOL, BPS_DENOMINATOR } from './constants';
-import { createTokenMetadata, SocialLinks, getMetadataPDA } from './metadata';
+import { PROGRAM_ID, LAMPORTS_PER_SOL, BPS_DENOMINATOR, TOKEN_DECIMALS, TOTAL_SUPPLY } from './constants';
+import { SocialLinks, getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from './metadata';
+import { CurveKind, CurveParams } from './types';
+import { PriceSchedule } from './curve';
 
 export interface ProofOfMemeSDKConfig {
   connection: Connection;
@@ -35,12 +38,22 @@ export interface SubmitMemeParams {
   solGoal: number; // in SOL
   minBackers: number;
   durationSeconds: number;
+  launchOnGoal?: boolean; // launch as soon as the goal is met, after the platform launch window
+  solCap?: number; // in SOL; backing beyond it is refunded pro rata (default uncapped)
+  token2022?: boolean; // create the mint under Token-2022 with native metadata (default SPL Token)
+  // Curve parameters, within the platform's curveBounds; unset ones take the platform defaults
+  initialVirtualSol?: number; // in SOL; default opens the curve at the genesis price
+  completionSol?: number; // in SOL raised on the curve beyond the backing
+  genesisAllocationBps?: number;
+  curveKind?: CurveKind; // default constant product; see piecewiseLinearCurve for breakpoints
+  nativePool?: boolean; // migrate into the native pool instead of Raydium, if curveBounds allow it
 }
 
 export interface MemeInfo {
   publicKey: PublicKey;
   creator: PublicKey;
   mint: PublicKey;
+  tokenProgram: PublicKey;
   name: string;
   symbol: string;
   uri: string;
@@ -55,6 +68,10 @@ export interface MemeInfo {
   launchedAt: BN;
   creatorBacking: BN;
   index: BN;
+  metadataUpdates: number;
+  metadataUpdatedAt: BN;
+  metadataLocked: boolean;
+  curveParams: CurveParams;
 }
 
 export interface BackingInfo {
@@ -86,6 +103,9 @@ export interface CurveInfo {
   burnFeesAccumulated: BN;
   status: string;
   completionThreshold: BN;
+  openingPrice: BN;
+  curveSupply: BN;
+  kind: CurveKind;
 }
 
 export interface ProofOfMemeSDKConfigWithIdl extends ProofOfMemeSDKConfig {
@@ -128,7 +148,9 @@ export class ProofOfMemeSDK {
     submissionFee: number, // in SOL
     platformFeeBps: number,
     genesisFeeBps: number,
-    burnFeeBps: number
+    burnFeeBps: number,
+    vestingCliffSeconds: number = 0,
+    vestingDurationSeconds: number = 0
   ): Promise<string> {
     const [platformPDA] = getPlatformPDA(this.programId);
 
@@ -137,7 +159,9 @@ export class ProofOfMemeSDK {
         new BN(submissionFee * LAMPORTS_PER_SOL),
         platformFeeBps,
         genesisFeeBps,
-        burnFeeBps
+        burnFeeBps,
+        new BN(vestingCliffSeconds),
+        new BN(vestingDurationSeconds)
       )
       .accounts({
         authority: this.provider.wallet.publicKey,
@@ -170,14 +194,24 @@ export class ProofOfMemeSDK {
         params.description,
         new BN(params.solGoal * LAMPORTS_PER_SOL),
         params.minBackers,
-        new BN(params.durationSeconds)
+        new BN(params.durationSeconds),
+        params.launchOnGoal ?? false,
+        new BN((params.solCap ?? 0) * LAMPORTS_PER_SOL),
+        params.token2022 ? { token2022: {} } : { spl: {} },
+        {
+          initialVirtualSol: new BN((params.initialVirtualSol ?? 0) * LAMPORTS_PER_SOL),
+          completionSol: new BN((params.completionSol ?? 0) * LAMPORTS_PER_SOL),
+          genesisAllocationBps: params.genesisAllocationBps ?? 0,
+          kind: params.curveKind ?? { constantProduct: {} },
+          migrationTarget: params.nativePool ? { nativePool: {} } : { raydium: {} },
+        }
       )
       .accounts({
         creator: this.provider.wallet.publicKey,
         platform: platformPDA,
         meme: memePDA,
         vault: vaultPDA,
-        platformAuthority: platform.authority,
+        feeRecipient: platform.feeRecipient,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -193,6 +227,7 @@ export class ProofOfMemeSDK {
       publicKey: memePDA,
       creator: meme.creator,
       mint: meme.mint,
+      tokenProgram: this.decodeTokenProgram(meme.tokenProgram),
       name: this.decodeString(meme.name),
       symbol: this.decodeString(meme.symbol),
       uri: this.decodeString(meme.uri),
@@ -207,6 +242,10 @@ export class ProofOfMemeSDK {
       launchedAt: meme.launchedAt,
       creatorBacking: meme.creatorBacking,
       index: meme.index,
+      metadataUpdates: meme.metadataUpdates,
+      metadataUpdatedAt: meme.metadataUpdatedAt,
+      metadataLocked: meme.metadataLocked,
+      curveParams: meme.curveParams,
     };
   }
 
@@ -216,6 +255,7 @@ export class ProofOfMemeSDK {
       publicKey: m.publicKey,
       creator: m.account.creator,
       mint: m.account.mint,
+      tokenProgram: this.decodeTokenProgram(m.account.tokenProgram),
       name: this.decodeString(m.account.name),
       symbol: this.decodeString(m.account.symbol),
       uri: this.decodeString(m.account.uri),
@@ -230,9 +270,27 @@ export class ProofOfMemeSDK {
       launchedAt: m.account.launchedAt,
       creatorBacking: m.account.creatorBacking,
       index: m.account.index,
+      metadataUpdates: m.account.metadataUpdates,
+      metadataUpdatedAt: m.account.metadataUpdatedAt,
+      metadataLocked: m.account.metadataLocked,
+      curveParams: m.account.curveParams,
     }));
   }
 
+  async updateMemeMetadata(memeIndex: number | BN, uri: string, description: string): Promise<string> {
+    const [memePDA] = getMemePDA(this.programId, memeIndex);
+
+    const tx = await this.program.methods
+      .updateMemeMetadata(uri, description)
+      .accounts({
+        creator: this.provider.wallet.publicKey,
+        meme: memePDA,
+      })
+      .rpc();
+
+    return tx;
+  }
+
   // ============ Backing Methods ============
 
   async backMeme(memeIndex: number | BN, amountSol: number): Promise<string> {
@@ -254,6 +312,27 @@ export class ProofOfMemeSDK {
     return tx;
   }
 
+  async unbackMeme(memeIndex: number | BN, amountSol: number): Promise<string> {
+    const platform = await this.getPlatformConfig();
+    const [memePDA] = getMemePDA(this.programId, memeIndex);
+    const [backingPDA] = getBackingPDA(this.programId, memePDA, this.provider.wallet.publicKey);
+    const [vaultPDA] = getVaultPDA(this.programId, memePDA);
+
+    const tx = await this.program.methods
+      .unbackMeme(new BN(amountSol * LAMPORTS_PER_SOL))
+      .accounts({
+        backer: this.provider.wallet.publicKey,
+        meme: memePDA,
+        backing: backingPDA,
+        vault: vaultPDA,
+        feeRecipient: platform.feeRecipient,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
   async getBacking(memeIndex: number | BN, backer?: PublicKey): Promise<BackingInfo> {
     const [memePDA] = getMemePDA(this.programId, memeIndex);
     const backerKey = backer || this.provider.wallet.publicKey;
@@ -304,8 +383,11 @@ export class ProofOfMemeSDK {
     const [curveVaultPDA] = getCurveVaultPDA(this.programId, memePDA);
     const [genesisPoolPDA] = getGenesisPoolPDA(this.programId, memePDA);
     const [mintPDA] = getMintPDA(this.programId, memePDA);
+    const meme = await (this.program.account as any).meme.fetch(memePDA);
+    const tokenProgram = this.decodeTokenProgram(meme.tokenProgram);
 
-    const curveTokenAccount = await getAssociatedTokenAddress(mintPDA, curvePDA, true);
+    const curveTokenAccount = await getAssociatedTokenAddress(mintPDA, curvePDA, true, tokenProgram);
+    const genesisTokenAccount = await getAssociatedTokenAddress(mintPDA, genesisPoolPDA, true, tokenProgram);
 
     const tx = await this.program.methods
       .finalizeProving()
@@ -318,9 +400,12 @@ export class ProofOfMemeSDK {
         curve: curvePDA,
         curveTokenAccount,
         genesisPool: genesisPoolPDA,
+        genesisTokenAccount,
         curveVault: curveVaultPDA,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        metadata: getMetadataPDA(mintPDA),
+        tokenProgram,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
@@ -329,83 +414,27 @@ export class ProofOfMemeSDK {
     return tx;
   }
 
-  /**
-   * Finalize proving AND create Metaplex metadata for visibility on Axiom, Birdeye, etc.
-   * This is the recommended method for launching tokens.
-   *
-   * @param memeIndex - The meme index to finalize
-   * @param metadataUri - URI to the JSON metadata (uploaded to IPFS/Arweave)
-   * @param payer - Keypair that will pay for metadata account rent (~0.01 SOL)
-   * @returns Object with finalize tx hash and metadata tx hash
-   */
-  async finalizeProvingWithMetadata(
-    memeIndex: number | BN,
-    metadataUri: string,
-    payer: Keypair
-  ): Promise<{ finalizeTx: string; metadataTx: string; mint: PublicKey }> {
-    // First, finalize proving to create the mint
-    const finalizeTx = await this.finalizeProving(memeIndex);
-
-    // Get meme data to extract name/symbol
-    const memeInfo = await this.getMeme(memeIndex);
-    const [mintPDA] = getMintPDA(this.programId, memeInfo.publicKey);
-
-    // Create Metaplex metadata
-    const metadataTx = await createTokenMetadata({
-      connection: this.provider.connection,
-      payer,
-      mint: mintPDA,
-      name: memeInfo.name,
-      symbol: memeInfo.symbol,
-      uri: metadataUri,
-      updateAuthority: payer.publicKey,
-      isMutable: true,
-      sellerFeeBasisPoints: 0, // No royalties for meme coins
-    });
-
-    return { finalizeTx, metadataTx, mint: mintPDA };
-  }
+  async markMemeFailed(memeIndex: number | BN): Promise<string> {
+    const [memePDA] = getMemePDA(this.programId, memeIndex);
 
-  /**
-   * Create Metaplex metadata for an already-launched token
-   * Use this if finalize was called without metadata, or to update metadata
-   */
-  async createMetadataForMeme(
-    memeIndex: number | BN,
-    metadataUri: string,
-    payer: Keypair
-  ): Promise<{ metadataTx: string; metadataPDA: PublicKey }> {
-    const memeInfo = await this.getMeme(memeIndex);
-
-    if (memeInfo.status !== 'launched' && memeInfo.status !== 'migrated') {
-      throw new Error('Meme must be launched before creating metadata');
-    }
+    const tx = await this.program.methods
+      .markMemeFailed()
+      .accounts({
+        finalizer: this.provider.wallet.publicKey,
+        meme: memePDA,
+      })
+      .rpc();
 
-    const [mintPDA] = getMintPDA(this.programId, memeInfo.publicKey);
-    const metadataPDA = getMetadataPDA(mintPDA);
-
-    const metadataTx = await createTokenMetadata({
-      connection: this.provider.connection,
-      payer,
-      mint: mintPDA,
-      name: memeInfo.name,
-      symbol: memeInfo.symbol,
-      uri: metadataUri,
-      updateAuthority: payer.publicKey,
-      isMutable: true,
-      sellerFeeBasisPoints: 0,
-    });
-
-    return { metadataTx, metadataPDA };
+    return tx;
   }
 
-  async markMemeFailed(memeIndex: number | BN): Promise<string> {
+  async cancelMeme(memeIndex: number | BN): Promise<string> {
     const [memePDA] = getMemePDA(this.programId, memeIndex);
 
     const tx = await this.program.methods
-      .markMemeFailed()
+      .cancelMeme()
       .accounts({
-        finalizer: this.provider.wallet.publicKey,
+        creator: this.provider.wallet.publicKey,
         meme: memePDA,
       })
       .rpc();
@@ -425,8 +454,9 @@ export class ProofOfMemeSDK {
     const [curveVaultPDA] = getCurveVaultPDA(this.programId, memePDA);
     const [genesisPoolPDA] = getGenesisPoolPDA(this.programId, memePDA);
 
-    const curveTokenAccount = await getAssociatedTokenAddress(meme.mint, curvePDA, true);
-    const buyerTokenAccount = await getAssociatedTokenAddress(meme.mint, this.provider.wallet.publicKey);
+    const tokenProgram = this.decodeTokenProgram(meme.tokenProgram);
+    const curveTokenAccount = await getAssociatedTokenAddress(meme.mint, curvePDA, true, tokenProgram);
+    const buyerTokenAccount = await getAssociatedTokenAddress(meme.mint, this.provider.wallet.publicKey, false, tokenProgram);
 
     const tx = await this.program.methods
       .buyTokens(new BN(solAmount * LAMPORTS_PER_SOL), minTokensOut)
@@ -436,11 +466,12 @@ export class ProofOfMemeSDK {
         meme: memePDA,
         curve: curvePDA,
         genesisPool: genesisPoolPDA,
+        mint: meme.mint,
         curveTokenAccount,
         buyerTokenAccount,
         curveVault: curveVaultPDA,
-        platformAuthority: platform.authority,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        feeRecipient: platform.feeRecipient,
+        tokenProgram,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -458,8 +489,9 @@ export class ProofOfMemeSDK {
     const [curveVaultPDA] = getCurveVaultPDA(this.programId, memePDA);
     const [genesisPoolPDA] = getGenesisPoolPDA(this.programId, memePDA);
 
-    const curveTokenAccount = await getAssociatedTokenAddress(meme.mint, curvePDA, true);
-    const sellerTokenAccount = await getAssociatedTokenAddress(meme.mint, this.provider.wallet.publicKey);
+    const tokenProgram = this.decodeTokenProgram(meme.tokenProgram);
+    const curveTokenAccount = await getAssociatedTokenAddress(meme.mint, curvePDA, true, tokenProgram);
+    const sellerTokenAccount = await getAssociatedTokenAddress(meme.mint, this.provider.wallet.publicKey, false, tokenProgram);
 
     const tx = await this.program.methods
       .sellTokens(tokenAmount, new BN(minSolOut * LAMPORTS_PER_SOL))
@@ -469,11 +501,12 @@ export class ProofOfMemeSDK {
         meme: memePDA,
         curve: curvePDA,
         genesisPool: genesisPoolPDA,
+        mint: meme.mint,
         curveTokenAccount,
         sellerTokenAccount,
         curveVault: curveVaultPDA,
-        platformAuthority: platform.authority,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        feeRecipient: platform.feeRecipient,
+        tokenProgram,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -502,9 +535,28 @@ export class ProofOfMemeSDK {
       burnFeesAccumulated: curve.burnFeesAccumulated,
       status: this.decodeCurveStatus(curve.status),
       completionThreshold: curve.completionThreshold,
+      openingPrice: curve.openingPrice,
+      curveSupply: curve.curveSupply,
+      kind: curve.kind,
     };
   }
 
+  /**
+   * Whether a launched meme's supply is final: full supply minted, no mint or freeze authority
+   */
+  async isFixedSupply(memeIndex: number | BN): Promise<boolean> {
+    const [memePDA] = getMemePDA(this.programId, memeIndex);
+    const [mintPDA] = getMintPDA(this.programId, memePDA);
+    const meme = await (this.program.account as any).meme.fetch(memePDA);
+    const mint = await getMint(this.provider.connection, mintPDA, undefined, this.decodeTokenProgram(meme.tokenProgram));
+
+    const fullSupply = BigInt(TOTAL_SUPPLY) * BigInt(10) ** BigInt(TOKEN_DECIMALS);
+    return mint.isInitialized
+      && mint.supply === fullSupply
+      && mint.mintAuthority === null
+      && mint.freezeAuthority === null;
+  }
+
   // ============ Genesis Fee Methods ============
 
   async claimGenesisFees(memeIndex: number | BN): Promise<string> {
@@ -538,6 +590,7 @@ export class ProofOfMemeSDK {
     const [memePDA] = getMemePDA(this.programId, memeIndex);
     const [curvePDA] = getCurvePDA(this.programId, memePDA);
     const [curveVaultPDA] = getCurveVaultPDA(this.programId, memePDA);
+    const meme = await (this.program.account as any).meme.fetch(memePDA);
 
     const tx = await this.program.methods
       .migrateToRaydium()
@@ -546,8 +599,10 @@ export class ProofOfMemeSDK {
         platform: platformPDA,
         meme: memePDA,
         curve: curvePDA,
-        platformAuthority: platform.authority,
+        feeRecipient: platform.feeRecipient,
         curveVault: curveVaultPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        memeTokenProgram: this.decodeTokenProgram(meme.tokenProgram),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -565,10 +620,16 @@ export class ProofOfMemeSDK {
     const tradingFee = solIn.muln(100).divn(BPS_DENOMINATOR);
     const solAfterFee = solIn.sub(tradingFee);
 
-    // Constant product formula: tokens_out = (sol_in * virtual_token_reserves) / (virtual_sol_reserves + sol_in)
-    const numerator = solAfterFee.mul(curve.virtualTokenReserves);
-    const denominator = curve.virtualSolReserves.add(solAfterFee);
-    let tokensOut = numerator.div(denominator);
+    let tokensOut: BN;
+    const schedule = this.priceSchedule(curve);
+    if (schedule) {
+      tokensOut = new BN(schedule.buyTokens(BigInt(curve.realTokenReserves.toString()), BigInt(solAfterFee.toString())).toString());
+    } else {
+      // Constant product formula: tokens_out = (sol_in * virtual_token_reserves) / (virtual_sol_reserves + sol_in)
+      const numerator = solAfterFee.mul(curve.virtualTokenReserves);
+      const denominator = curve.virtualSolReserves.add(solAfterFee);
+      tokensOut = numerator.div(denominator);
+    }
 
     // Cap at real reserves
     if (tokensOut.gt(curve.realTokenReserves)) {
@@ -581,10 +642,21 @@ export class ProofOfMemeSDK {
   async quoteSell(memeIndex: number | BN, tokenAmount: BN): Promise<{ solOut: BN; fee: BN }> {
     const curve = await this.getCurve(memeIndex);
 
-    // Constant product formula: sol_out = (token_in * virtual_sol_reserves) / (virtual_token_reserves + token_in)
-    const numerator = tokenAmount.mul(curve.virtualSolReserves);
-    const denominator = curve.virtualTokenReserves.add(tokenAmount);
-    const solOutGross = numerator.div(denominator);
+    let solOutGross: BN;
+    const schedule = this.priceSchedule(curve);
+    if (schedule) {
+      solOutGross = new BN(schedule.sellSol(BigInt(curve.realTokenReserves.toString()), BigInt(tokenAmount.toString())).toString());
+    } else {
+      // Constant product formula: sol_out = (token_in * virtual_sol_reserves) / (virtual_token_reserves + token_in)
+      const numerator = tokenAmount.mul(curve.virtualSolReserves);
+      const denominator = curve.virtualTokenReserves.add(tokenAmount);
+      solOutGross = numerator.div(denominator);
+    }
+
+    // Cap at real reserves
+    if (solOutGross.gt(curve.realSolReserves)) {
+      solOutGross = curve.realSolReserves;
+    }
 
     // Calculate trading fee (1%)
     const tradingFee = solOutGross.muln(100).divn(BPS_DENOMINATOR);
@@ -595,6 +667,15 @@ export class ProofOfMemeSDK {
 
   // ============ Helper Methods ============
 
+  /** Price schedule for linear, exponential and piecewise-linear curves; null for constant product */
+  private priceSchedule(curve: CurveInfo): PriceSchedule | null {
+    return PriceSchedule.fromCurve(
+      curve.kind,
+      BigInt(curve.curveSupply.toString()),
+      BigInt(curve.openingPrice.toString())
+    );
+  }
+
   private decodeString(buffer: number[]): string {
     // Find the first zero byte (null terminator)
     let end = buffer.indexOf(0);
@@ -607,9 +688,14 @@ export class ProofOfMemeSDK {
     if (status.launched) return 'launched';
     if (status.failed) return 'failed';
     if (status.migrated) return 'migrated';
+    if (status.cancelled) return 'cancelled';
     return 'unknown';
   }
 
+  private decodeTokenProgram(tokenProgram: any): PublicKey {
+    return tokenProgram.token2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+  }
+
   private decodeCurveStatus(status: any): string {
     if (status.active) return 'active';
     if (status.complete) return 'complete';
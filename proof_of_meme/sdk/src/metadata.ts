@@ -204,19 +204,19 @@ export async function createTokenMetadata(params: CreateMetadataParams): Promise
   return Buffer.from(result.signature).toString('base64');
 }
 
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
 /**
  * Get the metadata PDA for a mint
  */
 export function getMetadataPDA(mint: PublicKey): PublicKey {
-  const METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
-
   const [metadataPDA] = PublicKey.findProgramAddressSync(
     [
       Buffer.from('metadata'),
-      METADATA_PROGRAM_ID.toBuffer(),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
       mint.toBuffer(),
     ],
-    METADATA_PROGRAM_ID
+    TOKEN_METADATA_PROGRAM_ID
   );
 
   return metadataPDA;
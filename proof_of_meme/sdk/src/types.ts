@@ -30,8 +30,37 @@ export enum MemeStatus {
   Launched = 'launched',
   Failed = 'failed',
   Migrated = 'migrated',
+  Cancelled = 'cancelled',
 }
 
+export enum TokenProgram {
+  Spl = 'spl',
+  Token2022 = 'token2022',
+}
+
+export interface CurveBreakpoint {
+  supplyBps: number; // share of the curve supply sold at this point
+  priceBps: number; // price as a multiple of the opening price, 10_000 = 1x
+}
+
+// Curve shape, in Anchor's enum encoding
+export type CurveKind =
+  | { constantProduct: {} }
+  | { linear: { endPriceBps: number } }
+  | { exponential: { growthBps: number } }
+  | { piecewiseLinear: { breakpoints: CurveBreakpoint[]; count: number } };
+
+export interface CurveParams {
+  initialVirtualSol: BN;
+  completionSol: BN;
+  genesisAllocationBps: number;
+  kind: CurveKind;
+  migrationTarget: MigrationTarget;
+}
+
+// Where a completed curve migrates, in Anchor's enum encoding
+export type MigrationTarget = { raydium: {} } | { nativePool: {} };
+
 export interface Meme {
   creator: PublicKey;
   mint: PublicKey;
@@ -49,6 +78,8 @@ export interface Meme {
   launchedAt: BN;
   creatorBacking: BN;
   index: BN;
+  tokenProgram: any;
+  curveParams: CurveParams;
   bump: number;
   vaultBump: number;
 }
@@ -89,6 +120,12 @@ export interface BondingCurve {
   completionThreshold: BN;
   bump: number;
   vaultBump: number;
+  migrationPool: PublicKey;
+  burnFeesSpent: BN;
+  tokensBurned: BN;
+  openingPrice: BN;
+  curveSupply: BN;
+  kind: CurveKind;
 }
 
 export interface GenesisPool {
@@ -112,9 +112,18 @@ describe("proof_of_meme", () => {
       const platformFeeBps = 2000; // 20%
       const genesisFeeBps = 7000; // 70%
       const burnFeeBps = 1000; // 10%
+      const vestingCliffSeconds = new BN(0);
+      const vestingDurationSeconds = new BN(30 * 24 * 60 * 60); // 30 days
 
       await program.methods
-        .initializePlatform(submissionFee, platformFeeBps, genesisFeeBps, burnFeeBps)
+        .initializePlatform(
+          submissionFee,
+          platformFeeBps,
+          genesisFeeBps,
+          burnFeeBps,
+          vestingCliffSeconds,
+          vestingDurationSeconds
+        )
         .accounts({
           authority: authority.publicKey,
           platform: platformPDA,
@@ -159,13 +168,13 @@ describe("proof_of_meme", () => {
       const durationSeconds = new BN(24 * 60 * 60); // 24 hours
 
       await program.methods
-        .submitMeme(name, symbol, uri, description, solGoal, minBackers, durationSeconds)
+        .submitMeme(name, symbol, uri, description, solGoal, minBackers, durationSeconds, false, new BN(0), { spl: {} }, { initialVirtualSol: new BN(0), completionSol: new BN(0), genesisAllocationBps: 0, kind: { constantProduct: {} } })
         .accounts({
           creator: creator.publicKey,
           platform: platformPDA,
           meme: memePDA,
           vault: vaultPDA,
-          platformAuthority: authority.publicKey,
+          feeRecipient: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([creator])
@@ -198,14 +207,18 @@ describe("proof_of_meme", () => {
             "",
             new BN(10 * LAMPORTS_PER_SOL), // Below 20 SOL minimum
             30,
-            new BN(24 * 60 * 60)
+            new BN(24 * 60 * 60),
+            false,
+            new BN(0),
+            { spl: {} },
+            { initialVirtualSol: new BN(0), completionSol: new BN(0), genesisAllocationBps: 0, kind: { constantProduct: {} } }
           )
           .accounts({
             creator: creator.publicKey,
             platform: platformPDA,
             meme: meme2PDA,
             vault: vault2PDA,
-            platformAuthority: authority.publicKey,
+            feeRecipient: authority.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .signers([creator])